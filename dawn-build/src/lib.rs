@@ -0,0 +1,750 @@
+//! Compiles a directory of GLSL and WGSL shaders to SPIR-V in a build script.
+//!
+//! Every shader is compiled to SPIR-V and written to a Rust file in `OUT_DIR` as a `Shader`
+//! constant with the SPIR-V words and the entry points, vertex inputs and bind group layout
+//! entries of the shader. WGSL is validated with naga. GLSL is validated by shaderc and then
+//! reflected with naga, whose SPIR-V frontend doesn't read every module; the constant of a
+//! shader it can't read has no entry points. Cargo reruns the build script when a shader or
+//! one of its `#include`s changes.
+//!
+//! GLSL files are named after their stage: `.vert`, `.frag` or `.comp`, optionally followed
+//! by `.glsl`. WGSL files end in `.wgsl`. The constant is named after the path relative to the
+//! directory, so `shaders/post/blur.frag.glsl` becomes `POST_BLUR_FRAG`.
+//!
+//! GLSL is compiled with shaderc and needs the default `glsl` feature. Without it, the SPIR-V
+//! is read from a checked-in `.spv` file next to the shader, e.g. `blur.frag.spv` for
+//! `blur.frag.glsl`, so crates can build without shaderc.
+//!
+//! In `build.rs`:
+//!
+//! ```no_run
+//! if let Err(err) = dawn_build::Shaders::new("shaders").compile("shaders.rs") {
+//!     panic!("{}", err);
+//! }
+//! ```
+//!
+//! In the crate:
+//!
+//! ```ignore
+//! mod shaders {
+//!     include!(concat!(env!("OUT_DIR"), "/shaders.rs"));
+//! }
+//!
+//! let vertex_shader = device.create_shader_module_with_code(shaders::TRIANGLE_VERT.spirv);
+//! let main = shaders::TRIANGLE_VERT.entry_point("main").unwrap();
+//! for &(group, entries) in main.bind_groups {
+//!     // Create the bind group layouts from `entries`.
+//! }
+//! ```
+//!
+//! The generated code refers to `dawn` types, so the crate must depend on dawn-rs.
+
+#[cfg(feature = "glsl")]
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Write};
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+use naga::valid::{Capabilities, ModuleInfo, ValidationFlags, Validator};
+use naga::{AddressSpace, Binding, ImageClass, ImageDimension, Module, ScalarKind, TypeInner};
+
+#[derive(Debug)]
+pub enum Error {
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+    /// `OUT_DIR` isn't set, so `compile` wasn't called from a build script.
+    MissingOutDir,
+    /// A shader failed to compile or validate. `message` has the file and line.
+    Compile {
+        path: PathBuf,
+        message: String,
+    },
+    /// Two shaders map to the same constant name.
+    DuplicateName {
+        name: String,
+        first: PathBuf,
+        second: PathBuf,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+            Error::MissingOutDir => write!(f, "OUT_DIR isn't set, call from a build script"),
+            Error::Compile { path, message } => {
+                write!(f, "failed to compile {}:\n{}", path.display(), message)
+            }
+            Error::DuplicateName {
+                name,
+                first,
+                second,
+            } => write!(
+                f,
+                "{} and {} are both named {}",
+                first.display(),
+                second.display(),
+                name
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Language {
+    Glsl(naga::ShaderStage),
+    Wgsl,
+}
+
+/// A directory of shaders to compile.
+#[derive(Debug, Clone)]
+pub struct Shaders {
+    dir: PathBuf,
+    defines: Vec<(String, String)>,
+}
+
+impl Shaders {
+    pub fn new<P: AsRef<Path>>(dir: P) -> Shaders {
+        Shaders {
+            dir: dir.as_ref().to_owned(),
+            defines: Vec::new(),
+        }
+    }
+
+    /// Defines a macro for all GLSL shaders, as if declared with `#define NAME VALUE`.
+    pub fn define(&mut self, name: &str, value: &str) -> &mut Shaders {
+        self.defines.push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    /// Compiles the shaders, writes the generated code to `$OUT_DIR/<file_name>` and prints
+    /// the `cargo:rerun-if-changed` lines.
+    pub fn compile(&self, file_name: &str) -> Result<(), Error> {
+        let out_dir = env::var_os("OUT_DIR").ok_or(Error::MissingOutDir)?;
+        let (code, dependencies) = self.generate()?;
+        println!("cargo:rerun-if-changed={}", self.dir.display());
+        for path in &dependencies {
+            println!("cargo:rerun-if-changed={}", path.display());
+        }
+        let path = Path::new(&out_dir).join(file_name);
+        fs::write(&path, code).map_err(|error| Error::Io { path, error })
+    }
+
+    /// Compiles the shaders and returns the generated code and the files it depends on.
+    pub fn generate(&self) -> Result<(String, BTreeSet<PathBuf>), Error> {
+        let mut paths = Vec::new();
+        find_shaders(&self.dir, &mut paths)?;
+        paths.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut dependencies = BTreeSet::new();
+        let mut names = BTreeMap::new();
+        let mut code = String::new();
+        writeln!(
+            code,
+            "// Generated by dawn-build from {}. Do not edit.",
+            self.dir.display()
+        )
+        .unwrap();
+        code.push_str(PRELUDE);
+
+        for (path, language) in paths {
+            let relative = path.strip_prefix(&self.dir).unwrap_or(&path);
+            let name = const_name(relative);
+            if let Some(first) = names.insert(name.clone(), path.clone()) {
+                return Err(Error::DuplicateName {
+                    name,
+                    first,
+                    second: path,
+                });
+            }
+            let source = fs::read_to_string(&path).map_err(|error| Error::Io {
+                path: path.clone(),
+                error,
+            })?;
+            dependencies.insert(path.clone());
+            let compile_error = |message| Error::Compile {
+                path: path.clone(),
+                message,
+            };
+            let (spirv, entry_points) = match language {
+                Language::Glsl(stage) => {
+                    let spirv = self
+                        .compile_glsl(&path, &source, stage, &mut dependencies)
+                        .map_err(compile_error)?;
+                    // The SPIR-V is still usable if naga can't reflect it.
+                    let entry_points =
+                        parse_spirv(&spirv).and_then(|(module, info)| entry_points(&module, &info));
+                    (spirv, entry_points)
+                }
+                Language::Wgsl => {
+                    let (spirv, module, info) = compile_wgsl(&source).map_err(compile_error)?;
+                    let entry_points = entry_points(&module, &info).map_err(compile_error)?;
+                    (spirv, Ok(entry_points))
+                }
+            };
+            write_shader(&mut code, &name, relative, &spirv, entry_points);
+        }
+
+        writeln!(code).unwrap();
+        writeln!(code, "#[allow(dead_code)]").unwrap();
+        writeln!(code, "pub const ALL: &[Shader] = &[").unwrap();
+        for name in names.keys() {
+            writeln!(code, "    {},", name).unwrap();
+        }
+        writeln!(code, "];").unwrap();
+        Ok((code, dependencies))
+    }
+
+    #[cfg(feature = "glsl")]
+    fn compile_glsl(
+        &self,
+        path: &Path,
+        source: &str,
+        stage: naga::ShaderStage,
+        dependencies: &mut BTreeSet<PathBuf>,
+    ) -> Result<Vec<u32>, String> {
+        let kind = match stage {
+            naga::ShaderStage::Vertex => shaderc::ShaderKind::Vertex,
+            naga::ShaderStage::Fragment => shaderc::ShaderKind::Fragment,
+            naga::ShaderStage::Compute => shaderc::ShaderKind::Compute,
+        };
+        let includes = RefCell::new(Vec::new());
+        let internal = || "failed to initialize shaderc".to_owned();
+        let mut compiler = shaderc::Compiler::new().ok_or_else(internal)?;
+        let mut options = shaderc::CompileOptions::new().ok_or_else(internal)?;
+        options.set_source_language(shaderc::SourceLanguage::GLSL);
+        options.set_target_env(
+            shaderc::TargetEnv::Vulkan,
+            shaderc::EnvVersion::Vulkan1_0 as u32,
+        );
+        for (name, value) in &self.defines {
+            options.add_macro_definition(name, Some(value.as_str()));
+        }
+        options.set_include_callback(|requested, include_type, requesting, _depth| {
+            let path = match include_type {
+                shaderc::IncludeType::Relative => Path::new(requesting)
+                    .parent()
+                    .unwrap_or_else(|| Path::new(""))
+                    .join(requested),
+                shaderc::IncludeType::Standard => self.dir.join(requested),
+            };
+            includes.borrow_mut().push(path.clone());
+            match fs::read_to_string(&path) {
+                Ok(content) => Ok(shaderc::ResolvedInclude {
+                    resolved_name: path.to_string_lossy().into_owned(),
+                    content,
+                }),
+                Err(error) => Err(format!("{}: {}", path.display(), error)),
+            }
+        });
+        let result = compiler.compile_into_spirv(
+            source,
+            kind,
+            &path.to_string_lossy(),
+            "main",
+            Some(&options),
+        );
+        drop(options);
+        dependencies.extend(includes.into_inner());
+        match result {
+            Ok(artifact) => Ok(artifact.as_binary().to_vec()),
+            Err(shaderc::Error::CompilationError(_, message)) => Err(message),
+            Err(err) => Err(err.to_string()),
+        }
+    }
+
+    // Reads the checked-in SPIR-V instead.
+    #[cfg(not(feature = "glsl"))]
+    fn compile_glsl(
+        &self,
+        path: &Path,
+        _source: &str,
+        _stage: naga::ShaderStage,
+        dependencies: &mut BTreeSet<PathBuf>,
+    ) -> Result<Vec<u32>, String> {
+        let name = path.file_name().unwrap().to_string_lossy();
+        let spv = path.with_file_name(format!(
+            "{}.spv",
+            name.strip_suffix(".glsl").unwrap_or(&name)
+        ));
+        let bytes = fs::read(&spv).map_err(|error| {
+            format!(
+                "GLSL shaders need the `glsl` feature of dawn-build or a precompiled {}: {}",
+                spv.display(),
+                error
+            )
+        })?;
+        dependencies.insert(spv.clone());
+        if bytes.len() % 4 != 0 || bytes.len() < 20 {
+            return Err(format!("{} isn't SPIR-V", spv.display()));
+        }
+        let mut words: Vec<u32> = bytes
+            .chunks_exact(4)
+            .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            .collect();
+        if words[0].swap_bytes() == SPIRV_MAGIC {
+            for word in &mut words {
+                *word = word.swap_bytes();
+            }
+        } else if words[0] != SPIRV_MAGIC {
+            return Err(format!("{} isn't SPIR-V", spv.display()));
+        }
+        Ok(words)
+    }
+}
+
+// The types the constants are made of.
+const PRELUDE: &str = r#"
+/// A shader compiled by dawn-build.
+#[allow(dead_code)]
+#[derive(Debug, Copy, Clone)]
+pub struct Shader {
+    /// The path relative to the shader directory.
+    pub path: &'static str,
+    pub spirv: &'static [u32],
+    /// Empty if naga can't reflect the SPIR-V compiled from GLSL.
+    pub entry_points: &'static [EntryPoint],
+}
+
+#[allow(dead_code)]
+impl Shader {
+    pub fn entry_point(&self, name: &str) -> Option<&'static EntryPoint> {
+        self.entry_points.iter().find(|entry_point| entry_point.name == name)
+    }
+}
+
+/// An entry point of a shader compiled by dawn-build.
+#[allow(dead_code)]
+#[derive(Debug, Copy, Clone)]
+pub struct EntryPoint {
+    pub name: &'static str,
+    pub stage: ::dawn::ShaderStage,
+    /// The locations and formats of the vertex inputs of a vertex shader.
+    pub vertex_inputs: &'static [(u32, ::dawn::VertexFormat)],
+    /// The bind group layout entries used by the entry point, by group.
+    pub bind_groups: &'static [(u32, &'static [::dawn::BindGroupLayoutEntry])],
+}
+"#;
+
+fn find_shaders(dir: &Path, shaders: &mut Vec<(PathBuf, Language)>) -> Result<(), Error> {
+    let io_error = |error| Error::Io {
+        path: dir.to_owned(),
+        error,
+    };
+    for entry in fs::read_dir(dir).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        if path.is_dir() {
+            find_shaders(&path, shaders)?;
+        } else if let Some(language) = language(&path) {
+            shaders.push((path, language));
+        }
+    }
+    Ok(())
+}
+
+fn language(path: &Path) -> Option<Language> {
+    let name = path.file_name()?.to_str()?;
+    let name = name.strip_suffix(".glsl").unwrap_or(name);
+    let stage = match Path::new(name).extension()?.to_str()? {
+        "vert" => naga::ShaderStage::Vertex,
+        "frag" => naga::ShaderStage::Fragment,
+        "comp" => naga::ShaderStage::Compute,
+        "wgsl" => return Some(Language::Wgsl),
+        _ => return None,
+    };
+    Some(Language::Glsl(stage))
+}
+
+fn const_name(relative: &Path) -> String {
+    let path = relative.to_string_lossy();
+    let path = path.strip_suffix(".glsl").unwrap_or(&path);
+    let path = path.strip_suffix(".wgsl").unwrap_or(path);
+    let mut name: String = path
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' => c.to_ascii_uppercase(),
+            _ => '_',
+        })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    name
+}
+
+fn validate(module: &Module) -> Result<ModuleInfo, naga::WithSpan<naga::valid::ValidationError>> {
+    Validator::new(ValidationFlags::all(), Capabilities::empty()).validate(module)
+}
+
+#[cfg(not(feature = "glsl"))]
+const SPIRV_MAGIC: u32 = 0x0723_0203;
+
+// Parses GLSL compiled to SPIR-V with naga to reflect it.
+fn parse_spirv(spirv: &[u32]) -> Result<(Module, ModuleInfo), String> {
+    let bytes: Vec<u8> = spirv
+        .iter()
+        .flat_map(|word| word.to_le_bytes().to_vec())
+        .collect();
+    let module = naga::front::spv::parse_u8_slice(&bytes, &Default::default())
+        .map_err(|err| format!("naga can't read the SPIR-V: {}", err))?;
+    let info = validate(&module).map_err(|err| err.as_inner().to_string())?;
+    Ok((module, info))
+}
+
+fn compile_wgsl(source: &str) -> Result<(Vec<u32>, Module, ModuleInfo), String> {
+    let module = naga::front::wgsl::parse_str(source).map_err(|err| err.emit_to_string(source))?;
+    let info = validate(&module).map_err(|err| {
+        let mut message = err.as_inner().to_string();
+        for (span, label) in err.spans() {
+            if let Some(range) = span.to_range() {
+                let line = source[..range.start.min(source.len())]
+                    .matches('\n')
+                    .count()
+                    + 1;
+                write!(message, "\n  line {}: {}", line, label).unwrap();
+            }
+        }
+        message
+    })?;
+    // Keep the names in release builds too, the default only has them with debug assertions.
+    let mut options = naga::back::spv::Options::default();
+    options.flags |= naga::back::spv::WriterFlags::DEBUG;
+    let spirv = naga::back::spv::write_vec(&module, &info, &options, None)
+        .map_err(|err| err.to_string())?;
+    Ok((spirv, module, info))
+}
+
+/// Writes the constant of a shader. `entry_points` is the generated list of entry points, or
+/// why it couldn't be generated.
+fn write_shader(
+    code: &mut String,
+    name: &str,
+    relative: &Path,
+    spirv: &[u32],
+    entry_points: Result<String, String>,
+) {
+    let path = relative.to_string_lossy().replace('\\', "/");
+    writeln!(code).unwrap();
+    writeln!(code, "/// `{}`", path).unwrap();
+    writeln!(code, "#[allow(dead_code)]").unwrap();
+    writeln!(code, "pub const {}: Shader = Shader {{", name).unwrap();
+    writeln!(code, "    path: {:?},", path).unwrap();
+    writeln!(code, "    spirv: &[").unwrap();
+    for words in spirv.chunks(8) {
+        code.push_str("       ");
+        for word in words {
+            write!(code, " {:#010x},", word).unwrap();
+        }
+        code.push('\n');
+    }
+    writeln!(code, "    ],").unwrap();
+    match entry_points {
+        Ok(entry_points) => code.push_str(&entry_points),
+        Err(reason) => {
+            writeln!(code, "    // naga can't reflect the SPIR-V:").unwrap();
+            for line in reason.lines() {
+                writeln!(code, "    // {}", line).unwrap();
+            }
+            writeln!(code, "    entry_points: &[],").unwrap();
+        }
+    }
+    writeln!(code, "}};").unwrap();
+}
+
+fn entry_points(module: &Module, info: &ModuleInfo) -> Result<String, String> {
+    let mut code = String::new();
+    writeln!(code, "    entry_points: &[").unwrap();
+    for (index, entry_point) in module.entry_points.iter().enumerate() {
+        let stage = match entry_point.stage {
+            naga::ShaderStage::Vertex => "VERTEX",
+            naga::ShaderStage::Fragment => "FRAGMENT",
+            naga::ShaderStage::Compute => "COMPUTE",
+        };
+        writeln!(code, "        EntryPoint {{").unwrap();
+        writeln!(code, "            name: {:?},", entry_point.name).unwrap();
+        writeln!(code, "            stage: ::dawn::ShaderStage::{},", stage).unwrap();
+
+        writeln!(code, "            vertex_inputs: &[").unwrap();
+        if entry_point.stage == naga::ShaderStage::Vertex {
+            for (location, format) in vertex_inputs(module, &entry_point.function)? {
+                writeln!(
+                    code,
+                    "                ({}, ::dawn::VertexFormat::{}),",
+                    location, format
+                )
+                .unwrap();
+            }
+        }
+        writeln!(code, "            ],").unwrap();
+
+        let mut groups: BTreeMap<u32, Vec<(u32, String)>> = BTreeMap::new();
+        let function_info = info.get_entry_point(index);
+        for (handle, variable) in module.global_variables.iter() {
+            let binding = match &variable.binding {
+                Some(binding) if !function_info[handle].is_empty() => binding,
+                _ => continue,
+            };
+            let ty = binding_type(module, variable).map_err(|reason| {
+                format!(
+                    "group {} binding {}: {}",
+                    binding.group, binding.binding, reason
+                )
+            })?;
+            groups
+                .entry(binding.group)
+                .or_default()
+                .push((binding.binding, ty));
+        }
+        writeln!(code, "            bind_groups: &[").unwrap();
+        for (group, mut entries) in groups {
+            entries.sort();
+            writeln!(code, "                ({}, &[", group).unwrap();
+            for (binding, ty) in entries {
+                writeln!(code, "                    ::dawn::BindGroupLayoutEntry {{").unwrap();
+                writeln!(code, "                        binding: {},", binding).unwrap();
+                writeln!(
+                    code,
+                    "                        visibility: ::dawn::ShaderStage::{},",
+                    stage
+                )
+                .unwrap();
+                writeln!(code, "                        ty: {},", ty).unwrap();
+                writeln!(code, "                    }},").unwrap();
+            }
+            writeln!(code, "                ]),").unwrap();
+        }
+        writeln!(code, "            ],").unwrap();
+        writeln!(code, "        }},").unwrap();
+    }
+    writeln!(code, "    ],").unwrap();
+    Ok(code)
+}
+
+// The vertex inputs are the arguments with a location, or the members of struct arguments.
+fn vertex_inputs(
+    module: &Module,
+    function: &naga::Function,
+) -> Result<Vec<(u32, &'static str)>, String> {
+    let mut inputs = Vec::new();
+    let mut add = |binding: &Option<Binding>, ty: naga::Handle<naga::Type>| {
+        if let Some(Binding::Location { location, .. }) = binding {
+            let format = vertex_format(&module.types[ty].inner).ok_or_else(|| {
+                format!("vertex input at location {} has no vertex format", location)
+            })?;
+            inputs.push((*location, format));
+        }
+        Ok::<(), String>(())
+    };
+    for argument in &function.arguments {
+        match &module.types[argument.ty].inner {
+            TypeInner::Struct { members, .. } if argument.binding.is_none() => {
+                for member in members {
+                    add(&member.binding, member.ty)?;
+                }
+            }
+            _ => add(&argument.binding, argument.ty)?,
+        }
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+fn vertex_format(ty: &TypeInner) -> Option<&'static str> {
+    let (kind, components) = match *ty {
+        TypeInner::Scalar { kind, width: 4 } => (kind, 1),
+        TypeInner::Vector {
+            size,
+            kind,
+            width: 4,
+        } => (kind, size as u8),
+        _ => return None,
+    };
+    let format = match (kind, components) {
+        (ScalarKind::Float, 1) => "Float",
+        (ScalarKind::Float, 2) => "Float2",
+        (ScalarKind::Float, 3) => "Float3",
+        (ScalarKind::Float, 4) => "Float4",
+        (ScalarKind::Sint, 1) => "Int",
+        (ScalarKind::Sint, 2) => "Int2",
+        (ScalarKind::Sint, 3) => "Int3",
+        (ScalarKind::Sint, 4) => "Int4",
+        (ScalarKind::Uint, 1) => "UInt",
+        (ScalarKind::Uint, 2) => "UInt2",
+        (ScalarKind::Uint, 3) => "UInt3",
+        (ScalarKind::Uint, 4) => "UInt4",
+        _ => return None,
+    };
+    Some(format)
+}
+
+// Returns the `dawn::BindingType` of a resource as Rust code.
+fn binding_type(module: &Module, variable: &naga::GlobalVariable) -> Result<String, String> {
+    let ty = match variable.space {
+        AddressSpace::Uniform => "::dawn::BindingType::UniformBuffer { dynamic: false }".to_owned(),
+        AddressSpace::Storage { access } => format!(
+            "::dawn::BindingType::StorageBuffer {{ dynamic: false, readonly: {} }}",
+            !access.contains(naga::StorageAccess::STORE)
+        ),
+        AddressSpace::Handle => match module.types[variable.ty].inner {
+            TypeInner::Sampler { comparison } => {
+                format!(
+                    "::dawn::BindingType::Sampler {{ comparison: {} }}",
+                    comparison
+                )
+            }
+            TypeInner::Image {
+                dim,
+                arrayed,
+                class,
+            } => {
+                let dimension = match (dim, arrayed) {
+                    (ImageDimension::D1, false) => "D1",
+                    (ImageDimension::D2, false) => "D2",
+                    (ImageDimension::D2, true) => "D2Array",
+                    (ImageDimension::D3, false) => "D3",
+                    (ImageDimension::Cube, false) => "Cube",
+                    (ImageDimension::Cube, true) => "CubeArray",
+                    _ => return Err("unsupported texture dimension".to_owned()),
+                };
+                match class {
+                    ImageClass::Sampled { kind, multi } => format!(
+                        "::dawn::BindingType::SampledTexture {{ dimension: \
+                         ::dawn::TextureViewDimension::{}, component_type: \
+                         ::dawn::TextureComponentType::{}, multisampled: {} }}",
+                        dimension,
+                        component_type(kind)?,
+                        multi
+                    ),
+                    ImageClass::Depth { multi } => format!(
+                        "::dawn::BindingType::SampledTexture {{ dimension: \
+                         ::dawn::TextureViewDimension::{}, component_type: \
+                         ::dawn::TextureComponentType::Float, multisampled: {} }}",
+                        dimension, multi
+                    ),
+                    ImageClass::Storage { format, access } => {
+                        let (format, kind) = storage_texture_format(format);
+                        format!(
+                            "::dawn::BindingType::StorageTexture {{ dimension: \
+                             ::dawn::TextureViewDimension::{}, component_type: \
+                             ::dawn::TextureComponentType::{}, format: \
+                             ::dawn::TextureFormat::{}, readonly: {}, writeonly: {} }}",
+                            dimension,
+                            component_type(kind)?,
+                            format,
+                            !access.contains(naga::StorageAccess::STORE),
+                            !access.contains(naga::StorageAccess::LOAD)
+                        )
+                    }
+                }
+            }
+            _ => return Err("unsupported resource type".to_owned()),
+        },
+        _ => return Err("unsupported address space".to_owned()),
+    };
+    Ok(ty)
+}
+
+fn component_type(kind: ScalarKind) -> Result<&'static str, String> {
+    match kind {
+        ScalarKind::Float => Ok("Float"),
+        ScalarKind::Sint => Ok("Sint"),
+        ScalarKind::Uint => Ok("Uint"),
+        ScalarKind::Bool => Err("boolean textures aren't supported".to_owned()),
+    }
+}
+
+fn storage_texture_format(format: naga::StorageFormat) -> (&'static str, ScalarKind) {
+    use naga::StorageFormat::*;
+
+    match format {
+        R8Unorm => ("R8Unorm", ScalarKind::Float),
+        R8Snorm => ("R8Snorm", ScalarKind::Float),
+        R8Uint => ("R8Uint", ScalarKind::Uint),
+        R8Sint => ("R8Sint", ScalarKind::Sint),
+        R16Uint => ("R16Uint", ScalarKind::Uint),
+        R16Sint => ("R16Sint", ScalarKind::Sint),
+        R16Float => ("R16Float", ScalarKind::Float),
+        Rg8Unorm => ("RG8Unorm", ScalarKind::Float),
+        Rg8Snorm => ("RG8Snorm", ScalarKind::Float),
+        Rg8Uint => ("RG8Uint", ScalarKind::Uint),
+        Rg8Sint => ("RG8Sint", ScalarKind::Sint),
+        R32Uint => ("R32Uint", ScalarKind::Uint),
+        R32Sint => ("R32Sint", ScalarKind::Sint),
+        R32Float => ("R32Float", ScalarKind::Float),
+        Rg16Uint => ("RG16Uint", ScalarKind::Uint),
+        Rg16Sint => ("RG16Sint", ScalarKind::Sint),
+        Rg16Float => ("RG16Float", ScalarKind::Float),
+        Rgba8Unorm => ("RGBA8Unorm", ScalarKind::Float),
+        Rgba8Snorm => ("RGBA8Snorm", ScalarKind::Float),
+        Rgba8Uint => ("RGBA8Uint", ScalarKind::Uint),
+        Rgba8Sint => ("RGBA8Sint", ScalarKind::Sint),
+        Rgb10a2Unorm => ("RGB10A2Unorm", ScalarKind::Float),
+        Rg11b10Float => ("RG11B10Float", ScalarKind::Float),
+        Rg32Uint => ("RG32Uint", ScalarKind::Uint),
+        Rg32Sint => ("RG32Sint", ScalarKind::Sint),
+        Rg32Float => ("RG32Float", ScalarKind::Float),
+        Rgba16Uint => ("RGBA16Uint", ScalarKind::Uint),
+        Rgba16Sint => ("RGBA16Sint", ScalarKind::Sint),
+        Rgba16Float => ("RGBA16Float", ScalarKind::Float),
+        Rgba32Uint => ("RGBA32Uint", ScalarKind::Uint),
+        Rgba32Sint => ("RGBA32Sint", ScalarKind::Sint),
+        Rgba32Float => ("RGBA32Float", ScalarKind::Float),
+    }
+}
+
+#[test]
+fn names_and_languages_follow_the_path() {
+    let path = Path::new("post/blur.frag.glsl");
+    assert_eq!("POST_BLUR_FRAG", const_name(path));
+    assert_eq!(
+        Some(Language::Glsl(naga::ShaderStage::Fragment)),
+        language(path)
+    );
+    assert_eq!(Some(Language::Wgsl), language(Path::new("2d/sprite.wgsl")));
+    assert_eq!("_2D_SPRITE", const_name(Path::new("2d/sprite.wgsl")));
+    assert_eq!(None, language(Path::new("common.glsl")));
+    assert_eq!(None, language(Path::new("triangle.vert.spv")));
+}
+
+#[test]
+fn generates_examples() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../dawn-rs/examples");
+    let (code, dependencies) = Shaders::new(&dir).generate().unwrap();
+    assert!(dependencies.contains(&dir.join("triangle.vert.glsl")));
+    #[cfg(not(feature = "glsl"))]
+    assert!(dependencies.contains(&dir.join("triangle.vert.spv")));
+    assert!(code.contains("pub const TRIANGLE_VERT: Shader"));
+    assert!(code.contains("pub const TRIANGLE_FRAG: Shader"));
+    assert!(code.contains("(0, ::dawn::VertexFormat::Float3),"));
+    assert!(code.contains("(1, ::dawn::VertexFormat::Float3),"));
+    assert!(code.contains("ty: ::dawn::BindingType::UniformBuffer { dynamic: false },"));
+}
+
+#[test]
+fn spirv_naga_cant_read_is_kept_without_metadata() {
+    let spirv = [0x0723_0203, 0x0001_0000, 0, 1, 0, 0xffff_ffff];
+    let entry_points = parse_spirv(&spirv).and_then(|(module, info)| entry_points(&module, &info));
+    let mut code = String::new();
+    write_shader(
+        &mut code,
+        "BROKEN",
+        Path::new("broken.frag"),
+        &spirv,
+        entry_points,
+    );
+    assert!(code.contains("0xffffffff,"));
+    assert!(code.contains("    // naga can't reflect the SPIR-V:\n"));
+    assert!(code.contains("    entry_points: &[],\n"));
+}
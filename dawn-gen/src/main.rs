@@ -0,0 +1,727 @@
+//! Generates the dawn-rs enums, bitflags and descriptor structs from Dawn's `dawn.json`.
+//!
+//! The output is checked in as `dawn-rs/src/generated.rs`. Regenerate it whenever the Dawn
+//! submodule is updated:
+//!
+//! ```text
+//! cargo run -p dawn-gen -- dawn-sys/dawn/dawn.json dawn-rs/src/generated.rs
+//! rustfmt --edition 2018 dawn-rs/src/generated.rs
+//! ```
+//!
+//! Names follow Dawn's own generator: each space separated word of a `dawn.json` name is
+//! capitalized, so `"discrete GPU"` becomes `DiscreteGPU` and matches the
+//! `sys::WGPUAdapterType_DiscreteGPU` constant. Types, enum values and members whose dawn-rs
+//! name differs from the Dawn name are listed in `RENAMED_TYPES`, `RENAMED_VALUES` and
+//! `RENAMED_MEMBERS`. Enums are emitted
+//! with the `dawn_enum!` macro from dawn-rs, which also implements their `TryFrom<i32>`
+//! conversion.
+//!
+//! Every structure gets a `lower` method that converts it to the `sys` struct, with the
+//! strings and arrays it points to kept alive by an `Arena`. The few types dawn-rs defines by
+//! hand are listed in `SKIPPED_ENUMS` and `SKIPPED_STRUCTURES`. Hand-written structures that
+//! are members of generated ones implement `lower` themselves.
+//!
+//! `cargo test -p dawn-gen` regenerates the file from the Dawn submodule and fails if the
+//! checked in copy is out of date.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::{env, fs, process};
+
+use serde_json::Value;
+
+/// Types whose dawn-rs name differs from the Dawn name.
+const RENAMED_TYPES: &[(&str, &str)] = &[
+    ("color write mask", "ColorWrite"),
+    ("extent 3D", "Extent3d"),
+    ("origin 3D", "Origin3d"),
+];
+
+/// Enum values whose dawn-rs variant name differs from the Dawn name.
+const RENAMED_VALUES: &[(&str, &str, &str)] =
+    &[("front face", "CCW", "Ccw"), ("front face", "CW", "Cw")];
+
+/// Structure members whose dawn-rs name differs from the Dawn name, as in the WebGPU spec.
+const RENAMED_MEMBERS: &[(&str, &str, &str)] = &[
+    ("bind group descriptor", "bindings", "entries"),
+    ("bind group layout descriptor", "bindings", "entries"),
+    ("buffer copy view", "row pitch", "bytes per row"),
+    ("buffer copy view", "image height", "rows per image"),
+];
+
+/// Members that are optional in Dawn but required in dawn-rs.
+const REQUIRED_MEMBERS: &[(&str, &str)] = &[
+    // Pipelines without vertex buffers pass an empty `VertexStateDescriptor`.
+    ("render pipeline descriptor", "vertex state"),
+];
+
+/// Structure pointer members that dawn-rs holds by value.
+const BY_VALUE_MEMBERS: &[(&str, &str)] = &[("render pipeline descriptor", "fragment stage")];
+
+/// Documentation for structure members, which `dawn.json` doesn't have.
+const MEMBER_DOCS: &[(&str, &str, &str)] = &[
+    ("compute pipeline descriptor", "layout", LAYOUT_DOC),
+    ("render pipeline descriptor", "layout", LAYOUT_DOC),
+];
+
+const LAYOUT_DOC: &str =
+    "`None` derives the layout from the shaders. Its bind group layouts can be queried with
+`get_bind_group_layout`.";
+
+/// Bitmask values that are hidden from the documentation.
+const HIDDEN_VALUES: &[(&str, &str)] = &[
+    // Only valid for swap chain textures, which dawn creates itself.
+    ("texture usage", "present"),
+];
+
+/// Enums that are defined by hand in dawn-rs.
+const SKIPPED_ENUMS: &[&str] = &[
+    // Keeps backends added by newer Dawn revisions as `BackendType::Unknown`.
+    "backend type",
+    // One variant per binding type, with the members of the layout entry that apply to it.
+    "binding type",
+    // Chained structs are filled in by hand.
+    "s type",
+];
+
+/// Structures that are defined by hand in dawn-rs.
+const SKIPPED_STRUCTURES: &[&str] = &[
+    // Returned by dawn rather than passed to it.
+    "adapter properties",
+    "create buffer mapped result",
+    // Use `BindingResource` and `BindingType` instead of the flat members.
+    "bind group entry",
+    "bind group layout entry",
+    "chained struct",
+    // Accepts WGSL as well as SPIR-V.
+    "shader module descriptor",
+    // Filled in from the window by `Instance::create_surface`.
+    "surface descriptor",
+];
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 2 || args.len() > 3 {
+        eprintln!("usage: dawn-gen <dawn.json> [output.rs]");
+        process::exit(1);
+    }
+
+    let json = fs::read_to_string(&args[1]).unwrap_or_else(|err| {
+        eprintln!("failed to read {}: {}", args[1], err);
+        process::exit(1);
+    });
+    let types: BTreeMap<String, Value> = serde_json::from_str(&json).unwrap_or_else(|err| {
+        eprintln!("failed to parse {}: {}", args[1], err);
+        process::exit(1);
+    });
+
+    let output = generate(&types);
+
+    match args.get(2) {
+        Some(path) => fs::write(path, output).unwrap_or_else(|err| {
+            eprintln!("failed to write {}: {}", path, err);
+            process::exit(1);
+        }),
+        None => print!("{}", output),
+    }
+}
+
+fn generate(types: &BTreeMap<String, Value>) -> String {
+    let mut out = String::new();
+    out.push_str(PRELUDE);
+
+    for (name, ty) in types {
+        match category(ty) {
+            "enum" if SKIPPED_ENUMS.contains(&name.as_str()) => {}
+            "enum" => enumeration(&mut out, name, ty),
+            "bitmask" => bitmask(&mut out, name, ty),
+            _ => {}
+        }
+    }
+
+    for (name, ty) in types {
+        if category(ty) == "structure" && !SKIPPED_STRUCTURES.contains(&name.as_str()) {
+            structure(&mut out, types, name, ty);
+        }
+    }
+
+    out
+}
+
+const PRELUDE: &str = "\
+// Generated by dawn-gen from dawn.json. Do not edit.
+
+use std::any::Any;
+use std::ffi::CString;
+use std::ptr;
+
+use dawn_sys as sys;
+
+#[allow(unused_imports)]
+use crate::*;
+
+/// Owns the memory that lowered descriptors point into. Keep it alive until the raw
+/// descriptor has been passed to dawn.
+#[derive(Default)]
+pub(crate) struct Arena {
+    items: Vec<Box<dyn Any>>,
+}
+
+impl Arena {
+    pub(crate) fn label(&mut self, label: Option<&str>) -> *const libc::c_char {
+        match label {
+            Some(label) => {
+                let label = Box::new(CString::new(label).unwrap());
+                let raw = label.as_ptr();
+                self.items.push(label);
+                raw
+            }
+            None => ptr::null(),
+        }
+    }
+
+    pub(crate) fn boxed<T: 'static>(&mut self, value: T) -> *const T {
+        let value = Box::new(value);
+        let raw = &*value as *const T;
+        self.items.push(value);
+        raw
+    }
+
+    pub(crate) fn slice<T: 'static>(&mut self, values: Vec<T>) -> *const T {
+        if values.is_empty() {
+            return ptr::null();
+        }
+        let values = Box::new(values.into_boxed_slice());
+        let raw = values.as_ptr();
+        self.items.push(values);
+        raw
+    }
+}
+
+";
+
+fn category(ty: &Value) -> &str {
+    ty["category"].as_str().unwrap_or("")
+}
+
+fn words(name: &str) -> impl Iterator<Item = &str> {
+    name.split(' ').filter(|word| !word.is_empty())
+}
+
+/// `"discrete GPU"` -> `DiscreteGPU`
+fn camel_case(name: &str) -> String {
+    words(name)
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// `"vertex stage"` -> `vertexStage`, the field names of the sys structs.
+fn lower_camel_case(name: &str) -> String {
+    let camel = camel_case(name);
+    let mut chars = camel.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// `"bind group layouts"` -> `bind_group_layouts`
+fn snake_case(name: &str) -> String {
+    let snake = words(name)
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("_");
+    match snake.as_str() {
+        "type" | "ref" | "use" | "mod" | "loop" | "match" => format!("r#{}", snake),
+        _ => snake,
+    }
+}
+
+/// `"map read"` -> `MAP_READ`
+fn shouty_snake_case(name: &str) -> String {
+    words(name)
+        .map(|word| word.to_uppercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+fn field_name(structure: &str, member: &str) -> String {
+    let renamed = RENAMED_MEMBERS
+        .iter()
+        .find(|(dawn_structure, dawn_member, _)| {
+            *dawn_structure == structure && *dawn_member == member
+        })
+        .map_or(member, |(_, _, renamed)| renamed);
+    snake_case(renamed)
+}
+
+fn type_name(name: &str) -> String {
+    RENAMED_TYPES
+        .iter()
+        .find(|(dawn_name, _)| *dawn_name == name)
+        .map(|(_, renamed)| renamed.to_string())
+        .unwrap_or_else(|| camel_case(name))
+}
+
+/// Rust identifiers can't start with a digit: `"2D array"` -> `D2Array`.
+fn variant_name(name: &str) -> String {
+    let camel = camel_case(name);
+    if !camel.starts_with(|c: char| c.is_ascii_digit()) {
+        return camel;
+    }
+    let digits: String = camel.chars().take_while(|c| c.is_ascii_digit()).collect();
+    let rest = &camel[digits.len()..];
+    match rest.strip_prefix('D') {
+        Some(rest) => format!("D{}{}", digits, rest),
+        None => format!("V{}", camel),
+    }
+}
+
+fn spec_link(out: &mut String, kind: &str, name: &str) {
+    let anchor: String = words(name).map(str::to_lowercase).collect();
+    writeln!(
+        out,
+        "/// <https://gpuweb.github.io/gpuweb/#{}-gpu{}>",
+        kind, anchor
+    )
+    .unwrap();
+}
+
+fn values(ty: &Value) -> impl Iterator<Item = &str> {
+    ty["values"]
+        .as_array()
+        .map(|values| values.as_slice())
+        .unwrap_or(&[])
+        .iter()
+        .filter_map(|value| value["name"].as_str())
+}
+
+fn enumeration(out: &mut String, name: &str, ty: &Value) {
+    let sys_name = camel_case(name);
+    out.push_str("dawn_enum! {\n    ");
+    spec_link(out, "enumdef", name);
+    out.push_str("    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]\n");
+    out.push_str("    #[repr(i32)]\n");
+    writeln!(out, "    pub enum {} {{", type_name(name)).unwrap();
+    for value in values(ty) {
+        let variant = RENAMED_VALUES
+            .iter()
+            .find(|(dawn_name, dawn_value, _)| *dawn_name == name && *dawn_value == value)
+            .map_or_else(
+                || variant_name(value),
+                |(_, _, renamed)| renamed.to_string(),
+            );
+        writeln!(
+            out,
+            "        {} = sys::WGPU{}_{},",
+            variant,
+            sys_name,
+            camel_case(value)
+        )
+        .unwrap();
+    }
+    out.push_str("    }\n}\n\n");
+}
+
+fn bitmask(out: &mut String, name: &str, ty: &Value) {
+    let sys_name = camel_case(name);
+    out.push_str("bitflags! {\n");
+    out.push_str("    ");
+    spec_link(out, "typedefdef", name);
+    writeln!(out, "    pub struct {}: i32 {{", type_name(name)).unwrap();
+    for value in values(ty) {
+        if HIDDEN_VALUES.contains(&(name, value)) {
+            out.push_str("        #[doc(hidden)]\n");
+        }
+        writeln!(
+            out,
+            "        const {} = sys::WGPU{}_{};",
+            shouty_snake_case(value),
+            sys_name,
+            camel_case(value)
+        )
+        .unwrap();
+    }
+    out.push_str("    }\n}\n\n");
+}
+
+/// How a structure member is represented in dawn-rs and lowered to the sys struct.
+enum Member {
+    /// Copied as is.
+    Native(&'static str),
+    Label,
+    String,
+    Enum(String),
+    Bitmask(String),
+    Object(String),
+    Structure(String),
+    StructurePointer(String),
+    Slice(Box<Member>),
+}
+
+fn native_type(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "bool" => "bool",
+        "float" => "f32",
+        "double" => "f64",
+        "int32_t" => "i32",
+        "uint32_t" => "u32",
+        "uint64_t" => "u64",
+        "size_t" => "usize",
+        _ => return None,
+    })
+}
+
+fn member(types: &BTreeMap<String, Value>, value: &Value) -> Result<Member, String> {
+    let ty = value["type"].as_str().unwrap_or("");
+    let annotation = value["annotation"].as_str().unwrap_or("value");
+    let is_array = value["length"].is_string() && value["length"] != "strlen";
+
+    if ty == "char" && annotation == "const*" {
+        let is_label = value["name"] == "label";
+        return Ok(if is_label {
+            Member::Label
+        } else {
+            Member::String
+        });
+    }
+
+    let element = match (native_type(ty), types.get(ty).map(category)) {
+        (Some(native), _) => Member::Native(native),
+        (None, Some("enum")) => Member::Enum(type_name(ty)),
+        (None, Some("bitmask")) => Member::Bitmask(type_name(ty)),
+        (None, Some("object")) => Member::Object(type_name(ty)),
+        (None, Some("structure")) if annotation == "const*" && !is_array => {
+            Member::StructurePointer(type_name(ty))
+        }
+        (None, Some("structure")) => Member::Structure(type_name(ty)),
+        _ => return Err(format!("unsupported member type `{} {}`", ty, annotation)),
+    };
+
+    match (annotation, is_array) {
+        ("value", false) | ("const*", false) => Ok(element),
+        ("const*", true) => Ok(Member::Slice(Box::new(element))),
+        _ => Err(format!("unsupported member type `{} {}`", ty, annotation)),
+    }
+}
+
+/// Returns `true` if the dawn-rs struct borrows and needs a lifetime parameter.
+fn has_lifetime(types: &BTreeMap<String, Value>, name: &str) -> bool {
+    members(&types[name]).any(|value| {
+        let member_type = value["type"].as_str().unwrap_or("");
+        value["annotation"].is_string()
+            || types.get(member_type).map(category) == Some("object")
+            || (types.get(member_type).map(category) == Some("structure")
+                && has_lifetime(types, member_type))
+    })
+}
+
+fn is_optional(structure: &str, value: &Value) -> bool {
+    let member = value["name"].as_str().unwrap_or("");
+    value["optional"].as_bool().unwrap_or(false) && !REQUIRED_MEMBERS.contains(&(structure, member))
+}
+
+fn members(ty: &Value) -> impl Iterator<Item = &Value> {
+    ty["members"]
+        .as_array()
+        .map(|members| members.as_slice())
+        .unwrap_or(&[])
+        .iter()
+}
+
+fn rust_type(types: &BTreeMap<String, Value>, member: &Member) -> String {
+    let lifetime = |name: &str| {
+        let dawn_name = types
+            .keys()
+            .find(|key| type_name(key) == name)
+            .expect("unknown structure");
+        if has_lifetime(types, dawn_name) {
+            format!("{}<'a>", name)
+        } else {
+            name.to_string()
+        }
+    };
+    match member {
+        Member::Native(native) => native.to_string(),
+        Member::Label => "Option<&'a str>".to_string(),
+        Member::String => "&'a str".to_string(),
+        Member::Enum(name) | Member::Bitmask(name) => name.clone(),
+        Member::Object(name) => format!("&'a {}", name),
+        Member::Structure(name) => lifetime(name),
+        Member::StructurePointer(name) => format!("&'a {}", lifetime(name)),
+        // Handles are passed by value in slices, like `Queue::submit` takes `&[CommandBuffer]`.
+        Member::Slice(element) => match &**element {
+            Member::Object(name) => format!("&'a [{}]", name),
+            element => format!("&'a [{}]", rust_type(types, element)),
+        },
+    }
+}
+
+fn lower_element(member: &Member, value: &str) -> String {
+    match member {
+        Member::Native(_) => value.to_string(),
+        Member::Label => format!("arena.label({})", value),
+        Member::String => format!("arena.label(Some({}))", value),
+        Member::Enum(_) => format!("{} as _", value),
+        Member::Bitmask(_) => format!("{}.bits() as _", value),
+        Member::Object(_) => format!("{}.raw", value),
+        Member::Structure(_) => format!("{}.lower(arena)", value),
+        Member::StructurePointer(_) => format!(
+            "{{\n                let raw = {}.lower(arena);\n                arena.boxed(raw)\n            }}",
+            value
+        ),
+        Member::Slice(_) => unreachable!(),
+    }
+}
+
+fn structure(out: &mut String, types: &BTreeMap<String, Value>, name: &str, ty: &Value) {
+    if ty["chained"].as_bool().unwrap_or(false) {
+        return;
+    }
+
+    let mut fields = Vec::new();
+    // Members that hold the length of a slice member are implied by the slice.
+    let lengths: Vec<&str> = members(ty)
+        .filter_map(|value| value["length"].as_str())
+        .collect();
+    for value in members(ty) {
+        let member_name = value["name"].as_str().unwrap_or("");
+        if lengths.contains(&member_name) {
+            continue;
+        }
+        match member(types, value) {
+            Ok(member) => fields.push((member_name, value, member)),
+            Err(err) => {
+                writeln!(out, "// Skipped `{}`: {}\n", name, err).unwrap();
+                return;
+            }
+        }
+    }
+
+    let rust_name = type_name(name);
+    let generics = if has_lifetime(types, name) {
+        "<'a>"
+    } else {
+        ""
+    };
+
+    // Labels, numbers and optional members have an obvious default.
+    let has_default = fields.iter().all(|(_, value, member)| match member {
+        Member::Label | Member::Native(_) => true,
+        Member::Enum(_) | Member::Bitmask(_) | Member::Structure(_) => false,
+        _ => is_optional(name, value),
+    });
+    let uses_arena = fields.iter().any(|(_, _, member)| {
+        !matches!(
+            member,
+            Member::Native(_) | Member::Enum(_) | Member::Bitmask(_) | Member::Object(_)
+        )
+    });
+
+    spec_link(out, "dictdef", name);
+    if has_default {
+        out.push_str("#[derive(Debug, Copy, Clone, Default)]\n");
+    } else {
+        out.push_str("#[derive(Debug, Copy, Clone)]\n");
+    }
+    if fields.is_empty() {
+        write!(out, "pub struct {}{} {{}}", rust_name, generics).unwrap();
+    } else {
+        writeln!(out, "pub struct {}{} {{", rust_name, generics).unwrap();
+    }
+    for (member_name, value, member) in &fields {
+        let optional = is_optional(name, value);
+        let rust_type = match member {
+            Member::StructurePointer(structure)
+                if BY_VALUE_MEMBERS.contains(&(name, member_name)) =>
+            {
+                rust_type(types, &Member::Structure(structure.clone()))
+            }
+            _ => rust_type(types, member),
+        };
+        let rust_type = match member {
+            Member::Label
+            | Member::Native(_)
+            | Member::Enum(_)
+            | Member::Bitmask(_)
+            | Member::Structure(_) => rust_type,
+            _ if optional => format!("Option<{}>", rust_type),
+            _ => rust_type,
+        };
+        let doc = MEMBER_DOCS
+            .iter()
+            .find(|(structure, member, _)| *structure == name && member == member_name);
+        if let Some((_, _, doc)) = doc {
+            for line in doc.lines() {
+                writeln!(out, "    /// {}", line).unwrap();
+            }
+        }
+        writeln!(
+            out,
+            "    pub {}: {},",
+            field_name(name, member_name),
+            rust_type
+        )
+        .unwrap();
+    }
+    if !fields.is_empty() {
+        out.push('}');
+    }
+    out.push_str("\n\n");
+
+    writeln!(out, "impl{} {}{} {{", generics, rust_name, generics).unwrap();
+    // Not every structure is passed to dawn on its own, e.g. `DeviceProperties`.
+    out.push_str("    #[allow(dead_code)]\n");
+    writeln!(
+        out,
+        "    pub(crate) fn lower(&self, {}: &mut Arena) -> sys::WGPU{} {{",
+        if uses_arena { "arena" } else { "_arena" },
+        camel_case(name)
+    )
+    .unwrap();
+    writeln!(out, "        sys::WGPU{} {{", camel_case(name)).unwrap();
+    if ty["extensible"].as_bool().unwrap_or(false) {
+        out.push_str("            nextInChain: ptr::null(),\n");
+    }
+    for value in members(ty) {
+        let member_name = value["name"].as_str().unwrap_or("");
+        let raw_name = lower_camel_case(member_name);
+        if lengths.contains(&member_name) {
+            let slice = members(ty)
+                .find(|other| other["length"] == member_name)
+                .and_then(|other| other["name"].as_str())
+                .unwrap();
+            let optional = members(ty)
+                .find(|other| other["length"] == member_name)
+                .map(|other| is_optional(name, other))
+                .unwrap();
+            let len = if optional {
+                format!("self.{}.map_or(0, |v| v.len())", field_name(name, slice))
+            } else {
+                format!("self.{}.len()", field_name(name, slice))
+            };
+            writeln!(out, "            {}: {} as _,", raw_name, len).unwrap();
+            continue;
+        }
+        let (_, value, member) = fields
+            .iter()
+            .find(|(name, _, _)| *name == member_name)
+            .unwrap();
+        let optional = is_optional(name, value);
+        let field = format!("self.{}", field_name(name, member_name));
+        let lowered = match member {
+            Member::Slice(element) => {
+                let source = if optional {
+                    format!("{}.unwrap_or(&[])", field)
+                } else {
+                    field
+                };
+                format!(
+                    "{{\n                let raw: Vec<_> = {}.iter().map(|v| {}).collect();\n                arena.slice(raw)\n            }}",
+                    source,
+                    match **element {
+                        Member::Native(_) | Member::Enum(_) => lower_element(element, "*v"),
+                        _ => lower_element(element, "v"),
+                    }
+                )
+            }
+            Member::Object(_) if optional => {
+                format!("{}.map_or(ptr::null_mut(), |v| v.raw)", field)
+            }
+            Member::String if optional => {
+                format!(
+                    "match {} {{\n                Some(v) => {},\n                None => ptr::null(),\n            }}",
+                    field,
+                    lower_element(member, "v")
+                )
+            }
+            Member::StructurePointer(_) if optional => format!(
+                "match {} {{\n                Some(v) => {{\n                    let raw = v.lower(arena);\n                    arena.boxed(raw)\n                }}\n                None => ptr::null(),\n            }}",
+                field
+            ),
+            _ => lower_element(member, &field),
+        };
+        writeln!(out, "            {}: {},", raw_name, lowered).unwrap();
+    }
+    out.push_str("        }\n    }\n}\n\n");
+}
+
+#[test]
+fn dawn_names() {
+    assert_eq!("DiscreteGPU", camel_case("discrete GPU"));
+    assert_eq!("BC1RGBAUnormSrgb", camel_case("BC1 RGBA unorm srgb"));
+    assert_eq!("vertexStage", lower_camel_case("vertex stage"));
+    assert_eq!("bind_group_layouts", snake_case("bind group layouts"));
+    assert_eq!("r#type", snake_case("type"));
+    assert_eq!("OUTPUT_ATTACHMENT", shouty_snake_case("output attachment"));
+    assert_eq!("D2Array", variant_name("2D array"));
+    assert_eq!("D3", variant_name("3D"));
+    assert_eq!("ColorWrite", type_name("color write mask"));
+    assert_eq!("bytes_per_row", field_name("buffer copy view", "row pitch"));
+    assert_eq!("bindings", field_name("bind group entry", "bindings"));
+}
+
+#[test]
+fn value_and_member_overrides() {
+    let types: BTreeMap<String, Value> = serde_json::from_str(
+        r#"{
+            "front face": {"category": "enum", "values": [{"name": "CCW", "value": 0}]},
+            "programmable stage descriptor": {"category": "structure", "members": [
+                {"name": "entry point", "type": "char", "annotation": "const*", "length": "strlen"}
+            ]},
+            "vertex state descriptor": {"category": "structure", "members": []},
+            "render pipeline descriptor": {"category": "structure", "members": [
+                {"name": "fragment stage", "type": "programmable stage descriptor",
+                 "annotation": "const*", "optional": true},
+                {"name": "vertex state", "type": "vertex state descriptor",
+                 "annotation": "const*", "optional": true}
+            ]}
+        }"#,
+    )
+    .unwrap();
+    let output = generate(&types);
+    assert!(output.contains("Ccw = sys::WGPUFrontFace_CCW,"));
+    assert!(output.contains("pub fragment_stage: Option<ProgrammableStageDescriptor<'a>>,"));
+    assert!(output.contains("pub vertex_state: &'a VertexStateDescriptor,"));
+}
+
+#[test]
+fn generated_rs_is_up_to_date() {
+    use std::path::Path;
+    use std::process::Command;
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let json_path = root.join("dawn-sys/dawn/dawn.json");
+    let json = match fs::read_to_string(&json_path) {
+        Ok(json) => json,
+        Err(_) => {
+            eprintln!("skipped: {} isn't checked out", json_path.display());
+            return;
+        }
+    };
+    let types: BTreeMap<String, Value> = serde_json::from_str(&json).unwrap();
+
+    let output = env::temp_dir().join(format!("dawn-gen-{}.rs", process::id()));
+    fs::write(&output, generate(&types)).unwrap();
+    let status = Command::new("rustfmt")
+        .arg("--edition")
+        .arg("2018")
+        .arg(&output)
+        .status()
+        .expect("failed to run rustfmt");
+    assert!(status.success());
+    let generated = fs::read_to_string(&output).unwrap();
+    fs::remove_file(&output).unwrap();
+
+    let committed = fs::read_to_string(root.join("dawn-rs/src/generated.rs")).unwrap();
+    assert!(
+        generated == committed,
+        "dawn-rs/src/generated.rs is out of date, regenerate it with dawn-gen"
+    );
+}
@@ -0,0 +1,14 @@
+use std::path::Path;
+
+fn main() {
+    // The shaders of the examples, also used by the unit tests. Packaged copies of the crate
+    // may not have them.
+    if !Path::new("examples").is_dir() {
+        println!("cargo:rerun-if-changed=examples");
+        println!("cargo:warning=examples/ not found, not compiling the example shaders");
+        return;
+    }
+    if let Err(err) = dawn_build::Shaders::new("examples").compile("example_shaders.rs") {
+        panic!("{}", err);
+    }
+}
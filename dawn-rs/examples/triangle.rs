@@ -6,6 +6,7 @@ use std::time::{Duration, Instant};
 
 use glfw::{Context, WindowEvent};
 
+use dawn::surface_manager::{SurfaceManager, SurfaceManagerDescriptor};
 use dawn::{
     native_swap_chain, util, AdapterType, BackendType, BindGroupDescriptor, BindGroupEntry,
     BindGroupLayoutDescriptor, BindGroupLayoutEntry, BindingResource, BindingType, BlendDescriptor,
@@ -13,11 +14,16 @@ use dawn::{
     ColorStateDescriptor, ColorWrite, CullMode, DeviceDescriptor, FrontFace, IndexFormat,
     InputStepMode, Instance, LoadOp, PipelineLayoutDescriptor, PresentMode, PrimitiveTopology,
     ProgrammableStageDescriptor, RasterizationStateDescriptor, RenderPassColorAttachmentDescriptor,
-    RenderPassDescriptor, RenderPipelineDescriptor, ShaderModuleDescriptor, ShaderStage, StoreOp,
-    TextureFormat, TextureUsage, VertexAttributeDescriptor, VertexBufferLayoutDescriptor,
-    VertexFormat, VertexStateDescriptor,
+    RenderPassDescriptor, RenderPipelineDescriptor, ShaderModuleDescriptor, ShaderModuleSource,
+    ShaderStage, StoreOp, VertexAttributeDescriptor, VertexBufferLayoutDescriptor, VertexFormat,
+    VertexStateDescriptor,
 };
 
+// `triangle.vert.glsl` and `triangle.frag.glsl`, compiled by the build script.
+mod shaders {
+    include!(concat!(env!("OUT_DIR"), "/example_shaders.rs"));
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut glfw = glfw::init(glfw::FAIL_ON_ERRORS.clone()).expect("glfwInit failed");
 
@@ -84,9 +90,6 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     device.set_error_callback::<PrintError>();
 
-    let swapchain_format = TextureFormat::RGBA8Unorm;
-    let swapchain_usage = TextureUsage::OUTPUT_ATTACHMENT | TextureUsage::PRESENT;
-
     let mut queue = device.default_queue();
 
     let swap_chain = match adapter.properties().backend_type {
@@ -129,16 +132,20 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         backend_type => unimplemented!("{:?}", backend_type),
     };
 
-    swap_chain.configure(swapchain_format, swapchain_usage, width, height);
+    let mut surface = SurfaceManager::new(
+        &device,
+        swap_chain,
+        &SurfaceManagerDescriptor::new(width, height),
+    );
 
     let vertex_shader = device.create_shader_module(&ShaderModuleDescriptor {
         label: None,
-        code: &util::spirv(include_bytes!("triangle.vert.spv")),
+        source: ShaderModuleSource::SpirV(shaders::TRIANGLE_VERT.spirv),
     });
 
     let fragment_shader = device.create_shader_module(&ShaderModuleDescriptor {
         label: None,
-        code: &util::spirv(include_bytes!("triangle.frag.spv")),
+        source: ShaderModuleSource::SpirV(shaders::TRIANGLE_FRAG.spirv),
     });
 
     let bind_group_layout = device.create_bind_group_layout(&BindGroupLayoutDescriptor {
@@ -246,7 +253,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let render_pipeline_descriptor = RenderPipelineDescriptor {
         label: None,
-        layout: &pipeline_layout,
+        layout: Some(&pipeline_layout),
         primitive_topology: PrimitiveTopology::TriangleList,
         vertex_stage: ProgrammableStageDescriptor {
             entry_point: "main",
@@ -257,7 +264,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             module: &fragment_shader,
         }),
         color_states: &[ColorStateDescriptor {
-            format: swapchain_format,
+            format: surface.format(),
             write_mask: ColorWrite::ALL,
             color_blend: color_replace,
             alpha_blend: color_replace,
@@ -302,7 +309,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut last_fps_time = Instant::now();
     let mut frame_count = 0;
 
-    let mut render_fn = || {
+    let mut render_fn = |surface: &mut SurfaceManager| {
         frame_count += 1;
 
         if last_fps_time.elapsed() > Duration::from_millis(1000) {
@@ -311,7 +318,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             last_fps_time = Instant::now();
         }
 
-        let frame_view = swap_chain.get_current_texture_view();
+        let frame = surface.next_frame();
         let frame_time = Instant::now();
 
         uniforms.time = (start.elapsed().as_millis() as f32) / 1000.0;
@@ -322,7 +329,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         let mut render_pass = encoder.begin_render_pass(&RenderPassDescriptor {
             label: None,
             color_attachments: &[RenderPassColorAttachmentDescriptor {
-                attachment: &frame_view,
+                attachment: &frame.view,
                 clear_color: Color {
                     r: 0.1,
                     g: 0.1,
@@ -343,7 +350,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         render_pass.end_pass();
 
         queue.submit(&[encoder.finish()]);
-        swap_chain.present();
+        surface.present();
         device.tick();
 
         last_frame_time = frame_time;
@@ -355,17 +362,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 WindowEvent::FramebufferSize(width, height) => {
                     let width = width.try_into().unwrap();
                     let height = height.try_into().unwrap();
-                    swap_chain.configure(swapchain_format, swapchain_usage, width, height);
+                    surface.resize(width, height);
                 }
                 WindowEvent::Refresh => {
-                    render_fn();
+                    render_fn(&mut surface);
                 }
                 _ => {}
             }
             None
         });
 
-        render_fn();
+        render_fn(&mut surface);
     }
 
     Ok(())
@@ -16,7 +16,9 @@ fn main() {
         .create_window(800, 600, "GLFW Window", glfw::WindowMode::Windowed)
         .expect("create window failed");
 
-    let surface = instance.create_surface(&window);
+    let surface = instance
+        .create_surface(&window)
+        .expect("create surface failed");
 
     let device = adapter.create_device(&DeviceDescriptor::default());
 
@@ -0,0 +1,74 @@
+use std::time::Instant;
+
+use winit::event::{Event, WindowEvent};
+use winit::event_loop::{ControlFlow, EventLoop};
+use winit::window::WindowBuilder;
+
+use dawn::winit::{WinitSurface, WinitSurfaceDescriptor};
+use dawn::{
+    Color, DeviceDescriptor, Instance, LoadOp, RenderPassColorAttachmentDescriptor,
+    RenderPassDescriptor, StoreOp,
+};
+
+fn main() {
+    let event_loop = EventLoop::new();
+    let window = WindowBuilder::new()
+        .with_title("winit")
+        .build(&event_loop)
+        .expect("create window failed");
+
+    let instance = Instance::new();
+    let adapters = instance.enumerate_adapters();
+    let adapter = adapters.first().expect("No adapters found");
+    println!("{:#?}", adapter.properties());
+
+    let device = adapter.create_device(&DeviceDescriptor::default());
+    let mut queue = device.default_queue();
+    let mut surface = WinitSurface::new(
+        &instance,
+        &device,
+        &window,
+        &WinitSurfaceDescriptor::default(),
+    )
+    .expect("create surface failed");
+
+    let start = Instant::now();
+
+    event_loop.run(move |event, _, control_flow| {
+        *control_flow = ControlFlow::Poll;
+        surface.handle_event(&event);
+        dawn::winit::tick(&device, &event);
+
+        match event {
+            Event::WindowEvent {
+                event: WindowEvent::CloseRequested,
+                ..
+            } => *control_flow = ControlFlow::Exit,
+            Event::MainEventsCleared => {
+                let t = start.elapsed().as_secs_f32();
+                let frame = surface.next_frame();
+                let mut encoder = device.create_command_encoder(&Default::default());
+                let render_pass = encoder.begin_render_pass(&RenderPassDescriptor {
+                    label: None,
+                    color_attachments: &[RenderPassColorAttachmentDescriptor {
+                        attachment: &frame.view,
+                        resolve_target: None,
+                        load_op: LoadOp::Clear,
+                        store_op: StoreOp::Store,
+                        clear_color: Color {
+                            r: 0.5 + 0.5 * t.sin(),
+                            g: 0.5 + 0.5 * (t * 0.7).sin(),
+                            b: 0.5 + 0.5 * (t * 1.3).sin(),
+                            a: 1.0,
+                        },
+                    }],
+                    depth_stencil_attachment: None,
+                });
+                render_pass.end_pass();
+                queue.submit(&[encoder.finish()]);
+                surface.present();
+            }
+            _ => {}
+        }
+    });
+}
@@ -0,0 +1,838 @@
+//! Reading rendered images back to the CPU.
+//!
+//! `Texture::read_to_image` copies one subresource of a texture into a buffer, waits for the
+//! copy and returns the texels as RGBA8, with BGRA formats swizzled back. The texture must
+//! have been created with `TextureUsage::COPY_SRC`.
+//!
+//! `SwapChain::capture_next_frame` takes a screenshot of the next presented frame. Offscreen
+//! swap chains already add `COPY_SRC` to their images and read every frame back. Dawn doesn't
+//! expose the textures behind the views of window swap chains, so while a capture is pending,
+//! `get_current_texture_view` hands out a view of a texture with the configured format, size
+//! and usage plus `COPY_SRC`. `present` copies that texture into a readback buffer and draws it
+//! to the window. Window swap chains must have been configured with `OUTPUT_ATTACHMENT` usage
+//! and a non-integer format.
+//!
+//! With the `png` feature, images can be encoded to PNG:
+//!
+//! ```no_run
+//! # #[cfg(feature = "png")]
+//! # fn run(texture: &dawn::Texture) -> Result<(), Box<dyn std::error::Error>> {
+//! let image = texture.read_to_image(0, 0)?;
+//! image.save_png("screenshot.png")?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::slice;
+use std::sync::Arc;
+use std::thread;
+
+use parking_lot::Mutex;
+
+use dawn_sys as sys;
+
+use crate::offscreen::Frame;
+use crate::{
+    convert, BindGroupDescriptor, BindGroupEntry, BindGroupLayout, BindGroupLayoutDescriptor,
+    BindGroupLayoutEntry, BindingResource, BindingType, BlendDescriptor, BlendFactor,
+    BlendOperation, Buffer, BufferCopyView, BufferDescriptor, BufferUsage, Color,
+    ColorStateDescriptor, ColorWrite, CommandEncoderDescriptor, Device, Extent3d, IndexFormat,
+    LoadOp, Origin3d, PipelineLayoutDescriptor, PrimitiveTopology, ProgrammableStageDescriptor,
+    RenderPassColorAttachmentDescriptor, RenderPassDescriptor, RenderPipeline,
+    RenderPipelineDescriptor, ShaderStage, StoreOp, SwapChain, Texture, TextureAspect,
+    TextureComponentType, TextureCopyView, TextureDescriptor, TextureDimension, TextureFormat,
+    TextureUsage, TextureView, TextureViewDescriptor, TextureViewDimension, VertexStateDescriptor,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CaptureError {
+    /// The format can't be converted to RGBA8.
+    UnsupportedFormat(TextureFormat),
+    /// The texture wasn't created with `TextureUsage::COPY_SRC`.
+    MissingCopySrc,
+    /// The mip level or array layer doesn't exist, or the texture is multisampled.
+    InvalidSubresource,
+    /// Mapping the readback buffer failed, e.g. because the device was lost.
+    MapFailed,
+    /// The swap chain presents to a window, but only offscreen swap chains are supported.
+    NotOffscreen,
+    /// The window swap chain hasn't been configured yet.
+    NotConfigured,
+    /// The window swap chain wasn't configured with `TextureUsage::OUTPUT_ATTACHMENT`, which
+    /// is needed to draw the captured frame to the window.
+    MissingOutputAttachment,
+}
+
+impl fmt::Display for CaptureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CaptureError::UnsupportedFormat(format) => {
+                write!(f, "can't convert {:?} to an image", format)
+            }
+            CaptureError::MissingCopySrc => write!(f, "texture doesn't have COPY_SRC usage"),
+            CaptureError::InvalidSubresource => write!(f, "invalid texture subresource"),
+            CaptureError::MapFailed => write!(f, "failed to map the readback buffer"),
+            CaptureError::NotOffscreen => write!(f, "swap chain isn't offscreen"),
+            CaptureError::NotConfigured => write!(f, "swap chain isn't configured"),
+            CaptureError::MissingOutputAttachment => {
+                write!(f, "swap chain doesn't have OUTPUT_ATTACHMENT usage")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CaptureError {}
+
+/// An 8 bit RGBA image with tightly packed rows, top to bottom.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u8>,
+}
+
+impl Image {
+    /// Converts tightly packed texels of `format` to RGBA8. Single and two channel formats
+    /// fill the missing color channels with zero and alpha with 255.
+    pub fn from_texels(
+        format: TextureFormat,
+        width: u32,
+        height: u32,
+        texels: &[u8],
+    ) -> Result<Image, CaptureError> {
+        use TextureFormat::*;
+        let mut data = Vec::with_capacity(width as usize * height as usize * 4);
+        match format {
+            RGBA8Unorm | RGBA8UnormSrgb | RGBA8Uint => data.extend_from_slice(texels),
+            BGRA8Unorm | BGRA8UnormSrgb => {
+                for bgra in texels.chunks(4) {
+                    data.extend_from_slice(&[bgra[2], bgra[1], bgra[0], bgra[3]]);
+                }
+            }
+            R8Unorm | R8Uint => {
+                for &r in texels {
+                    data.extend_from_slice(&[r, 0, 0, 255]);
+                }
+            }
+            RG8Unorm | RG8Uint => {
+                for rg in texels.chunks(2) {
+                    data.extend_from_slice(&[rg[0], rg[1], 0, 255]);
+                }
+            }
+            _ => return Err(CaptureError::UnsupportedFormat(format)),
+        }
+        debug_assert_eq!(data.len(), width as usize * height as usize * 4);
+        Ok(Image {
+            width,
+            height,
+            data,
+        })
+    }
+
+    /// Returns the RGBA value of a pixel.
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        let offset = (y as usize * self.width as usize + x as usize) * 4;
+        let mut pixel = [0; 4];
+        pixel.copy_from_slice(&self.data[offset..offset + 4]);
+        pixel
+    }
+
+    #[cfg(feature = "png")]
+    pub fn encode_png(&self) -> Result<Vec<u8>, png::EncodingError> {
+        let mut png_data = Vec::new();
+        {
+            let mut encoder = png::Encoder::new(&mut png_data, self.width, self.height);
+            encoder.set_color(png::ColorType::RGBA);
+            encoder.set_depth(png::BitDepth::Eight);
+            let mut writer = encoder.write_header()?;
+            writer.write_image_data(&self.data)?;
+        }
+        Ok(png_data)
+    }
+
+    #[cfg(feature = "png")]
+    pub fn save_png<P: AsRef<std::path::Path>>(&self, path: P) -> Result<(), png::EncodingError> {
+        std::fs::write(path, self.encode_png()?)?;
+        Ok(())
+    }
+
+    /// Decodes an 8 bit RGB or RGBA PNG.
+    #[cfg(feature = "png")]
+    pub fn decode_png(png_data: &[u8]) -> Result<Image, png::DecodingError> {
+        let decoder = png::Decoder::new(png_data);
+        let (info, mut reader) = decoder.read_info()?;
+        let mut buf = vec![0; info.buffer_size()];
+        reader.next_frame(&mut buf)?;
+        let data = match (info.color_type, info.bit_depth) {
+            (png::ColorType::RGBA, png::BitDepth::Eight) => buf,
+            (png::ColorType::RGB, png::BitDepth::Eight) => {
+                let mut data = Vec::with_capacity(buf.len() / 3 * 4);
+                for rgb in buf.chunks(3) {
+                    data.extend_from_slice(&[rgb[0], rgb[1], rgb[2], 255]);
+                }
+                data
+            }
+            (color_type, bit_depth) => {
+                let message = format!("unsupported png: {:?} {:?}", color_type, bit_depth);
+                return Err(png::DecodingError::Other(message.into()));
+            }
+        };
+        Ok(Image {
+            width: info.width,
+            height: info.height,
+            data,
+        })
+    }
+}
+
+impl Texture {
+    /// Reads back one mip level of one array layer and converts it to RGBA8. Blocks until the
+    /// GPU has finished the copy.
+    pub fn read_to_image(&self, mip_level: u32, array_layer: u32) -> Result<Image, CaptureError> {
+        let (width, height, texels) = read_texture(self, mip_level, array_layer)?;
+        Image::from_texels(self.format(), width, height, &texels)
+    }
+}
+
+/// Copies one subresource of `texture` into a buffer and returns its size and tightly packed
+/// texels.
+pub(crate) fn read_texture(
+    texture: &Texture,
+    mip_level: u32,
+    array_layer: u32,
+) -> Result<(u32, u32, Vec<u8>), CaptureError> {
+    let format = texture.format();
+    let texel_size = convert::texel_size(format).ok_or(CaptureError::UnsupportedFormat(format))?;
+    if !texture.usage().contains(TextureUsage::COPY_SRC) {
+        return Err(CaptureError::MissingCopySrc);
+    }
+    if mip_level >= texture.mip_level_count()
+        || array_layer >= texture.array_layer_count()
+        || texture.sample_count() > 1
+    {
+        return Err(CaptureError::InvalidSubresource);
+    }
+
+    let size = texture.size();
+    let width = (size.width >> mip_level).max(1);
+    let height = (size.height >> mip_level).max(1);
+    let row_size = texel_size * width;
+    let bytes_per_row = convert::padded_bytes_per_row(row_size);
+
+    let device = &texture.device;
+    let buffer = device.create_buffer(&BufferDescriptor {
+        label: Some("read_texture"),
+        usage: BufferUsage::MAP_READ | BufferUsage::COPY_DST,
+        size: u64::from(bytes_per_row) * u64::from(height),
+    });
+    let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor { label: None });
+    encoder.copy_texture_to_buffer(
+        &TextureCopyView {
+            texture,
+            mip_level,
+            array_layer,
+            origin: Origin3d { x: 0, y: 0, z: 0 },
+        },
+        &BufferCopyView {
+            buffer: &buffer,
+            offset: 0,
+            bytes_per_row,
+            rows_per_image: height,
+        },
+        &Extent3d {
+            width,
+            height,
+            depth: 1,
+        },
+    );
+    device.default_queue().submit(&[encoder.finish()]);
+
+    let mapped = map_read(device, &buffer).ok_or(CaptureError::MapFailed)?;
+    let mut texels = Vec::with_capacity(row_size as usize * height as usize);
+    for row in mapped.chunks(bytes_per_row as usize).take(height as usize) {
+        texels.extend_from_slice(&row[..row_size as usize]);
+    }
+    Ok((width, height, texels))
+}
+
+/// Maps `buffer` for reading, ticking the device until the map completes, and returns a copy
+/// of its contents. The buffer is unmapped again before this returns.
+pub(crate) fn map_read(device: &Device, buffer: &Buffer) -> Option<Vec<u8>> {
+    PendingMapRead::new(device, buffer).wait(device)
+}
+
+/// A buffer that is being mapped for reading. The map callback copies the contents and
+/// unmaps the buffer, so the buffer can be reused once the map has completed.
+pub(crate) struct PendingMapRead {
+    result: Arc<Mutex<Option<Option<Vec<u8>>>>>,
+}
+
+/// State handed to the map callback. Owns a reference to the buffer so that it outlives its
+/// other owners if needed.
+struct MapRead {
+    buffer: Buffer,
+    result: Arc<Mutex<Option<Option<Vec<u8>>>>>,
+}
+
+impl PendingMapRead {
+    pub(crate) fn new(device: &Device, buffer: &Buffer) -> PendingMapRead {
+        let result = Arc::new(Mutex::new(None));
+        let map_read = Box::new(MapRead {
+            buffer: buffer.clone(),
+            result: result.clone(),
+        });
+        let guard = device.inner.lock();
+        unsafe {
+            sys::wgpuBufferMapReadAsync(
+                buffer.raw,
+                Some(map_read_callback),
+                Box::into_raw(map_read) as _,
+            );
+        }
+        drop(guard);
+        PendingMapRead { result }
+    }
+
+    /// Returns the contents if the map has completed, `Some(None)` if it failed.
+    pub(crate) fn take(&self) -> Option<Option<Vec<u8>>> {
+        self.result.lock().take()
+    }
+
+    /// Like `take`, but ticks the device first if the map hasn't completed yet.
+    pub(crate) fn poll(&self, device: &Device) -> Option<Option<Vec<u8>>> {
+        if let Some(data) = self.take() {
+            return Some(data);
+        }
+        device.tick();
+        self.take()
+    }
+
+    /// Ticks the device until the map has completed and returns the contents, `None` if the
+    /// map failed.
+    pub(crate) fn wait(&self, device: &Device) -> Option<Vec<u8>> {
+        loop {
+            if let Some(data) = self.poll(device) {
+                return data;
+            }
+            thread::yield_now();
+        }
+    }
+}
+
+unsafe extern "C" fn map_read_callback(
+    status: sys::WGPUBufferMapAsyncStatus,
+    data: *const libc::c_void,
+    data_length: u64,
+    userdata: *mut libc::c_void,
+) {
+    let map_read = Box::from_raw(userdata as *mut MapRead);
+    let data = if status == sys::WGPUBufferMapAsyncStatus_Success && !data.is_null() {
+        let data = slice::from_raw_parts(data as *const u8, data_length as usize).to_vec();
+        // Called from within dawn while the device is locked, so this can't go through
+        // `Buffer::unmap`.
+        sys::wgpuBufferUnmap(map_read.buffer.raw);
+        Some(data)
+    } else {
+        None
+    };
+    *map_read.result.lock() = Some(data);
+}
+
+/// The pending capture of a presented frame.
+pub struct FrameCapture {
+    frame: CaptureSlot,
+}
+
+impl FrameCapture {
+    /// Returns the image once the frame has been presented and read back, which happens at
+    /// the latest on the next `SwapChain::flush`.
+    pub fn image(&self) -> Option<Image> {
+        let frame = self.frame.lock();
+        frame.as_ref().map(|frame| {
+            Image::from_texels(frame.format, frame.width, frame.height, &frame.data)
+                .expect("capture format was checked")
+        })
+    }
+}
+
+type CaptureSlot = Arc<Mutex<Option<Frame>>>;
+
+/// The arguments of the last `SwapChain::configure` of a window swap chain.
+#[derive(Debug, Copy, Clone)]
+struct Configuration {
+    format: TextureFormat,
+    usage: TextureUsage,
+    width: u32,
+    height: u32,
+}
+
+/// The texture a captured frame is rendered into instead of the swap chain's.
+struct CapturedFrame {
+    texture: Texture,
+    configuration: Configuration,
+    captures: Vec<CaptureSlot>,
+}
+
+/// Draws a captured frame to the swap chain.
+struct Blit {
+    format: TextureFormat,
+    layout: BindGroupLayout,
+    pipeline: RenderPipeline,
+}
+
+struct WindowReadback {
+    map: PendingMapRead,
+    captures: Vec<CaptureSlot>,
+    index: u64,
+    configuration: Configuration,
+    bytes_per_row: usize,
+    row_size: usize,
+}
+
+/// Capture state of a swap chain that presents to a window.
+#[derive(Default)]
+pub(crate) struct WindowCapture {
+    configuration: Option<Configuration>,
+    /// Filled with the next frame that is started.
+    requests: Vec<CaptureSlot>,
+    frame: Option<CapturedFrame>,
+    blit: Option<Blit>,
+    /// Number of `present` calls, used as the index of captured frames.
+    presented: u64,
+    in_flight: VecDeque<WindowReadback>,
+}
+
+impl fmt::Debug for WindowCapture {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WindowCapture")
+            .field("configuration", &self.configuration)
+            .field("requests", &self.requests.len())
+            .field("in_flight", &self.in_flight.len())
+            .finish()
+    }
+}
+
+impl WindowCapture {
+    /// Fills the captures of the oldest readback in flight. Returns `false` if there is none
+    /// or it hasn't completed and `wait` is `false`.
+    fn receive(&mut self, device: &Device, wait: bool) -> bool {
+        let mapped = match self.in_flight.front() {
+            Some(readback) if wait => readback.map.wait(device),
+            Some(readback) => match readback.map.take() {
+                Some(mapped) => mapped,
+                None => return false,
+            },
+            None => return false,
+        };
+        let readback = self.in_flight.pop_front().unwrap();
+        if let Some(mapped) = mapped {
+            let Configuration {
+                format,
+                width,
+                height,
+                ..
+            } = readback.configuration;
+            let mut data = Vec::with_capacity(readback.row_size * height as usize);
+            for row in mapped.chunks(readback.bytes_per_row).take(height as usize) {
+                data.extend_from_slice(&row[..readback.row_size]);
+            }
+            let frame = Frame {
+                index: readback.index,
+                width,
+                height,
+                format,
+                data,
+            };
+            for capture in &readback.captures {
+                *capture.lock() = Some(frame.clone());
+            }
+        }
+        true
+    }
+
+    fn blit(&mut self, device: &Device, format: TextureFormat) -> &Blit {
+        if self.blit.as_ref().map(|blit| blit.format) != Some(format) {
+            let module =
+                device.create_shader_module_with_code(crate::include_spirv!("shaders/blit.spv"));
+            let layout = device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("capture blit"),
+                entries: &[BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: ShaderStage::FRAGMENT,
+                    ty: BindingType::SampledTexture {
+                        dimension: TextureViewDimension::D2,
+                        component_type: TextureComponentType::Float,
+                        multisampled: false,
+                    },
+                }],
+            });
+            let pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+                label: Some("capture blit"),
+                bind_group_layouts: std::slice::from_ref(&layout),
+            });
+            let replace = BlendDescriptor {
+                src_factor: BlendFactor::One,
+                dst_factor: BlendFactor::Zero,
+                operation: BlendOperation::Add,
+            };
+            let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+                label: Some("capture blit"),
+                layout: Some(&pipeline_layout),
+                vertex_stage: ProgrammableStageDescriptor {
+                    module: &module,
+                    entry_point: "vs_main",
+                },
+                fragment_stage: Some(ProgrammableStageDescriptor {
+                    module: &module,
+                    entry_point: "fs_main",
+                }),
+                vertex_state: &VertexStateDescriptor {
+                    index_format: IndexFormat::Uint16,
+                    vertex_buffers: &[],
+                },
+                primitive_topology: PrimitiveTopology::TriangleList,
+                rasterization_state: None,
+                sample_count: 1,
+                depth_stencil_state: None,
+                color_states: &[ColorStateDescriptor {
+                    format,
+                    write_mask: ColorWrite::ALL,
+                    color_blend: replace,
+                    alpha_blend: replace,
+                }],
+                sample_mask: 0xFFFF_FFFF,
+                alpha_to_coverage_enabled: false,
+            });
+            self.blit = Some(Blit {
+                format,
+                layout,
+                pipeline,
+            });
+        }
+        self.blit.as_ref().unwrap()
+    }
+}
+
+fn texture_view(texture: &Texture, format: TextureFormat) -> TextureView {
+    texture.create_view(&TextureViewDescriptor {
+        label: None,
+        format,
+        dimension: TextureViewDimension::D2,
+        base_mip_level: 0,
+        mip_level_count: 1,
+        base_array_layer: 0,
+        array_layer_count: 1,
+        aspect: TextureAspect::All,
+    })
+}
+
+pub(crate) fn configure(
+    capture: &Mutex<WindowCapture>,
+    format: TextureFormat,
+    usage: TextureUsage,
+    width: u32,
+    height: u32,
+) {
+    let mut capture = capture.lock();
+    capture.configuration = Some(Configuration {
+        format,
+        usage,
+        width,
+        height,
+    });
+    // A frame that was started with the old configuration is captured on the next one.
+    if let Some(frame) = capture.frame.take() {
+        capture.requests.extend(frame.captures);
+    }
+}
+
+/// Returns the view of the texture the current frame is rendered into if it is captured.
+pub(crate) fn current_texture_view(
+    capture: &Mutex<WindowCapture>,
+    device: &Device,
+) -> Option<TextureView> {
+    let mut capture = capture.lock();
+    if capture.frame.is_none() && !capture.requests.is_empty() {
+        let configuration = capture.configuration?;
+        let texture = device.create_texture(&TextureDescriptor {
+            label: Some("captured frame"),
+            usage: (configuration.usage - TextureUsage::PRESENT)
+                | TextureUsage::COPY_SRC
+                | TextureUsage::SAMPLED,
+            dimension: TextureDimension::D2,
+            size: Extent3d {
+                width: configuration.width,
+                height: configuration.height,
+                depth: 1,
+            },
+            array_layer_count: 1,
+            format: configuration.format,
+            mip_level_count: 1,
+            sample_count: 1,
+        });
+        capture.frame = Some(CapturedFrame {
+            texture,
+            configuration,
+            captures: std::mem::take(&mut capture.requests),
+        });
+    }
+    let frame = capture.frame.as_ref()?;
+    Some(texture_view(&frame.texture, frame.configuration.format))
+}
+
+/// Reads back a captured frame and draws it to `target`, the current view of the swap chain.
+/// Called before the swap chain presents.
+pub(crate) fn present(
+    capture: &Mutex<WindowCapture>,
+    device: &Device,
+    target: impl FnOnce() -> TextureView,
+) {
+    let mut capture = capture.lock();
+    let frame = match capture.frame.take() {
+        Some(frame) => frame,
+        None => {
+            capture.presented += 1;
+            capture.receive(device, false);
+            return;
+        }
+    };
+    let Configuration {
+        format,
+        width,
+        height,
+        ..
+    } = frame.configuration;
+    let row_size = convert::texel_size(format).expect("capture format was checked") * width;
+    let bytes_per_row = convert::padded_bytes_per_row(row_size);
+    let readback = device.create_buffer(&BufferDescriptor {
+        label: Some("captured frame"),
+        usage: BufferUsage::MAP_READ | BufferUsage::COPY_DST,
+        size: u64::from(bytes_per_row) * u64::from(height),
+    });
+
+    let blit = capture.blit(device, format);
+    let source = texture_view(&frame.texture, format);
+    let bind_group = device.create_bind_group(&BindGroupDescriptor {
+        label: None,
+        layout: &blit.layout,
+        entries: &[BindGroupEntry {
+            binding: 0,
+            resource: BindingResource::TextureView(&source),
+        }],
+    });
+    let target = target();
+    let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor { label: None });
+    let mut render_pass = encoder.begin_render_pass(&RenderPassDescriptor {
+        label: Some("capture blit"),
+        color_attachments: &[RenderPassColorAttachmentDescriptor {
+            attachment: &target,
+            resolve_target: None,
+            load_op: LoadOp::Clear,
+            store_op: StoreOp::Store,
+            clear_color: Color {
+                r: 0.0,
+                g: 0.0,
+                b: 0.0,
+                a: 1.0,
+            },
+        }],
+        depth_stencil_attachment: None,
+    });
+    render_pass.set_pipeline(&blit.pipeline);
+    render_pass.set_bind_group(0, &bind_group, &[]);
+    render_pass.draw(3, 1, 0, 0);
+    render_pass.end_pass();
+    encoder.copy_texture_to_buffer(
+        &TextureCopyView {
+            texture: &frame.texture,
+            mip_level: 0,
+            array_layer: 0,
+            origin: Origin3d { x: 0, y: 0, z: 0 },
+        },
+        &BufferCopyView {
+            buffer: &readback,
+            offset: 0,
+            bytes_per_row,
+            rows_per_image: height,
+        },
+        &Extent3d {
+            width,
+            height,
+            depth: 1,
+        },
+    );
+    device.default_queue().submit(&[encoder.finish()]);
+
+    let index = capture.presented;
+    capture.in_flight.push_back(WindowReadback {
+        map: PendingMapRead::new(device, &readback),
+        captures: frame.captures,
+        index,
+        configuration: frame.configuration,
+        bytes_per_row: bytes_per_row as usize,
+        row_size: row_size as usize,
+    });
+    capture.presented += 1;
+    capture.receive(device, false);
+}
+
+/// Waits until every captured frame has been read back.
+pub(crate) fn flush(capture: &Mutex<WindowCapture>, device: &Device) {
+    let mut capture = capture.lock();
+    while capture.receive(device, true) {}
+}
+
+impl SwapChain {
+    /// Captures the next frame that is presented. For a window swap chain, this is the next
+    /// frame whose `get_current_texture_view` is called. See the module documentation.
+    pub fn capture_next_frame(&self) -> Result<FrameCapture, CaptureError> {
+        let frame = Arc::new(Mutex::new(None));
+        match &self.offscreen {
+            Some(offscreen) => {
+                let mut offscreen = offscreen.lock();
+                // Checked here so that `FrameCapture::image` can't fail.
+                Image::from_texels(offscreen.format, 0, 0, &[])?;
+                offscreen.captures.push(frame.clone());
+            }
+            None => {
+                let mut capture = self.capture.lock();
+                let configuration = capture.configuration.ok_or(CaptureError::NotConfigured)?;
+                let format = configuration.format;
+                Image::from_texels(format, 0, 0, &[])?;
+                // The blit samples the frame as float.
+                if let TextureFormat::RGBA8Uint | TextureFormat::RG8Uint | TextureFormat::R8Uint =
+                    format
+                {
+                    return Err(CaptureError::UnsupportedFormat(format));
+                }
+                if !configuration
+                    .usage
+                    .contains(TextureUsage::OUTPUT_ATTACHMENT)
+                {
+                    return Err(CaptureError::MissingOutputAttachment);
+                }
+                capture.requests.push(frame.clone());
+            }
+        }
+        Ok(FrameCapture { frame })
+    }
+}
+
+#[test]
+fn bgra_is_swizzled_to_rgba() {
+    let image =
+        Image::from_texels(TextureFormat::BGRA8Unorm, 2, 1, &[1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+    assert_eq!([3, 2, 1, 4], image.pixel(0, 0));
+    assert_eq!([7, 6, 5, 8], image.pixel(1, 0));
+    assert_eq!(
+        Err(CaptureError::UnsupportedFormat(TextureFormat::RGBA16Float)),
+        Image::from_texels(TextureFormat::RGBA16Float, 1, 1, &[0; 8])
+    );
+}
+
+#[cfg(feature = "png")]
+#[test]
+fn png_round_trip() {
+    let image = Image {
+        width: 2,
+        height: 1,
+        data: vec![255, 0, 0, 255, 0, 0, 255, 128],
+    };
+    let decoded = Image::decode_png(&image.encode_png().unwrap()).unwrap();
+    assert_eq!(image, decoded);
+}
+
+#[cfg(feature = "mock")]
+#[test]
+fn offscreen_frame_capture() {
+    use crate::offscreen::{self, OffscreenSwapChainDescriptor};
+
+    let _guard = crate::mock::install();
+    let device = crate::mock::create_device();
+    let swap_chain = offscreen::create_swap_chain(
+        &device,
+        &OffscreenSwapChainDescriptor {
+            label: None,
+            usage: TextureUsage::OUTPUT_ATTACHMENT,
+            format: TextureFormat::BGRA8Unorm,
+            width: 3,
+            height: 2,
+            image_count: 2,
+        },
+    );
+    swap_chain.present();
+    let capture = swap_chain.capture_next_frame().unwrap();
+    assert!(capture.image().is_none());
+    swap_chain.present();
+    swap_chain.flush();
+    let image = capture.image().unwrap();
+    assert_eq!((3, 2, 24), (image.width, image.height, image.data.len()));
+}
+
+#[cfg(feature = "mock")]
+#[test]
+fn window_frame_capture() {
+    use crate::{PresentMode, SwapChainDescriptor};
+
+    let _guard = crate::mock::install();
+    let device = crate::mock::create_device();
+    let swap_chain = device.create_swap_chain(
+        None,
+        &SwapChainDescriptor {
+            label: None,
+            usage: TextureUsage::OUTPUT_ATTACHMENT,
+            format: TextureFormat::BGRA8Unorm,
+            width: 3,
+            height: 2,
+            present_mode: PresentMode::Fifo,
+            implementation: 0,
+        },
+    );
+    assert_eq!(
+        Some(CaptureError::NotConfigured),
+        swap_chain.capture_next_frame().err()
+    );
+    let usage = TextureUsage::OUTPUT_ATTACHMENT | TextureUsage::PRESENT;
+    swap_chain.configure(TextureFormat::BGRA8Unorm, usage, 3, 2);
+    let capture = swap_chain.capture_next_frame().unwrap();
+    crate::mock::take_calls();
+
+    let _view = swap_chain.get_current_texture_view();
+    swap_chain.present();
+    swap_chain.flush();
+    let image = capture.image().unwrap();
+    assert_eq!((3, 2, 24), (image.width, image.height, image.data.len()));
+
+    let calls: Vec<&str> = crate::mock::take_calls()
+        .iter()
+        .map(|call| call.name)
+        .filter(|name| {
+            [
+                "get_current_texture_view",
+                "draw",
+                "copy_texture_to_buffer",
+                "present",
+            ]
+            .contains(name)
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            "get_current_texture_view",
+            "draw",
+            "copy_texture_to_buffer",
+            "present",
+        ],
+        calls
+    );
+
+    // Frames without a pending capture render into the swap chain directly.
+    let _view = swap_chain.get_current_texture_view();
+    swap_chain.present();
+    let calls = crate::mock::take_calls();
+    assert!(!calls
+        .iter()
+        .any(|call| call.name == "copy_texture_to_buffer"));
+}
@@ -1,32 +1,42 @@
-use dawn_sys as sys;
-
+use std::convert::TryFrom;
 use std::{ffi, mem, ptr};
 
-use crate::{AdapterType, BackendType};
+use crate::{AdapterType, BackendType, TextureFormat};
 
 pub fn adapter_type(v: i32) -> AdapterType {
-    match v {
-        sys::WGPUAdapterType_DiscreteGPU => AdapterType::DiscreteGPU,
-        sys::WGPUAdapterType_IntegratedGPU => AdapterType::IntegratedGPU,
-        sys::WGPUAdapterType_CPU => AdapterType::CPU,
-        sys::WGPUAdapterType_Unknown => AdapterType::Unknown,
-        _ => panic!("invalid adapter type: {}", v),
-    }
+    AdapterType::try_from(v).unwrap_or(AdapterType::Unknown)
 }
 
 pub fn backend_type(v: i32) -> BackendType {
-    match v {
-        sys::WGPUBackendType_Vulkan => BackendType::Vulkan,
-        sys::WGPUBackendType_Metal => BackendType::Metal,
-        sys::WGPUBackendType_D3D11 => BackendType::D3D11,
-        sys::WGPUBackendType_D3D12 => BackendType::D3D12,
-        sys::WGPUBackendType_OpenGL => BackendType::OpenGL,
-        sys::WGPUBackendType_OpenGLES => BackendType::OpenGLES,
-        sys::WGPUBackendType_Null => BackendType::Null,
-        _ => panic!("invalid backend type: {}", v),
+    BackendType::from(v)
+}
+
+/// Returns the size in bytes of one texel, or `None` for depth and block compressed formats
+/// which can't be copied texel by texel.
+pub fn texel_size(format: TextureFormat) -> Option<u32> {
+    use TextureFormat::*;
+    match format {
+        R8Unorm | R8Snorm | R8Uint | R8Sint => Some(1),
+        R16Uint | R16Sint | R16Float | RG8Unorm | RG8Snorm | RG8Uint | RG8Sint => Some(2),
+        R32Float | R32Uint | R32Sint | RG16Uint | RG16Sint | RG16Float | RGBA8Unorm
+        | RGBA8UnormSrgb | RGBA8Snorm | RGBA8Uint | RGBA8Sint | BGRA8Unorm | BGRA8UnormSrgb
+        | RGB10A2Unorm | RG11B10Float => Some(4),
+        RG32Float | RG32Uint | RG32Sint | RGBA16Uint | RGBA16Sint | RGBA16Float => Some(8),
+        RGBA32Float | RGBA32Uint | RGBA32Sint => Some(16),
+        _ => None,
     }
 }
 
+/// Dawn requires the rows of texture to buffer copies to be aligned to this many bytes. This
+/// must be a power of two.
+pub const BYTES_PER_ROW_ALIGNMENT: u32 = 256;
+
+/// Rounds the size of a row of texels up to `BYTES_PER_ROW_ALIGNMENT`.
+pub fn padded_bytes_per_row(row_size: u32) -> u32 {
+    let alignment = BYTES_PER_ROW_ALIGNMENT;
+    (row_size + alignment - 1) & !(alignment - 1)
+}
+
 // 30 + 1 byte for len + 1 byte for discriminate = 32 bytes for Label::Inline
 const LABEL_MAX_INLINE_WITH_NULL_LEN: usize = 30;
 
@@ -39,6 +49,26 @@ pub enum Label {
     Empty,
 }
 
+#[test]
+fn unknown_enum_values() {
+    use crate::{ErrorType, UnknownEnumValue};
+    use dawn_sys as sys;
+
+    assert_eq!(AdapterType::Unknown, adapter_type(1000));
+    assert_eq!(BackendType::Unknown(1000), backend_type(1000));
+    assert_eq!(
+        BackendType::Vulkan,
+        backend_type(sys::WGPUBackendType_Vulkan)
+    );
+    assert_eq!(
+        Err(UnknownEnumValue {
+            type_name: "ErrorType",
+            value: 1000,
+        }),
+        ErrorType::try_from(1000)
+    );
+}
+
 #[test]
 fn label_enum_size() {
     assert_eq!(32, std::mem::size_of::<Label>());
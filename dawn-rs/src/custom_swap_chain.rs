@@ -0,0 +1,242 @@
+//! Swap chains implemented in Rust.
+//!
+//! Dawn drives an implementation based swap chain through the C callbacks of
+//! `DawnSwapChainImplementation`. `Device::create_custom_swap_chain` wraps a
+//! `SwapChainImplementation` in those callbacks, so a swap chain can present into a
+//! compositor or video pipeline. Dawn owns the implementation once the swap chain is
+//! created and drops it when the swap chain is released.
+
+use std::convert::TryFrom;
+use std::ffi::CString;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::sync::Arc;
+
+use dawn_sys as sys;
+
+use crate::{Device, PresentMode, SwapChain, SwapChainDescriptor, TextureFormat, TextureUsage};
+
+/// A backend specific texture id, name or pointer returned by `next_texture`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NextTexture {
+    /// A pointer, e.g. `id<MTLTexture>` or `ID3D12Resource*`.
+    Ptr(*mut libc::c_void),
+    /// A 64 bit handle, e.g. `VkImage`.
+    U64(u64),
+    /// A 32 bit name, e.g. an OpenGL texture.
+    U32(u32),
+}
+
+/// A swap chain implementation. The methods are called by dawn from the thread that uses the
+/// swap chain. Returning `Err` reports the message to dawn as a swap chain error.
+pub trait SwapChainImplementation: Send + 'static {
+    /// Called once when the swap chain is created. `wsi_context` points to the backend's
+    /// `DawnWSIContext{D3D12,Metal,GL}`, or is `null` for backends without one.
+    fn init(&mut self, wsi_context: *mut libc::c_void) {
+        let _ = wsi_context;
+    }
+
+    /// How the textures returned by `next_texture` are used. Read once when the swap chain is
+    /// created; used by the D3D12 and Vulkan backends.
+    fn texture_usage(&self) -> TextureUsage {
+        TextureUsage::OUTPUT_ATTACHMENT
+    }
+
+    fn configure(
+        &mut self,
+        format: TextureFormat,
+        allowed_usage: TextureUsage,
+        width: u32,
+        height: u32,
+    ) -> Result<(), String>;
+
+    /// Acquires the texture that the next frame is rendered into.
+    fn next_texture(&mut self) -> Result<NextTexture, String>;
+
+    /// Presents the texture returned by the last `next_texture`.
+    fn present(&mut self) -> Result<(), String>;
+}
+
+struct UserData {
+    implementation: Box<dyn SwapChainImplementation>,
+    // Dawn only reads the error until the next call, so the last one is kept alive here.
+    last_error: Option<CString>,
+}
+
+impl UserData {
+    fn result<F>(&mut self, f: F) -> sys::DawnSwapChainError
+    where
+        F: FnOnce(&mut dyn SwapChainImplementation) -> Result<(), String>,
+    {
+        let implementation = &mut *self.implementation;
+        let result = panic::catch_unwind(AssertUnwindSafe(|| f(implementation)))
+            .unwrap_or_else(|_| Err("swap chain implementation panicked".to_string()));
+        match result {
+            Ok(()) => {
+                self.last_error = None;
+                ptr::null()
+            }
+            Err(message) => {
+                let message = message.replace('\0', " ");
+                let error = CString::new(message).unwrap();
+                let raw = error.as_ptr();
+                self.last_error = Some(error);
+                raw
+            }
+        }
+    }
+}
+
+unsafe extern "C" fn init(user_data: *mut libc::c_void, wsi_context: *mut libc::c_void) {
+    let user_data = &mut *(user_data as *mut UserData);
+    user_data.result(|implementation| {
+        implementation.init(wsi_context);
+        Ok(())
+    });
+}
+
+unsafe extern "C" fn destroy(user_data: *mut libc::c_void) {
+    let user_data = Box::from_raw(user_data as *mut UserData);
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| drop(user_data)));
+}
+
+unsafe extern "C" fn configure(
+    user_data: *mut libc::c_void,
+    format: sys::WGPUTextureFormat,
+    allowed_usage: sys::WGPUTextureUsage,
+    width: u32,
+    height: u32,
+) -> sys::DawnSwapChainError {
+    let user_data = &mut *(user_data as *mut UserData);
+    user_data.result(|implementation| {
+        let format = TextureFormat::try_from(format).map_err(|err| err.to_string())?;
+        let allowed_usage = TextureUsage::from_bits_truncate(allowed_usage as _);
+        implementation.configure(format, allowed_usage, width, height)
+    })
+}
+
+unsafe extern "C" fn get_next_texture(
+    user_data: *mut libc::c_void,
+    next_texture: *mut sys::DawnSwapChainNextTexture,
+) -> sys::DawnSwapChainError {
+    let user_data = &mut *(user_data as *mut UserData);
+    user_data.result(|implementation| {
+        let texture = &mut (*next_texture).texture;
+        match implementation.next_texture()? {
+            NextTexture::Ptr(value) => *texture.ptr.as_mut() = value,
+            NextTexture::U64(value) => *texture.u64.as_mut() = value,
+            NextTexture::U32(value) => *texture.u32.as_mut() = value,
+        }
+        Ok(())
+    })
+}
+
+unsafe extern "C" fn present(user_data: *mut libc::c_void) -> sys::DawnSwapChainError {
+    let user_data = &mut *(user_data as *mut UserData);
+    user_data.result(|implementation| implementation.present())
+}
+
+/// Wraps `implementation` in the C callbacks. Ownership passes to whoever calls `Destroy`.
+fn raw_implementation<T: SwapChainImplementation>(
+    implementation: T,
+) -> sys::DawnSwapChainImplementation {
+    let texture_usage = implementation.texture_usage();
+    let user_data = Box::new(UserData {
+        implementation: Box::new(implementation),
+        last_error: None,
+    });
+    sys::DawnSwapChainImplementation {
+        Init: Some(init),
+        Destroy: Some(destroy),
+        Configure: Some(configure),
+        GetNextTexture: Some(get_next_texture),
+        Present: Some(present),
+        userData: Box::into_raw(user_data) as _,
+        textureUsage: texture_usage.bits() as _,
+    }
+}
+
+impl Device {
+    /// Creates a swap chain that presents through `implementation`. Call `SwapChain::configure`
+    /// before acquiring the first texture.
+    pub fn create_custom_swap_chain<T: SwapChainImplementation>(
+        &self,
+        implementation: T,
+    ) -> SwapChain {
+        let dawn_swap_chain_impl = Arc::new(raw_implementation(implementation));
+        // The format, usage and size of implementation based swap chains are set by
+        // `configure`.
+        let descriptor = SwapChainDescriptor {
+            label: None,
+            usage: TextureUsage::OUTPUT_ATTACHMENT,
+            format: TextureFormat::BGRA8Unorm,
+            width: 0,
+            height: 0,
+            present_mode: PresentMode::Fifo,
+            implementation: dawn_swap_chain_impl.as_ref() as *const _ as u64,
+        };
+        let mut swap_chain = self.create_swap_chain(None, &descriptor);
+        swap_chain.dawn_swap_chain_impl = Some(dawn_swap_chain_impl);
+        swap_chain
+    }
+}
+
+#[test]
+fn custom_swap_chain_callbacks() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct Counting(Arc<AtomicUsize>);
+
+    impl SwapChainImplementation for Counting {
+        fn configure(
+            &mut self,
+            format: TextureFormat,
+            _: TextureUsage,
+            width: u32,
+            height: u32,
+        ) -> Result<(), String> {
+            assert_eq!(
+                (TextureFormat::RGBA8Unorm, 640, 480),
+                (format, width, height)
+            );
+            Ok(())
+        }
+
+        fn next_texture(&mut self) -> Result<NextTexture, String> {
+            Ok(NextTexture::U64(
+                self.0.fetch_add(1, Ordering::SeqCst) as u64
+            ))
+        }
+
+        fn present(&mut self) -> Result<(), String> {
+            Err("presented".to_string())
+        }
+    }
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let raw = raw_implementation(Counting(count.clone()));
+    unsafe {
+        let configured = raw.Configure.unwrap()(
+            raw.userData,
+            sys::WGPUTextureFormat_RGBA8Unorm,
+            sys::WGPUTextureUsage_OutputAttachment,
+            640,
+            480,
+        );
+        assert!(configured.is_null());
+
+        let mut next_texture: sys::DawnSwapChainNextTexture = std::mem::zeroed();
+        raw.GetNextTexture.unwrap()(raw.userData, &mut next_texture);
+        raw.GetNextTexture.unwrap()(raw.userData, &mut next_texture);
+        assert_eq!(1, *next_texture.texture.u64.as_ref());
+
+        let error = raw.Present.unwrap()(raw.userData);
+        assert_eq!(
+            "presented",
+            std::ffi::CStr::from_ptr(error).to_str().unwrap()
+        );
+
+        raw.Destroy.unwrap()(raw.userData);
+    }
+    assert_eq!(1, Arc::strong_count(&count));
+}
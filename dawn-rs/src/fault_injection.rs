@@ -0,0 +1,382 @@
+//! Fault injection for robustness testing.
+//!
+//! Installing a fault injector wraps the `deviceCreate*` entries of the active proc table so
+//! that selected calls fail on a deterministic schedule. The same seed and rules always fail
+//! the same calls, which makes error handling paths reproducible in tests.
+//!
+//! ```no_run
+//! use dawn::fault_injection::{self, EntryPoint, Fault, FaultInjectionDescriptor, FaultRule, Schedule};
+//!
+//! unsafe {
+//!     fault_injection::install(&FaultInjectionDescriptor {
+//!         seed: 42,
+//!         rules: &[FaultRule {
+//!             entry_point: EntryPoint::CreateBuffer,
+//!             schedule: Schedule::Every(3),
+//!             fault: Fault::OutOfMemory,
+//!         }],
+//!     });
+//! }
+//! ```
+
+use std::ptr;
+
+use parking_lot::Mutex;
+use unchecked_unwrap::UncheckedUnwrap;
+
+use dawn_sys as sys;
+
+use crate::{init_procs, ErrorType, PROC_TABLE};
+
+/// The proc table entries that may be failed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EntryPoint {
+    CreateBindGroup,
+    CreateBindGroupLayout,
+    CreateBuffer,
+    CreateBufferMapped,
+    CreateCommandEncoder,
+    CreateComputePipeline,
+    CreatePipelineLayout,
+    CreateRenderBundleEncoder,
+    CreateRenderPipeline,
+    CreateSampler,
+    CreateShaderModule,
+    CreateSwapChain,
+    CreateTexture,
+}
+
+const ENTRY_POINT_COUNT: usize = EntryPoint::CreateTexture as usize + 1;
+
+/// How a failed call behaves.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Fault {
+    /// Return a null handle without calling the underlying implementation.
+    NullHandle,
+    /// Report an `ErrorType::OutOfMemory` error on the device and return a null handle.
+    OutOfMemory,
+    /// Lose the device with `deviceLoseForTesting` and then forward the call, so that the
+    /// implementation returns whatever it returns for a lost device.
+    DeviceLost,
+}
+
+/// When a rule fails a call. Call numbers are counted per entry point and start at `1`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Schedule {
+    /// Fail only the `n`th call.
+    Nth(u64),
+    /// Fail every `n`th call.
+    Every(u64),
+    /// Fail every call after the first `n`.
+    After(u64),
+    /// Fail each call with the given probability (`0.0..=1.0`), drawn from the seeded generator.
+    Random(f32),
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct FaultRule {
+    pub entry_point: EntryPoint,
+    pub schedule: Schedule,
+    pub fault: Fault,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct FaultInjectionDescriptor<'a> {
+    /// Seed for `Schedule::Random`. Each entry point draws from its own sequence, so the
+    /// outcome for one entry point does not depend on how calls to others are interleaved.
+    pub seed: u64,
+    /// Rules are checked in order and the first one whose schedule matches is applied.
+    pub rules: &'a [FaultRule],
+}
+
+struct FaultState {
+    original: sys::DawnProcTable,
+    rules: Vec<FaultRule>,
+    calls: [u64; ENTRY_POINT_COUNT],
+    faults: [u64; ENTRY_POINT_COUNT],
+    rng: [u64; ENTRY_POINT_COUNT],
+}
+
+unsafe impl Send for FaultState {}
+
+static STATE: Mutex<Option<FaultState>> = parking_lot::const_mutex(None);
+
+impl FaultState {
+    fn new(original: sys::DawnProcTable, descriptor: &FaultInjectionDescriptor) -> FaultState {
+        let mut rng = [0; ENTRY_POINT_COUNT];
+        for (i, state) in rng.iter_mut().enumerate() {
+            *state = splitmix64(descriptor.seed ^ (i as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        }
+        FaultState {
+            original,
+            rules: descriptor.rules.to_vec(),
+            calls: [0; ENTRY_POINT_COUNT],
+            faults: [0; ENTRY_POINT_COUNT],
+            rng,
+        }
+    }
+
+    fn next_fault(&mut self, entry_point: EntryPoint) -> Option<Fault> {
+        let index = entry_point as usize;
+        self.calls[index] += 1;
+        let call = self.calls[index];
+        // Draw once per call so the random sequence is independent of which rules match.
+        self.rng[index] = splitmix64(self.rng[index]);
+        let sample = (self.rng[index] >> 11) as f64 / (1u64 << 53) as f64;
+        let fault = self
+            .rules
+            .iter()
+            .filter(|rule| rule.entry_point == entry_point)
+            .find(|rule| match rule.schedule {
+                Schedule::Nth(n) => call == n,
+                Schedule::Every(n) => n > 0 && call % n == 0,
+                Schedule::After(n) => call > n,
+                Schedule::Random(probability) => sample < f64::from(probability),
+            })
+            .map(|rule| rule.fault);
+        if fault.is_some() {
+            self.faults[index] += 1;
+        }
+        fault
+    }
+}
+
+fn splitmix64(state: u64) -> u64 {
+    let mut z = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Applies `fault` to `device` and returns `true` if the call should return a null handle.
+unsafe fn apply_fault(
+    device: sys::WGPUDevice,
+    fault: Fault,
+    inject_error: sys::WGPUProcDeviceInjectError,
+    lose_for_testing: sys::WGPUProcDeviceLoseForTesting,
+) -> bool {
+    match fault {
+        Fault::NullHandle => true,
+        Fault::OutOfMemory => {
+            let message = crate::convert::label(Some("injected out of memory fault"));
+            if let Some(inject_error) = inject_error {
+                inject_error(device, ErrorType::OutOfMemory as _, message.as_ptr());
+            }
+            true
+        }
+        Fault::DeviceLost => {
+            if let Some(lose_for_testing) = lose_for_testing {
+                lose_for_testing(device);
+            }
+            false
+        }
+    }
+}
+
+macro_rules! fault_trampoline {
+    ($name:ident, $entry_point:ident, $proc:ident, ($($arg:ident: $ty:ty),*) -> $ret:ty, $null:expr) => {
+        unsafe extern "C" fn $name(device: sys::WGPUDevice, $($arg: $ty),*) -> $ret {
+            let mut guard = STATE.lock();
+            let (fault, original, inject_error, lose_for_testing) = match guard.as_mut() {
+                Some(state) => (
+                    state.next_fault(EntryPoint::$entry_point),
+                    state.original.$proc,
+                    state.original.deviceInjectError,
+                    state.original.deviceLoseForTesting,
+                ),
+                None => (None, (*PROC_TABLE.as_ptr()).$proc, None, None),
+            };
+            // The error callback may run synchronously, so don't hold the lock while calling out.
+            drop(guard);
+            if let Some(fault) = fault {
+                if apply_fault(device, fault, inject_error, lose_for_testing) {
+                    return $null;
+                }
+            }
+            original.unchecked_unwrap()(device, $($arg),*)
+        }
+    };
+}
+
+fault_trampoline! {
+    device_create_bind_group, CreateBindGroup, deviceCreateBindGroup,
+    (descriptor: *const sys::WGPUBindGroupDescriptor) -> sys::WGPUBindGroup,
+    ptr::null_mut()
+}
+
+fault_trampoline! {
+    device_create_bind_group_layout, CreateBindGroupLayout, deviceCreateBindGroupLayout,
+    (descriptor: *const sys::WGPUBindGroupLayoutDescriptor) -> sys::WGPUBindGroupLayout,
+    ptr::null_mut()
+}
+
+fault_trampoline! {
+    device_create_buffer, CreateBuffer, deviceCreateBuffer,
+    (descriptor: *const sys::WGPUBufferDescriptor) -> sys::WGPUBuffer,
+    ptr::null_mut()
+}
+
+fault_trampoline! {
+    device_create_buffer_mapped, CreateBufferMapped, deviceCreateBufferMapped,
+    (descriptor: *const sys::WGPUBufferDescriptor) -> sys::WGPUCreateBufferMappedResult,
+    sys::WGPUCreateBufferMappedResult {
+        buffer: ptr::null_mut(),
+        dataLength: 0,
+        data: ptr::null_mut(),
+    }
+}
+
+fault_trampoline! {
+    device_create_command_encoder, CreateCommandEncoder, deviceCreateCommandEncoder,
+    (descriptor: *const sys::WGPUCommandEncoderDescriptor) -> sys::WGPUCommandEncoder,
+    ptr::null_mut()
+}
+
+fault_trampoline! {
+    device_create_compute_pipeline, CreateComputePipeline, deviceCreateComputePipeline,
+    (descriptor: *const sys::WGPUComputePipelineDescriptor) -> sys::WGPUComputePipeline,
+    ptr::null_mut()
+}
+
+fault_trampoline! {
+    device_create_pipeline_layout, CreatePipelineLayout, deviceCreatePipelineLayout,
+    (descriptor: *const sys::WGPUPipelineLayoutDescriptor) -> sys::WGPUPipelineLayout,
+    ptr::null_mut()
+}
+
+fault_trampoline! {
+    device_create_render_bundle_encoder, CreateRenderBundleEncoder, deviceCreateRenderBundleEncoder,
+    (descriptor: *const sys::WGPURenderBundleEncoderDescriptor) -> sys::WGPURenderBundleEncoder,
+    ptr::null_mut()
+}
+
+fault_trampoline! {
+    device_create_render_pipeline, CreateRenderPipeline, deviceCreateRenderPipeline,
+    (descriptor: *const sys::WGPURenderPipelineDescriptor) -> sys::WGPURenderPipeline,
+    ptr::null_mut()
+}
+
+fault_trampoline! {
+    device_create_sampler, CreateSampler, deviceCreateSampler,
+    (descriptor: *const sys::WGPUSamplerDescriptor) -> sys::WGPUSampler,
+    ptr::null_mut()
+}
+
+fault_trampoline! {
+    device_create_shader_module, CreateShaderModule, deviceCreateShaderModule,
+    (descriptor: *const sys::WGPUShaderModuleDescriptor) -> sys::WGPUShaderModule,
+    ptr::null_mut()
+}
+
+fault_trampoline! {
+    device_create_swap_chain, CreateSwapChain, deviceCreateSwapChain,
+    (surface: sys::WGPUSurface, descriptor: *const sys::WGPUSwapChainDescriptor) -> sys::WGPUSwapChain,
+    ptr::null_mut()
+}
+
+fault_trampoline! {
+    device_create_texture, CreateTexture, deviceCreateTexture,
+    (descriptor: *const sys::WGPUTextureDescriptor) -> sys::WGPUTexture,
+    ptr::null_mut()
+}
+
+/// Wraps the active proc table with the fault injector. Installing again replaces the
+/// previous rules and resets all call counters.
+///
+/// # Safety
+///
+/// The proc table must not be in use by another thread while it is being replaced.
+pub unsafe fn install(descriptor: &FaultInjectionDescriptor) {
+    uninstall();
+    init_procs();
+
+    let original = ptr::read(PROC_TABLE.as_ptr());
+    *STATE.lock() = Some(FaultState::new(original, descriptor));
+
+    let procs = &mut *PROC_TABLE.as_mut_ptr();
+    procs.deviceCreateBindGroup = Some(device_create_bind_group);
+    procs.deviceCreateBindGroupLayout = Some(device_create_bind_group_layout);
+    procs.deviceCreateBuffer = Some(device_create_buffer);
+    procs.deviceCreateBufferMapped = Some(device_create_buffer_mapped);
+    procs.deviceCreateCommandEncoder = Some(device_create_command_encoder);
+    procs.deviceCreateComputePipeline = Some(device_create_compute_pipeline);
+    procs.deviceCreatePipelineLayout = Some(device_create_pipeline_layout);
+    procs.deviceCreateRenderBundleEncoder = Some(device_create_render_bundle_encoder);
+    procs.deviceCreateRenderPipeline = Some(device_create_render_pipeline);
+    procs.deviceCreateSampler = Some(device_create_sampler);
+    procs.deviceCreateShaderModule = Some(device_create_shader_module);
+    procs.deviceCreateSwapChain = Some(device_create_swap_chain);
+    procs.deviceCreateTexture = Some(device_create_texture);
+    sys::dawnProcSetProcs(PROC_TABLE.as_ptr());
+}
+
+/// Restores the proc table that was active before `install`. Does nothing if the fault
+/// injector isn't installed.
+///
+/// # Safety
+///
+/// The proc table must not be in use by another thread while it is being replaced.
+pub unsafe fn uninstall() {
+    if let Some(state) = STATE.lock().take() {
+        PROC_TABLE.as_mut_ptr().write(state.original);
+        sys::dawnProcSetProcs(PROC_TABLE.as_ptr());
+    }
+}
+
+/// Returns the number of calls made to `entry_point` since the fault injector was installed.
+pub fn call_count(entry_point: EntryPoint) -> u64 {
+    STATE
+        .lock()
+        .as_ref()
+        .map(|state| state.calls[entry_point as usize])
+        .unwrap_or(0)
+}
+
+/// Returns the number of faults injected into `entry_point` since the fault injector was
+/// installed.
+pub fn fault_count(entry_point: EntryPoint) -> u64 {
+    STATE
+        .lock()
+        .as_ref()
+        .map(|state| state.faults[entry_point as usize])
+        .unwrap_or(0)
+}
+
+#[test]
+fn fault_schedule_is_deterministic() {
+    let rules = [
+        FaultRule {
+            entry_point: EntryPoint::CreateBuffer,
+            schedule: Schedule::Every(3),
+            fault: Fault::NullHandle,
+        },
+        FaultRule {
+            entry_point: EntryPoint::CreateTexture,
+            schedule: Schedule::Random(0.5),
+            fault: Fault::OutOfMemory,
+        },
+    ];
+    let descriptor = FaultInjectionDescriptor {
+        seed: 7,
+        rules: &rules,
+    };
+    let run = || {
+        let mut state = FaultState::new(unsafe { std::mem::zeroed() }, &descriptor);
+        (0..32)
+            .map(|i| {
+                let entry_point = if i % 2 == 0 {
+                    EntryPoint::CreateBuffer
+                } else {
+                    EntryPoint::CreateTexture
+                };
+                state.next_fault(entry_point)
+            })
+            .collect::<Vec<_>>()
+    };
+    let faults = run();
+    assert_eq!(faults, run());
+    let buffer_faults: Vec<_> = faults.iter().step_by(2).collect();
+    assert_eq!(None, *buffer_faults[1]);
+    assert_eq!(Some(Fault::NullHandle), *buffer_faults[2]);
+    assert_eq!(Some(Fault::NullHandle), *buffer_faults[5]);
+}
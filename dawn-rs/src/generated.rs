@@ -0,0 +1,1298 @@
+// Generated by dawn-gen from dawn.json. Do not edit.
+
+use std::any::Any;
+use std::ffi::CString;
+use std::ptr;
+
+use dawn_sys as sys;
+
+#[allow(unused_imports)]
+use crate::*;
+
+/// Owns the memory that lowered descriptors point into. Keep it alive until the raw
+/// descriptor has been passed to dawn.
+#[derive(Default)]
+pub(crate) struct Arena {
+    items: Vec<Box<dyn Any>>,
+}
+
+impl Arena {
+    pub(crate) fn label(&mut self, label: Option<&str>) -> *const libc::c_char {
+        match label {
+            Some(label) => {
+                let label = Box::new(CString::new(label).unwrap());
+                let raw = label.as_ptr();
+                self.items.push(label);
+                raw
+            }
+            None => ptr::null(),
+        }
+    }
+
+    pub(crate) fn boxed<T: 'static>(&mut self, value: T) -> *const T {
+        let value = Box::new(value);
+        let raw = &*value as *const T;
+        self.items.push(value);
+        raw
+    }
+
+    pub(crate) fn slice<T: 'static>(&mut self, values: Vec<T>) -> *const T {
+        if values.is_empty() {
+            return ptr::null();
+        }
+        let values = Box::new(values.into_boxed_slice());
+        let raw = values.as_ptr();
+        self.items.push(values);
+        raw
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gpuadaptertype>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum AdapterType {
+        DiscreteGPU = sys::WGPUAdapterType_DiscreteGPU,
+        IntegratedGPU = sys::WGPUAdapterType_IntegratedGPU,
+        CPU = sys::WGPUAdapterType_CPU,
+        Unknown = sys::WGPUAdapterType_Unknown,
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gpuaddressmode>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum AddressMode {
+        Repeat = sys::WGPUAddressMode_Repeat,
+        MirrorRepeat = sys::WGPUAddressMode_MirrorRepeat,
+        ClampToEdge = sys::WGPUAddressMode_ClampToEdge,
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gpublendfactor>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum BlendFactor {
+        Zero = sys::WGPUBlendFactor_Zero,
+        One = sys::WGPUBlendFactor_One,
+        SrcColor = sys::WGPUBlendFactor_SrcColor,
+        OneMinusSrcColor = sys::WGPUBlendFactor_OneMinusSrcColor,
+        SrcAlpha = sys::WGPUBlendFactor_SrcAlpha,
+        OneMinusSrcAlpha = sys::WGPUBlendFactor_OneMinusSrcAlpha,
+        DstColor = sys::WGPUBlendFactor_DstColor,
+        OneMinusDstColor = sys::WGPUBlendFactor_OneMinusDstColor,
+        DstAlpha = sys::WGPUBlendFactor_DstAlpha,
+        OneMinusDstAlpha = sys::WGPUBlendFactor_OneMinusDstAlpha,
+        SrcAlphaSaturated = sys::WGPUBlendFactor_SrcAlphaSaturated,
+        BlendColor = sys::WGPUBlendFactor_BlendColor,
+        OneMinusBlendColor = sys::WGPUBlendFactor_OneMinusBlendColor,
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gpublendoperation>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum BlendOperation {
+        Add = sys::WGPUBlendOperation_Add,
+        Subtract = sys::WGPUBlendOperation_Subtract,
+        ReverseSubtract = sys::WGPUBlendOperation_ReverseSubtract,
+        Min = sys::WGPUBlendOperation_Min,
+        Max = sys::WGPUBlendOperation_Max,
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gpubuffermapasyncstatus>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum BufferMapAsyncStatus {
+        Success = sys::WGPUBufferMapAsyncStatus_Success,
+        Error = sys::WGPUBufferMapAsyncStatus_Error,
+        Unknown = sys::WGPUBufferMapAsyncStatus_Unknown,
+        DeviceLost = sys::WGPUBufferMapAsyncStatus_DeviceLost,
+    }
+}
+
+bitflags! {
+    /// <https://gpuweb.github.io/gpuweb/#typedefdef-gpubufferusage>
+    pub struct BufferUsage: i32 {
+        const NONE = sys::WGPUBufferUsage_None;
+        const MAP_READ = sys::WGPUBufferUsage_MapRead;
+        const MAP_WRITE = sys::WGPUBufferUsage_MapWrite;
+        const COPY_SRC = sys::WGPUBufferUsage_CopySrc;
+        const COPY_DST = sys::WGPUBufferUsage_CopyDst;
+        const INDEX = sys::WGPUBufferUsage_Index;
+        const VERTEX = sys::WGPUBufferUsage_Vertex;
+        const UNIFORM = sys::WGPUBufferUsage_Uniform;
+        const STORAGE = sys::WGPUBufferUsage_Storage;
+        const INDIRECT = sys::WGPUBufferUsage_Indirect;
+    }
+}
+
+bitflags! {
+    /// <https://gpuweb.github.io/gpuweb/#typedefdef-gpucolorwritemask>
+    pub struct ColorWrite: i32 {
+        const NONE = sys::WGPUColorWriteMask_None;
+        const RED = sys::WGPUColorWriteMask_Red;
+        const GREEN = sys::WGPUColorWriteMask_Green;
+        const BLUE = sys::WGPUColorWriteMask_Blue;
+        const ALPHA = sys::WGPUColorWriteMask_Alpha;
+        const ALL = sys::WGPUColorWriteMask_All;
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gpucomparefunction>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum CompareFunction {
+        Never = sys::WGPUCompareFunction_Never,
+        Less = sys::WGPUCompareFunction_Less,
+        LessEqual = sys::WGPUCompareFunction_LessEqual,
+        Greater = sys::WGPUCompareFunction_Greater,
+        GreaterEqual = sys::WGPUCompareFunction_GreaterEqual,
+        Equal = sys::WGPUCompareFunction_Equal,
+        NotEqual = sys::WGPUCompareFunction_NotEqual,
+        Always = sys::WGPUCompareFunction_Always,
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gpucullmode>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum CullMode {
+        None = sys::WGPUCullMode_None,
+        Front = sys::WGPUCullMode_Front,
+        Back = sys::WGPUCullMode_Back,
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gpuerrorfilter>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum ErrorFilter {
+        None = sys::WGPUErrorFilter_None,
+        Validation = sys::WGPUErrorFilter_Validation,
+        OutOfMemory = sys::WGPUErrorFilter_OutOfMemory,
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gpuerrortype>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum ErrorType {
+        NoError = sys::WGPUErrorType_NoError,
+        Validation = sys::WGPUErrorType_Validation,
+        OutOfMemory = sys::WGPUErrorType_OutOfMemory,
+        Unknown = sys::WGPUErrorType_Unknown,
+        DeviceLost = sys::WGPUErrorType_DeviceLost,
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gpufencecompletionstatus>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum FenceCompletionStatus {
+        Success = sys::WGPUFenceCompletionStatus_Success,
+        Error = sys::WGPUFenceCompletionStatus_Error,
+        Unknown = sys::WGPUFenceCompletionStatus_Unknown,
+        DeviceLost = sys::WGPUFenceCompletionStatus_DeviceLost,
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gpufiltermode>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum FilterMode {
+        Nearest = sys::WGPUFilterMode_Nearest,
+        Linear = sys::WGPUFilterMode_Linear,
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gpufrontface>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum FrontFace {
+        Ccw = sys::WGPUFrontFace_CCW,
+        Cw = sys::WGPUFrontFace_CW,
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gpuindexformat>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum IndexFormat {
+        Uint16 = sys::WGPUIndexFormat_Uint16,
+        Uint32 = sys::WGPUIndexFormat_Uint32,
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gpuinputstepmode>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum InputStepMode {
+        Vertex = sys::WGPUInputStepMode_Vertex,
+        Instance = sys::WGPUInputStepMode_Instance,
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gpuloadop>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum LoadOp {
+        Clear = sys::WGPULoadOp_Clear,
+        Load = sys::WGPULoadOp_Load,
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gpupresentmode>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum PresentMode {
+        Immediate = sys::WGPUPresentMode_Immediate,
+        Mailbox = sys::WGPUPresentMode_Mailbox,
+        Fifo = sys::WGPUPresentMode_Fifo,
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gpuprimitivetopology>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum PrimitiveTopology {
+        PointList = sys::WGPUPrimitiveTopology_PointList,
+        LineList = sys::WGPUPrimitiveTopology_LineList,
+        LineStrip = sys::WGPUPrimitiveTopology_LineStrip,
+        TriangleList = sys::WGPUPrimitiveTopology_TriangleList,
+        TriangleStrip = sys::WGPUPrimitiveTopology_TriangleStrip,
+    }
+}
+
+bitflags! {
+    /// <https://gpuweb.github.io/gpuweb/#typedefdef-gpushaderstage>
+    pub struct ShaderStage: i32 {
+        const NONE = sys::WGPUShaderStage_None;
+        const VERTEX = sys::WGPUShaderStage_Vertex;
+        const FRAGMENT = sys::WGPUShaderStage_Fragment;
+        const COMPUTE = sys::WGPUShaderStage_Compute;
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gpustenciloperation>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum StencilOperation {
+        Keep = sys::WGPUStencilOperation_Keep,
+        Zero = sys::WGPUStencilOperation_Zero,
+        Replace = sys::WGPUStencilOperation_Replace,
+        Invert = sys::WGPUStencilOperation_Invert,
+        IncrementClamp = sys::WGPUStencilOperation_IncrementClamp,
+        DecrementClamp = sys::WGPUStencilOperation_DecrementClamp,
+        IncrementWrap = sys::WGPUStencilOperation_IncrementWrap,
+        DecrementWrap = sys::WGPUStencilOperation_DecrementWrap,
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gpustoreop>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum StoreOp {
+        Store = sys::WGPUStoreOp_Store,
+        Clear = sys::WGPUStoreOp_Clear,
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gputextureaspect>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum TextureAspect {
+        All = sys::WGPUTextureAspect_All,
+        StencilOnly = sys::WGPUTextureAspect_StencilOnly,
+        DepthOnly = sys::WGPUTextureAspect_DepthOnly,
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gputexturecomponenttype>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum TextureComponentType {
+        Float = sys::WGPUTextureComponentType_Float,
+        Sint = sys::WGPUTextureComponentType_Sint,
+        Uint = sys::WGPUTextureComponentType_Uint,
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gputexturedimension>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum TextureDimension {
+        D1 = sys::WGPUTextureDimension_1D,
+        D2 = sys::WGPUTextureDimension_2D,
+        D3 = sys::WGPUTextureDimension_3D,
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gputextureformat>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum TextureFormat {
+        Undefined = sys::WGPUTextureFormat_Undefined,
+        R8Unorm = sys::WGPUTextureFormat_R8Unorm,
+        R8Snorm = sys::WGPUTextureFormat_R8Snorm,
+        R8Uint = sys::WGPUTextureFormat_R8Uint,
+        R8Sint = sys::WGPUTextureFormat_R8Sint,
+        R16Uint = sys::WGPUTextureFormat_R16Uint,
+        R16Sint = sys::WGPUTextureFormat_R16Sint,
+        R16Float = sys::WGPUTextureFormat_R16Float,
+        RG8Unorm = sys::WGPUTextureFormat_RG8Unorm,
+        RG8Snorm = sys::WGPUTextureFormat_RG8Snorm,
+        RG8Uint = sys::WGPUTextureFormat_RG8Uint,
+        RG8Sint = sys::WGPUTextureFormat_RG8Sint,
+        R32Float = sys::WGPUTextureFormat_R32Float,
+        R32Uint = sys::WGPUTextureFormat_R32Uint,
+        R32Sint = sys::WGPUTextureFormat_R32Sint,
+        RG16Uint = sys::WGPUTextureFormat_RG16Uint,
+        RG16Sint = sys::WGPUTextureFormat_RG16Sint,
+        RG16Float = sys::WGPUTextureFormat_RG16Float,
+        RGBA8Unorm = sys::WGPUTextureFormat_RGBA8Unorm,
+        RGBA8UnormSrgb = sys::WGPUTextureFormat_RGBA8UnormSrgb,
+        RGBA8Snorm = sys::WGPUTextureFormat_RGBA8Snorm,
+        RGBA8Uint = sys::WGPUTextureFormat_RGBA8Uint,
+        RGBA8Sint = sys::WGPUTextureFormat_RGBA8Sint,
+        BGRA8Unorm = sys::WGPUTextureFormat_BGRA8Unorm,
+        BGRA8UnormSrgb = sys::WGPUTextureFormat_BGRA8UnormSrgb,
+        RGB10A2Unorm = sys::WGPUTextureFormat_RGB10A2Unorm,
+        RG11B10Float = sys::WGPUTextureFormat_RG11B10Float,
+        RG32Float = sys::WGPUTextureFormat_RG32Float,
+        RG32Uint = sys::WGPUTextureFormat_RG32Uint,
+        RG32Sint = sys::WGPUTextureFormat_RG32Sint,
+        RGBA16Uint = sys::WGPUTextureFormat_RGBA16Uint,
+        RGBA16Sint = sys::WGPUTextureFormat_RGBA16Sint,
+        RGBA16Float = sys::WGPUTextureFormat_RGBA16Float,
+        RGBA32Float = sys::WGPUTextureFormat_RGBA32Float,
+        RGBA32Uint = sys::WGPUTextureFormat_RGBA32Uint,
+        RGBA32Sint = sys::WGPUTextureFormat_RGBA32Sint,
+        Depth32Float = sys::WGPUTextureFormat_Depth32Float,
+        Depth24Plus = sys::WGPUTextureFormat_Depth24Plus,
+        Depth24PlusStencil8 = sys::WGPUTextureFormat_Depth24PlusStencil8,
+        BC1RGBAUnorm = sys::WGPUTextureFormat_BC1RGBAUnorm,
+        BC1RGBAUnormSrgb = sys::WGPUTextureFormat_BC1RGBAUnormSrgb,
+        BC2RGBAUnorm = sys::WGPUTextureFormat_BC2RGBAUnorm,
+        BC2RGBAUnormSrgb = sys::WGPUTextureFormat_BC2RGBAUnormSrgb,
+        BC3RGBAUnorm = sys::WGPUTextureFormat_BC3RGBAUnorm,
+        BC3RGBAUnormSrgb = sys::WGPUTextureFormat_BC3RGBAUnormSrgb,
+        BC4RUnorm = sys::WGPUTextureFormat_BC4RUnorm,
+        BC4RSnorm = sys::WGPUTextureFormat_BC4RSnorm,
+        BC5RGUnorm = sys::WGPUTextureFormat_BC5RGUnorm,
+        BC5RGSnorm = sys::WGPUTextureFormat_BC5RGSnorm,
+        BC6HRGBUfloat = sys::WGPUTextureFormat_BC6HRGBUfloat,
+        BC6HRGBSfloat = sys::WGPUTextureFormat_BC6HRGBSfloat,
+        BC7RGBAUnorm = sys::WGPUTextureFormat_BC7RGBAUnorm,
+        BC7RGBAUnormSrgb = sys::WGPUTextureFormat_BC7RGBAUnormSrgb,
+    }
+}
+
+bitflags! {
+    /// <https://gpuweb.github.io/gpuweb/#typedefdef-gputextureusage>
+    pub struct TextureUsage: i32 {
+        const NONE = sys::WGPUTextureUsage_None;
+        const COPY_SRC = sys::WGPUTextureUsage_CopySrc;
+        const COPY_DST = sys::WGPUTextureUsage_CopyDst;
+        const SAMPLED = sys::WGPUTextureUsage_Sampled;
+        const STORAGE = sys::WGPUTextureUsage_Storage;
+        const OUTPUT_ATTACHMENT = sys::WGPUTextureUsage_OutputAttachment;
+        #[doc(hidden)]
+        const PRESENT = sys::WGPUTextureUsage_Present;
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gputextureviewdimension>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum TextureViewDimension {
+        Undefined = sys::WGPUTextureViewDimension_Undefined,
+        D1 = sys::WGPUTextureViewDimension_1D,
+        D2 = sys::WGPUTextureViewDimension_2D,
+        D2Array = sys::WGPUTextureViewDimension_2DArray,
+        Cube = sys::WGPUTextureViewDimension_Cube,
+        CubeArray = sys::WGPUTextureViewDimension_CubeArray,
+        D3 = sys::WGPUTextureViewDimension_3D,
+    }
+}
+
+dawn_enum! {
+    /// <https://gpuweb.github.io/gpuweb/#enumdef-gpuvertexformat>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[repr(i32)]
+    pub enum VertexFormat {
+        UChar2 = sys::WGPUVertexFormat_UChar2,
+        UChar4 = sys::WGPUVertexFormat_UChar4,
+        Char2 = sys::WGPUVertexFormat_Char2,
+        Char4 = sys::WGPUVertexFormat_Char4,
+        UChar2Norm = sys::WGPUVertexFormat_UChar2Norm,
+        UChar4Norm = sys::WGPUVertexFormat_UChar4Norm,
+        Char2Norm = sys::WGPUVertexFormat_Char2Norm,
+        Char4Norm = sys::WGPUVertexFormat_Char4Norm,
+        UShort2 = sys::WGPUVertexFormat_UShort2,
+        UShort4 = sys::WGPUVertexFormat_UShort4,
+        Short2 = sys::WGPUVertexFormat_Short2,
+        Short4 = sys::WGPUVertexFormat_Short4,
+        UShort2Norm = sys::WGPUVertexFormat_UShort2Norm,
+        UShort4Norm = sys::WGPUVertexFormat_UShort4Norm,
+        Short2Norm = sys::WGPUVertexFormat_Short2Norm,
+        Short4Norm = sys::WGPUVertexFormat_Short4Norm,
+        Half2 = sys::WGPUVertexFormat_Half2,
+        Half4 = sys::WGPUVertexFormat_Half4,
+        Float = sys::WGPUVertexFormat_Float,
+        Float2 = sys::WGPUVertexFormat_Float2,
+        Float3 = sys::WGPUVertexFormat_Float3,
+        Float4 = sys::WGPUVertexFormat_Float4,
+        UInt = sys::WGPUVertexFormat_UInt,
+        UInt2 = sys::WGPUVertexFormat_UInt2,
+        UInt3 = sys::WGPUVertexFormat_UInt3,
+        UInt4 = sys::WGPUVertexFormat_UInt4,
+        Int = sys::WGPUVertexFormat_Int,
+        Int2 = sys::WGPUVertexFormat_Int2,
+        Int3 = sys::WGPUVertexFormat_Int3,
+        Int4 = sys::WGPUVertexFormat_Int4,
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpubindgroupdescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct BindGroupDescriptor<'a> {
+    pub label: Option<&'a str>,
+    pub layout: &'a BindGroupLayout,
+    pub entries: &'a [BindGroupEntry<'a>],
+}
+
+impl<'a> BindGroupDescriptor<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPUBindGroupDescriptor {
+        sys::WGPUBindGroupDescriptor {
+            nextInChain: ptr::null(),
+            label: arena.label(self.label),
+            layout: self.layout.raw,
+            bindingCount: self.entries.len() as _,
+            bindings: {
+                let raw: Vec<_> = self.entries.iter().map(|v| v.lower(arena)).collect();
+                arena.slice(raw)
+            },
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpubindgrouplayoutdescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct BindGroupLayoutDescriptor<'a> {
+    pub label: Option<&'a str>,
+    pub entries: &'a [BindGroupLayoutEntry],
+}
+
+impl<'a> BindGroupLayoutDescriptor<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPUBindGroupLayoutDescriptor {
+        sys::WGPUBindGroupLayoutDescriptor {
+            nextInChain: ptr::null(),
+            label: arena.label(self.label),
+            bindingCount: self.entries.len() as _,
+            bindings: {
+                let raw: Vec<_> = self.entries.iter().map(|v| v.lower(arena)).collect();
+                arena.slice(raw)
+            },
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpublenddescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct BlendDescriptor {
+    pub operation: BlendOperation,
+    pub src_factor: BlendFactor,
+    pub dst_factor: BlendFactor,
+}
+
+impl BlendDescriptor {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, _arena: &mut Arena) -> sys::WGPUBlendDescriptor {
+        sys::WGPUBlendDescriptor {
+            operation: self.operation as _,
+            srcFactor: self.src_factor as _,
+            dstFactor: self.dst_factor as _,
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpubuffercopyview>
+#[derive(Debug, Copy, Clone)]
+pub struct BufferCopyView<'a> {
+    pub buffer: &'a Buffer,
+    pub offset: u64,
+    pub bytes_per_row: u32,
+    pub rows_per_image: u32,
+}
+
+impl<'a> BufferCopyView<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, _arena: &mut Arena) -> sys::WGPUBufferCopyView {
+        sys::WGPUBufferCopyView {
+            nextInChain: ptr::null(),
+            buffer: self.buffer.raw,
+            offset: self.offset,
+            rowPitch: self.bytes_per_row,
+            imageHeight: self.rows_per_image,
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpubufferdescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct BufferDescriptor<'a> {
+    pub label: Option<&'a str>,
+    pub usage: BufferUsage,
+    pub size: u64,
+}
+
+impl<'a> BufferDescriptor<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPUBufferDescriptor {
+        sys::WGPUBufferDescriptor {
+            nextInChain: ptr::null(),
+            label: arena.label(self.label),
+            usage: self.usage.bits() as _,
+            size: self.size,
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpucolor>
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl Color {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, _arena: &mut Arena) -> sys::WGPUColor {
+        sys::WGPUColor {
+            r: self.r,
+            g: self.g,
+            b: self.b,
+            a: self.a,
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpucolorstatedescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct ColorStateDescriptor {
+    pub format: TextureFormat,
+    pub alpha_blend: BlendDescriptor,
+    pub color_blend: BlendDescriptor,
+    pub write_mask: ColorWrite,
+}
+
+impl ColorStateDescriptor {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPUColorStateDescriptor {
+        sys::WGPUColorStateDescriptor {
+            nextInChain: ptr::null(),
+            format: self.format as _,
+            alphaBlend: self.alpha_blend.lower(arena),
+            colorBlend: self.color_blend.lower(arena),
+            writeMask: self.write_mask.bits() as _,
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpucommandbufferdescriptor>
+#[derive(Debug, Copy, Clone, Default)]
+pub struct CommandBufferDescriptor<'a> {
+    pub label: Option<&'a str>,
+}
+
+impl<'a> CommandBufferDescriptor<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPUCommandBufferDescriptor {
+        sys::WGPUCommandBufferDescriptor {
+            nextInChain: ptr::null(),
+            label: arena.label(self.label),
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpucommandencoderdescriptor>
+#[derive(Debug, Copy, Clone, Default)]
+pub struct CommandEncoderDescriptor<'a> {
+    pub label: Option<&'a str>,
+}
+
+impl<'a> CommandEncoderDescriptor<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPUCommandEncoderDescriptor {
+        sys::WGPUCommandEncoderDescriptor {
+            nextInChain: ptr::null(),
+            label: arena.label(self.label),
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpucomputepassdescriptor>
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ComputePassDescriptor<'a> {
+    pub label: Option<&'a str>,
+}
+
+impl<'a> ComputePassDescriptor<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPUComputePassDescriptor {
+        sys::WGPUComputePassDescriptor {
+            nextInChain: ptr::null(),
+            label: arena.label(self.label),
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpucomputepipelinedescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct ComputePipelineDescriptor<'a> {
+    pub label: Option<&'a str>,
+    /// `None` derives the layout from the shaders. Its bind group layouts can be queried with
+    /// `get_bind_group_layout`.
+    pub layout: Option<&'a PipelineLayout>,
+    pub compute_stage: ProgrammableStageDescriptor<'a>,
+}
+
+impl<'a> ComputePipelineDescriptor<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPUComputePipelineDescriptor {
+        sys::WGPUComputePipelineDescriptor {
+            nextInChain: ptr::null(),
+            label: arena.label(self.label),
+            layout: self.layout.map_or(ptr::null_mut(), |v| v.raw),
+            computeStage: self.compute_stage.lower(arena),
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpudepthstencilstatedescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct DepthStencilStateDescriptor {
+    pub format: TextureFormat,
+    pub depth_write_enabled: bool,
+    pub depth_compare: CompareFunction,
+    pub stencil_front: StencilStateFaceDescriptor,
+    pub stencil_back: StencilStateFaceDescriptor,
+    pub stencil_read_mask: u32,
+    pub stencil_write_mask: u32,
+}
+
+impl DepthStencilStateDescriptor {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPUDepthStencilStateDescriptor {
+        sys::WGPUDepthStencilStateDescriptor {
+            nextInChain: ptr::null(),
+            format: self.format as _,
+            depthWriteEnabled: self.depth_write_enabled,
+            depthCompare: self.depth_compare as _,
+            stencilFront: self.stencil_front.lower(arena),
+            stencilBack: self.stencil_back.lower(arena),
+            stencilReadMask: self.stencil_read_mask,
+            stencilWriteMask: self.stencil_write_mask,
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpudeviceproperties>
+#[derive(Debug, Copy, Clone, Default)]
+pub struct DeviceProperties {
+    pub texture_compression_bc: bool,
+}
+
+impl DeviceProperties {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, _arena: &mut Arena) -> sys::WGPUDeviceProperties {
+        sys::WGPUDeviceProperties {
+            textureCompressionBC: self.texture_compression_bc,
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpuextent3d>
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Extent3d {
+    pub width: u32,
+    pub height: u32,
+    pub depth: u32,
+}
+
+impl Extent3d {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, _arena: &mut Arena) -> sys::WGPUExtent3D {
+        sys::WGPUExtent3D {
+            width: self.width,
+            height: self.height,
+            depth: self.depth,
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpufencedescriptor>
+#[derive(Debug, Copy, Clone, Default)]
+pub struct FenceDescriptor<'a> {
+    pub label: Option<&'a str>,
+    pub initial_value: u64,
+}
+
+impl<'a> FenceDescriptor<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPUFenceDescriptor {
+        sys::WGPUFenceDescriptor {
+            nextInChain: ptr::null(),
+            label: arena.label(self.label),
+            initialValue: self.initial_value,
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpuinstancedescriptor>
+#[derive(Debug, Copy, Clone, Default)]
+pub struct InstanceDescriptor {}
+
+impl InstanceDescriptor {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, _arena: &mut Arena) -> sys::WGPUInstanceDescriptor {
+        sys::WGPUInstanceDescriptor {
+            nextInChain: ptr::null(),
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpuorigin3d>
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Origin3d {
+    pub x: u32,
+    pub y: u32,
+    pub z: u32,
+}
+
+impl Origin3d {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, _arena: &mut Arena) -> sys::WGPUOrigin3D {
+        sys::WGPUOrigin3D {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpupipelinelayoutdescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct PipelineLayoutDescriptor<'a> {
+    pub label: Option<&'a str>,
+    pub bind_group_layouts: &'a [BindGroupLayout],
+}
+
+impl<'a> PipelineLayoutDescriptor<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPUPipelineLayoutDescriptor {
+        sys::WGPUPipelineLayoutDescriptor {
+            nextInChain: ptr::null(),
+            label: arena.label(self.label),
+            bindGroupLayoutCount: self.bind_group_layouts.len() as _,
+            bindGroupLayouts: {
+                let raw: Vec<_> = self.bind_group_layouts.iter().map(|v| v.raw).collect();
+                arena.slice(raw)
+            },
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpuprogrammablestagedescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct ProgrammableStageDescriptor<'a> {
+    pub module: &'a ShaderModule,
+    pub entry_point: &'a str,
+}
+
+impl<'a> ProgrammableStageDescriptor<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPUProgrammableStageDescriptor {
+        sys::WGPUProgrammableStageDescriptor {
+            nextInChain: ptr::null(),
+            module: self.module.raw,
+            entryPoint: arena.label(Some(self.entry_point)),
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpurasterizationstatedescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct RasterizationStateDescriptor {
+    pub front_face: FrontFace,
+    pub cull_mode: CullMode,
+    pub depth_bias: i32,
+    pub depth_bias_slope_scale: f32,
+    pub depth_bias_clamp: f32,
+}
+
+impl RasterizationStateDescriptor {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, _arena: &mut Arena) -> sys::WGPURasterizationStateDescriptor {
+        sys::WGPURasterizationStateDescriptor {
+            nextInChain: ptr::null(),
+            frontFace: self.front_face as _,
+            cullMode: self.cull_mode as _,
+            depthBias: self.depth_bias,
+            depthBiasSlopeScale: self.depth_bias_slope_scale,
+            depthBiasClamp: self.depth_bias_clamp,
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpurenderbundledescriptor>
+#[derive(Debug, Copy, Clone, Default)]
+pub struct RenderBundleDescriptor<'a> {
+    pub label: Option<&'a str>,
+}
+
+impl<'a> RenderBundleDescriptor<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPURenderBundleDescriptor {
+        sys::WGPURenderBundleDescriptor {
+            nextInChain: ptr::null(),
+            label: arena.label(self.label),
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpurenderbundleencoderdescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct RenderBundleEncoderDescriptor<'a> {
+    pub label: Option<&'a str>,
+    pub color_formats: &'a [TextureFormat],
+    pub depth_stencil_format: TextureFormat,
+    pub sample_count: u32,
+}
+
+impl<'a> RenderBundleEncoderDescriptor<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPURenderBundleEncoderDescriptor {
+        sys::WGPURenderBundleEncoderDescriptor {
+            nextInChain: ptr::null(),
+            label: arena.label(self.label),
+            colorFormatsCount: self.color_formats.len() as _,
+            colorFormats: {
+                let raw: Vec<_> = self.color_formats.iter().map(|v| *v as _).collect();
+                arena.slice(raw)
+            },
+            depthStencilFormat: self.depth_stencil_format as _,
+            sampleCount: self.sample_count,
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpurenderpasscolorattachmentdescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct RenderPassColorAttachmentDescriptor<'a> {
+    pub attachment: &'a TextureView,
+    pub resolve_target: Option<&'a TextureView>,
+    pub load_op: LoadOp,
+    pub store_op: StoreOp,
+    pub clear_color: Color,
+}
+
+impl<'a> RenderPassColorAttachmentDescriptor<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPURenderPassColorAttachmentDescriptor {
+        sys::WGPURenderPassColorAttachmentDescriptor {
+            attachment: self.attachment.raw,
+            resolveTarget: self.resolve_target.map_or(ptr::null_mut(), |v| v.raw),
+            loadOp: self.load_op as _,
+            storeOp: self.store_op as _,
+            clearColor: self.clear_color.lower(arena),
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpurenderpassdepthstencilattachmentdescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct RenderPassDepthStencilAttachmentDescriptor<'a> {
+    pub attachment: &'a TextureView,
+    pub depth_load_op: LoadOp,
+    pub depth_store_op: StoreOp,
+    pub clear_depth: f32,
+    pub stencil_load_op: LoadOp,
+    pub stencil_store_op: StoreOp,
+    pub clear_stencil: u32,
+}
+
+impl<'a> RenderPassDepthStencilAttachmentDescriptor<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(
+        &self,
+        _arena: &mut Arena,
+    ) -> sys::WGPURenderPassDepthStencilAttachmentDescriptor {
+        sys::WGPURenderPassDepthStencilAttachmentDescriptor {
+            attachment: self.attachment.raw,
+            depthLoadOp: self.depth_load_op as _,
+            depthStoreOp: self.depth_store_op as _,
+            clearDepth: self.clear_depth,
+            stencilLoadOp: self.stencil_load_op as _,
+            stencilStoreOp: self.stencil_store_op as _,
+            clearStencil: self.clear_stencil,
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpurenderpassdescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct RenderPassDescriptor<'a> {
+    pub label: Option<&'a str>,
+    pub color_attachments: &'a [RenderPassColorAttachmentDescriptor<'a>],
+    pub depth_stencil_attachment: Option<&'a RenderPassDepthStencilAttachmentDescriptor<'a>>,
+}
+
+impl<'a> RenderPassDescriptor<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPURenderPassDescriptor {
+        sys::WGPURenderPassDescriptor {
+            nextInChain: ptr::null(),
+            label: arena.label(self.label),
+            colorAttachmentCount: self.color_attachments.len() as _,
+            colorAttachments: {
+                let raw: Vec<_> = self
+                    .color_attachments
+                    .iter()
+                    .map(|v| v.lower(arena))
+                    .collect();
+                arena.slice(raw)
+            },
+            depthStencilAttachment: match self.depth_stencil_attachment {
+                Some(v) => {
+                    let raw = v.lower(arena);
+                    arena.boxed(raw)
+                }
+                None => ptr::null(),
+            },
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpurenderpipelinedescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct RenderPipelineDescriptor<'a> {
+    pub label: Option<&'a str>,
+    /// `None` derives the layout from the shaders. Its bind group layouts can be queried with
+    /// `get_bind_group_layout`.
+    pub layout: Option<&'a PipelineLayout>,
+    pub vertex_stage: ProgrammableStageDescriptor<'a>,
+    pub fragment_stage: Option<ProgrammableStageDescriptor<'a>>,
+    pub vertex_state: &'a VertexStateDescriptor<'a>,
+    pub primitive_topology: PrimitiveTopology,
+    pub rasterization_state: Option<&'a RasterizationStateDescriptor>,
+    pub sample_count: u32,
+    pub depth_stencil_state: Option<&'a DepthStencilStateDescriptor>,
+    pub color_states: &'a [ColorStateDescriptor],
+    pub sample_mask: u32,
+    pub alpha_to_coverage_enabled: bool,
+}
+
+impl<'a> RenderPipelineDescriptor<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPURenderPipelineDescriptor {
+        sys::WGPURenderPipelineDescriptor {
+            nextInChain: ptr::null(),
+            label: arena.label(self.label),
+            layout: self.layout.map_or(ptr::null_mut(), |v| v.raw),
+            vertexStage: self.vertex_stage.lower(arena),
+            fragmentStage: match self.fragment_stage {
+                Some(v) => {
+                    let raw = v.lower(arena);
+                    arena.boxed(raw)
+                }
+                None => ptr::null(),
+            },
+            vertexState: {
+                let raw = self.vertex_state.lower(arena);
+                arena.boxed(raw)
+            },
+            primitiveTopology: self.primitive_topology as _,
+            rasterizationState: match self.rasterization_state {
+                Some(v) => {
+                    let raw = v.lower(arena);
+                    arena.boxed(raw)
+                }
+                None => ptr::null(),
+            },
+            sampleCount: self.sample_count,
+            depthStencilState: match self.depth_stencil_state {
+                Some(v) => {
+                    let raw = v.lower(arena);
+                    arena.boxed(raw)
+                }
+                None => ptr::null(),
+            },
+            colorStateCount: self.color_states.len() as _,
+            colorStates: {
+                let raw: Vec<_> = self.color_states.iter().map(|v| v.lower(arena)).collect();
+                arena.slice(raw)
+            },
+            sampleMask: self.sample_mask,
+            alphaToCoverageEnabled: self.alpha_to_coverage_enabled,
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpusamplerdescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct SamplerDescriptor<'a> {
+    pub label: Option<&'a str>,
+    pub address_mode_u: AddressMode,
+    pub address_mode_v: AddressMode,
+    pub address_mode_w: AddressMode,
+    pub mag_filter: FilterMode,
+    pub min_filter: FilterMode,
+    pub mipmap_filter: FilterMode,
+    pub lod_min_clamp: f32,
+    pub lod_max_clamp: f32,
+    pub compare: CompareFunction,
+}
+
+impl<'a> SamplerDescriptor<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPUSamplerDescriptor {
+        sys::WGPUSamplerDescriptor {
+            nextInChain: ptr::null(),
+            label: arena.label(self.label),
+            addressModeU: self.address_mode_u as _,
+            addressModeV: self.address_mode_v as _,
+            addressModeW: self.address_mode_w as _,
+            magFilter: self.mag_filter as _,
+            minFilter: self.min_filter as _,
+            mipmapFilter: self.mipmap_filter as _,
+            lodMinClamp: self.lod_min_clamp,
+            lodMaxClamp: self.lod_max_clamp,
+            compare: self.compare as _,
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpustencilstatefacedescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct StencilStateFaceDescriptor {
+    pub compare: CompareFunction,
+    pub fail_op: StencilOperation,
+    pub depth_fail_op: StencilOperation,
+    pub pass_op: StencilOperation,
+}
+
+impl StencilStateFaceDescriptor {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, _arena: &mut Arena) -> sys::WGPUStencilStateFaceDescriptor {
+        sys::WGPUStencilStateFaceDescriptor {
+            compare: self.compare as _,
+            failOp: self.fail_op as _,
+            depthFailOp: self.depth_fail_op as _,
+            passOp: self.pass_op as _,
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpuswapchaindescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct SwapChainDescriptor<'a> {
+    pub label: Option<&'a str>,
+    pub usage: TextureUsage,
+    pub format: TextureFormat,
+    pub width: u32,
+    pub height: u32,
+    pub present_mode: PresentMode,
+    pub implementation: u64,
+}
+
+impl<'a> SwapChainDescriptor<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPUSwapChainDescriptor {
+        sys::WGPUSwapChainDescriptor {
+            nextInChain: ptr::null(),
+            label: arena.label(self.label),
+            usage: self.usage.bits() as _,
+            format: self.format as _,
+            width: self.width,
+            height: self.height,
+            presentMode: self.present_mode as _,
+            implementation: self.implementation,
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gputexturecopyview>
+#[derive(Debug, Copy, Clone)]
+pub struct TextureCopyView<'a> {
+    pub texture: &'a Texture,
+    pub mip_level: u32,
+    pub array_layer: u32,
+    pub origin: Origin3d,
+}
+
+impl<'a> TextureCopyView<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPUTextureCopyView {
+        sys::WGPUTextureCopyView {
+            nextInChain: ptr::null(),
+            texture: self.texture.raw,
+            mipLevel: self.mip_level,
+            arrayLayer: self.array_layer,
+            origin: self.origin.lower(arena),
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gputexturedescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct TextureDescriptor<'a> {
+    pub label: Option<&'a str>,
+    pub usage: TextureUsage,
+    pub dimension: TextureDimension,
+    pub size: Extent3d,
+    pub array_layer_count: u32,
+    pub format: TextureFormat,
+    pub mip_level_count: u32,
+    pub sample_count: u32,
+}
+
+impl<'a> TextureDescriptor<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPUTextureDescriptor {
+        sys::WGPUTextureDescriptor {
+            nextInChain: ptr::null(),
+            label: arena.label(self.label),
+            usage: self.usage.bits() as _,
+            dimension: self.dimension as _,
+            size: self.size.lower(arena),
+            arrayLayerCount: self.array_layer_count,
+            format: self.format as _,
+            mipLevelCount: self.mip_level_count,
+            sampleCount: self.sample_count,
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gputextureviewdescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct TextureViewDescriptor<'a> {
+    pub label: Option<&'a str>,
+    pub format: TextureFormat,
+    pub dimension: TextureViewDimension,
+    pub base_mip_level: u32,
+    pub mip_level_count: u32,
+    pub base_array_layer: u32,
+    pub array_layer_count: u32,
+    pub aspect: TextureAspect,
+}
+
+impl<'a> TextureViewDescriptor<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPUTextureViewDescriptor {
+        sys::WGPUTextureViewDescriptor {
+            nextInChain: ptr::null(),
+            label: arena.label(self.label),
+            format: self.format as _,
+            dimension: self.dimension as _,
+            baseMipLevel: self.base_mip_level,
+            mipLevelCount: self.mip_level_count,
+            baseArrayLayer: self.base_array_layer,
+            arrayLayerCount: self.array_layer_count,
+            aspect: self.aspect as _,
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpuvertexattributedescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct VertexAttributeDescriptor {
+    pub format: VertexFormat,
+    pub offset: u64,
+    pub shader_location: u32,
+}
+
+impl VertexAttributeDescriptor {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, _arena: &mut Arena) -> sys::WGPUVertexAttributeDescriptor {
+        sys::WGPUVertexAttributeDescriptor {
+            format: self.format as _,
+            offset: self.offset,
+            shaderLocation: self.shader_location,
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpuvertexbufferlayoutdescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct VertexBufferLayoutDescriptor<'a> {
+    pub array_stride: u64,
+    pub step_mode: InputStepMode,
+    pub attributes: &'a [VertexAttributeDescriptor],
+}
+
+impl<'a> VertexBufferLayoutDescriptor<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPUVertexBufferLayoutDescriptor {
+        sys::WGPUVertexBufferLayoutDescriptor {
+            arrayStride: self.array_stride,
+            stepMode: self.step_mode as _,
+            attributeCount: self.attributes.len() as _,
+            attributes: {
+                let raw: Vec<_> = self.attributes.iter().map(|v| v.lower(arena)).collect();
+                arena.slice(raw)
+            },
+        }
+    }
+}
+
+/// <https://gpuweb.github.io/gpuweb/#dictdef-gpuvertexstatedescriptor>
+#[derive(Debug, Copy, Clone)]
+pub struct VertexStateDescriptor<'a> {
+    pub index_format: IndexFormat,
+    pub vertex_buffers: &'a [VertexBufferLayoutDescriptor<'a>],
+}
+
+impl<'a> VertexStateDescriptor<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn lower(&self, arena: &mut Arena) -> sys::WGPUVertexStateDescriptor {
+        sys::WGPUVertexStateDescriptor {
+            nextInChain: ptr::null(),
+            indexFormat: self.index_format as _,
+            vertexBufferCount: self.vertex_buffers.len() as _,
+            vertexBuffers: {
+                let raw: Vec<_> = self.vertex_buffers.iter().map(|v| v.lower(arena)).collect();
+                arena.slice(raw)
+            },
+        }
+    }
+}
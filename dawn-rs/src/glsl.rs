@@ -0,0 +1,298 @@
+//! Compiling GLSL to SPIR-V at runtime.
+//!
+//! `Device::create_shader_module_from_glsl` compiles with shaderc in-process, so shaders can
+//! be edited without regenerating `.spv` files. Errors are reported as `Diagnostic`s with the
+//! file and line they refer to, including lines in `#include`d files.
+//!
+//! ```no_run
+//! # let device: dawn::Device = unimplemented!();
+//! use dawn::glsl::GlslSource;
+//! use dawn::ShaderStage;
+//!
+//! let source = GlslSource::File("examples/triangle.vert.glsl".as_ref());
+//! let defines = [("SCALE", "2.0")];
+//! match device.create_shader_module_from_glsl(source, ShaderStage::VERTEX, &defines) {
+//!     Ok(vertex_shader) => {}
+//!     Err(err) => eprintln!("{}", err),
+//! }
+//! ```
+
+use std::cell::RefCell;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{Device, ShaderModule, ShaderStage};
+
+/// GLSL source code.
+#[derive(Debug, Copy, Clone)]
+pub enum GlslSource<'a> {
+    /// A file that is read when compiling.
+    File(&'a Path),
+    /// Source code in memory. `name` is used in diagnostics and relative `#include`s are
+    /// resolved against its directory.
+    Code { name: &'a str, code: &'a str },
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// A compiler message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// The file the message refers to, if any.
+    pub file: Option<String>,
+    /// The 1-based line in `file`.
+    pub line: Option<u32>,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+            if let Some(line) = self.line {
+                write!(f, "{}:", line)?;
+            }
+            write!(f, " ")?;
+        }
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        write!(f, "{}: {}", severity, self.message)
+    }
+}
+
+#[derive(Debug)]
+pub enum GlslError {
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+    /// The stage isn't exactly one of `VERTEX`, `FRAGMENT` or `COMPUTE`.
+    InvalidStage(ShaderStage),
+    Compile(Vec<Diagnostic>),
+    /// shaderc failed without reporting diagnostics.
+    Internal(String),
+}
+
+impl fmt::Display for GlslError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GlslError::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+            GlslError::InvalidStage(stage) => write!(f, "can't compile GLSL for {:?}", stage),
+            GlslError::Compile(diagnostics) => {
+                for (i, diagnostic) in diagnostics.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "{}", diagnostic)?;
+                }
+                Ok(())
+            }
+            GlslError::Internal(message) => write!(f, "shaderc failed: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for GlslError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GlslError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Compiles `source` to SPIR-V. Each of `defines` is a macro name and value, as if declared
+/// with `#define NAME VALUE` before the first line.
+pub fn compile(
+    source: GlslSource,
+    stage: ShaderStage,
+    defines: &[(&str, &str)],
+) -> Result<Vec<u32>, GlslError> {
+    compile_with_includes(source, stage, defines, &mut Vec::new())
+}
+
+/// Like `compile`, and adds the files that were `#include`d to `includes`, also if the
+/// compilation fails.
+pub(crate) fn compile_with_includes(
+    source: GlslSource,
+    stage: ShaderStage,
+    defines: &[(&str, &str)],
+    includes: &mut Vec<PathBuf>,
+) -> Result<Vec<u32>, GlslError> {
+    let kind = if stage == ShaderStage::VERTEX {
+        shaderc::ShaderKind::Vertex
+    } else if stage == ShaderStage::FRAGMENT {
+        shaderc::ShaderKind::Fragment
+    } else if stage == ShaderStage::COMPUTE {
+        shaderc::ShaderKind::Compute
+    } else {
+        return Err(GlslError::InvalidStage(stage));
+    };
+
+    let (name, code) = match source {
+        GlslSource::File(path) => {
+            let code = fs::read_to_string(path).map_err(|error| GlslError::Io {
+                path: path.to_owned(),
+                error,
+            })?;
+            (path.to_string_lossy().into_owned(), code)
+        }
+        GlslSource::Code { name, code } => (name.to_owned(), code.to_owned()),
+    };
+
+    // Declared before `options`, whose include callback borrows it.
+    let included = RefCell::new(Vec::new());
+    let internal = || GlslError::Internal("failed to initialize the compiler".to_owned());
+    let mut compiler = shaderc::Compiler::new().ok_or_else(internal)?;
+    let mut options = shaderc::CompileOptions::new().ok_or_else(internal)?;
+    options.set_source_language(shaderc::SourceLanguage::GLSL);
+    options.set_target_env(
+        shaderc::TargetEnv::Vulkan,
+        shaderc::EnvVersion::Vulkan1_0 as u32,
+    );
+    for &(macro_name, value) in defines {
+        options.add_macro_definition(macro_name, Some(value));
+    }
+    options.set_include_callback(|requested, include_type, requesting, _depth| {
+        let path = match include_type {
+            shaderc::IncludeType::Relative => Path::new(requesting)
+                .parent()
+                .unwrap_or_else(|| Path::new(""))
+                .join(requested),
+            shaderc::IncludeType::Standard => PathBuf::from(requested),
+        };
+        included.borrow_mut().push(path.clone());
+        match fs::read_to_string(&path) {
+            Ok(content) => Ok(shaderc::ResolvedInclude {
+                resolved_name: path.to_string_lossy().into_owned(),
+                content,
+            }),
+            Err(error) => Err(format!("{}: {}", path.display(), error)),
+        }
+    });
+
+    let result = compiler.compile_into_spirv(&code, kind, &name, "main", Some(&options));
+    drop(options);
+    includes.extend(included.into_inner());
+    match result {
+        Ok(artifact) => Ok(artifact.as_binary().to_vec()),
+        Err(shaderc::Error::CompilationError(_, messages)) => {
+            Err(GlslError::Compile(parse_diagnostics(&messages)))
+        }
+        Err(err) => Err(GlslError::Internal(err.to_string())),
+    }
+}
+
+// Parses glslang's messages, which look like `file:line: error: message`. The file name can
+// contain colons, so the severity is found first.
+fn parse_diagnostics(messages: &str) -> Vec<Diagnostic> {
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    for line in messages.lines() {
+        let line = line.trim();
+        let found = [
+            ("error: ", Severity::Error),
+            ("warning: ", Severity::Warning),
+        ]
+        .iter()
+        .filter_map(|&(marker, severity)| {
+            let start = if line.starts_with(marker) {
+                0
+            } else {
+                line.find(&format!(": {}", marker))? + 2
+            };
+            Some((start, marker.len(), severity))
+        })
+        .min_by_key(|&(start, _, _)| start);
+        let (start, marker_len, severity) = match found {
+            Some(found) => found,
+            // Summaries such as "1 error generated." and continuation lines.
+            None => {
+                let summary = line.is_empty() || line.ends_with(" generated.");
+                match diagnostics.last_mut() {
+                    Some(last) if !summary => {
+                        last.message.push('\n');
+                        last.message.push_str(line);
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+        };
+        let location = line[..start].trim_end_matches(&[':', ' '][..]);
+        let (file, line_number) = match location.rfind(':') {
+            Some(colon) => match location[colon + 1..].parse() {
+                Ok(line_number) => (&location[..colon], Some(line_number)),
+                Err(_) => (location, None),
+            },
+            None => (location, None),
+        };
+        diagnostics.push(Diagnostic {
+            severity,
+            file: match file {
+                "" => None,
+                file => Some(file.to_owned()),
+            },
+            line: line_number,
+            message: line[start + marker_len..].to_owned(),
+        });
+    }
+    diagnostics
+}
+
+impl Device {
+    /// Compiles GLSL with `glsl::compile` and creates a shader module from the result.
+    pub fn create_shader_module_from_glsl(
+        &self,
+        source: GlslSource,
+        stage: ShaderStage,
+        defines: &[(&str, &str)],
+    ) -> Result<ShaderModule, GlslError> {
+        let code = compile(source, stage, defines)?;
+        Ok(self.create_shader_module_with_code(&code))
+    }
+}
+
+#[test]
+fn diagnostics_have_file_and_line() {
+    let messages = "shaders/C:/a.vert:12: error: 'foo' : undeclared identifier\n\
+                    shaders/common.glsl:3: warning: '#extension' : extension not supported: x\n\
+                    glslang: error: Linking vertex stage: Missing entry point\n\
+                    2 errors generated.\n";
+    let diagnostics = parse_diagnostics(messages);
+    assert_eq!(
+        vec![
+            Diagnostic {
+                severity: Severity::Error,
+                file: Some("shaders/C:/a.vert".to_owned()),
+                line: Some(12),
+                message: "'foo' : undeclared identifier".to_owned(),
+            },
+            Diagnostic {
+                severity: Severity::Warning,
+                file: Some("shaders/common.glsl".to_owned()),
+                line: Some(3),
+                message: "'#extension' : extension not supported: x".to_owned(),
+            },
+            Diagnostic {
+                severity: Severity::Error,
+                file: Some("glslang".to_owned()),
+                line: None,
+                message: "Linking vertex stage: Missing entry point".to_owned(),
+            },
+        ],
+        diagnostics
+    );
+    assert_eq!(
+        "shaders/C:/a.vert:12: error: 'foo' : undeclared identifier",
+        diagnostics[0].to_string()
+    );
+}
@@ -0,0 +1,580 @@
+//! Reloading shaders while the application runs.
+//!
+//! `ShaderWatcher` creates shader modules from files and recreates them, and the pipelines
+//! created from them, when the files change. The objects are returned as `Reloadable` handles
+//! whose `get` returns the latest version, so code that draws with a pipeline picks up the
+//! new one on the next frame. When a shader fails to compile or Dawn rejects the new module
+//! or pipeline, the previous objects are kept and the error is returned from `poll`.
+//!
+//! `.spv` files are always supported. `.wgsl` files need the `wgsl` feature, and GLSL files
+//! named `.vert`, `.frag` or `.comp`, optionally followed by `.glsl`, need the `glsl` feature.
+//! The files a shader `#include`s are watched as well. WGSL files are run through the
+//! preprocessor of `permutation` for their `#include`s.
+//!
+//! ```no_run
+//! # let device: dawn::Device = unimplemented!();
+//! use dawn::hot_reload::ShaderWatcher;
+//! use dawn::{ComputePipelineDescriptor, ProgrammableStageDescriptor};
+//!
+//! let mut watcher = ShaderWatcher::new(&device);
+//! let shader = watcher.add_shader("shaders/blur.comp.spv").unwrap();
+//! let pipeline = watcher
+//!     .add_compute_pipeline(&[&shader], move |device, modules| {
+//!         device.create_compute_pipeline(&ComputePipelineDescriptor {
+//!             label: None,
+//!             layout: None,
+//!             compute_stage: ProgrammableStageDescriptor {
+//!                 module: &modules[0],
+//!                 entry_point: "main",
+//!             },
+//!         })
+//!     })
+//!     .unwrap();
+//!
+//! loop {
+//!     for err in watcher.poll() {
+//!         eprintln!("{}", err);
+//!     }
+//!     let pipeline = pipeline.get();
+//!     // Dispatch with `pipeline`.
+//! }
+//! ```
+//!
+//! Files are checked for changes by their modification time and length when `poll` is called.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::Arc;
+use std::thread;
+use std::time::SystemTime;
+
+use parking_lot::Mutex;
+
+use crate::spirv::{ShaderSource, SpirvError};
+use crate::{ComputePipeline, Device, ErrorFilter, ErrorType, RenderPipeline, ShaderModule};
+
+/// A handle to an object that a `ShaderWatcher` replaces when its shaders change. Like the
+/// objects themselves, handles can't be sent to other threads.
+#[derive(Debug)]
+pub struct Reloadable<T> {
+    current: Rc<RefCell<T>>,
+}
+
+impl<T: Clone> Reloadable<T> {
+    /// Returns the latest version of the object.
+    pub fn get(&self) -> T {
+        self.current.borrow().clone()
+    }
+}
+
+impl<T> Clone for Reloadable<T> {
+    fn clone(&self) -> Reloadable<T> {
+        Reloadable {
+            current: self.current.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ReloadError {
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+    /// The file extension isn't a shader language enabled by the crate features.
+    UnsupportedFile(PathBuf),
+    Spirv {
+        path: PathBuf,
+        error: SpirvError,
+    },
+    #[cfg(feature = "glsl")]
+    Glsl(crate::glsl::GlslError),
+    #[cfg(feature = "wgsl")]
+    Wgsl {
+        path: PathBuf,
+        error: crate::wgsl::WgslError,
+    },
+    /// Dawn reported an error when creating the shader module.
+    ShaderModule {
+        path: PathBuf,
+        message: String,
+    },
+    /// A directive of a WGSL file is invalid.
+    #[cfg(feature = "wgsl")]
+    Preprocess(crate::permutation::PermutationError),
+    /// Dawn reported an error when creating a pipeline from the shaders in `paths`.
+    Pipeline {
+        paths: Vec<PathBuf>,
+        message: String,
+    },
+    /// A shader passed to `add_render_pipeline` or `add_compute_pipeline` wasn't created by
+    /// the same `ShaderWatcher`.
+    UnwatchedShader,
+}
+
+impl fmt::Display for ReloadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReloadError::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+            ReloadError::UnsupportedFile(path) => {
+                write!(f, "{}: unsupported shader file", path.display())
+            }
+            ReloadError::Spirv { path, error } => write!(f, "{}: {}", path.display(), error),
+            #[cfg(feature = "glsl")]
+            ReloadError::Glsl(error) => write!(f, "{}", error),
+            #[cfg(feature = "wgsl")]
+            ReloadError::Wgsl { path, error } if !error.labels().is_empty() => {
+                // The message starts with the line and column.
+                write!(f, "{}:{}", path.display(), error)
+            }
+            #[cfg(feature = "wgsl")]
+            ReloadError::Wgsl { path, error } => write!(f, "{}: {}", path.display(), error),
+            ReloadError::ShaderModule { path, message } => {
+                write!(f, "{}: {}", path.display(), message)
+            }
+            #[cfg(feature = "wgsl")]
+            ReloadError::Preprocess(error) => write!(f, "{}", error),
+            ReloadError::Pipeline { paths, message } => {
+                write!(f, "failed to recreate the pipeline of ")?;
+                for (i, path) in paths.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", path.display())?;
+                }
+                write!(f, ": {}", message)
+            }
+            ReloadError::UnwatchedShader => {
+                write!(f, "the shader isn't watched by this ShaderWatcher")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReloadError {}
+
+// Changes to either mean that the file has been written.
+type Stamp = Option<(SystemTime, u64)>;
+
+fn stamp(path: &Path) -> Stamp {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+struct WatchedShader {
+    path: PathBuf,
+    /// `path` and the files it includes, as of the last compilation.
+    files: Vec<(PathBuf, Stamp)>,
+    module: Reloadable<ShaderModule>,
+}
+
+impl WatchedShader {
+    /// Returns `true` if one of the files has been written, and takes the new stamps.
+    fn update_stamps(&mut self) -> bool {
+        let mut changed = false;
+        for (path, old) in &mut self.files {
+            let new = stamp(path);
+            // Editors that replace the file may briefly remove it.
+            if new.is_some() && new != *old {
+                *old = new;
+                changed = true;
+            }
+        }
+        changed
+    }
+}
+
+// Creates a pipeline from new shader modules and replaces the previous one.
+type Recreate = Box<dyn FnMut(&Device, &[ShaderModule]) -> Result<(), String>>;
+
+struct WatchedPipeline {
+    shaders: Vec<usize>,
+    recreate: Recreate,
+}
+
+/// Recreates shader modules and pipelines when their shader files change.
+pub struct ShaderWatcher {
+    device: Device,
+    shaders: Vec<WatchedShader>,
+    pipelines: Vec<WatchedPipeline>,
+}
+
+impl ShaderWatcher {
+    pub fn new(device: &Device) -> ShaderWatcher {
+        ShaderWatcher {
+            device: device.clone(),
+            shaders: Vec::new(),
+            pipelines: Vec::new(),
+        }
+    }
+
+    /// Creates a shader module from `path` and watches the file.
+    pub fn add_shader<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<Reloadable<ShaderModule>, ReloadError> {
+        let path = path.as_ref().to_owned();
+        let stamp = stamp(&path);
+        let (module, includes) = create_shader_module(&self.device, &path)?;
+        let module = Reloadable {
+            current: Rc::new(RefCell::new(module)),
+        };
+        let mut files = vec![(path.clone(), stamp)];
+        files.extend(stamps(includes));
+        self.shaders.push(WatchedShader {
+            path,
+            files,
+            module: module.clone(),
+        });
+        Ok(module)
+    }
+
+    /// Creates a render pipeline with `create` and calls it again with the new modules when
+    /// one of `shaders` changes. The modules are passed in the order of `shaders`.
+    pub fn add_render_pipeline<F>(
+        &mut self,
+        shaders: &[&Reloadable<ShaderModule>],
+        create: F,
+    ) -> Result<Reloadable<RenderPipeline>, ReloadError>
+    where
+        F: FnMut(&Device, &[ShaderModule]) -> RenderPipeline + 'static,
+    {
+        self.add_pipeline(shaders, create)
+    }
+
+    /// Like `add_render_pipeline` for compute pipelines.
+    pub fn add_compute_pipeline<F>(
+        &mut self,
+        shaders: &[&Reloadable<ShaderModule>],
+        create: F,
+    ) -> Result<Reloadable<ComputePipeline>, ReloadError>
+    where
+        F: FnMut(&Device, &[ShaderModule]) -> ComputePipeline + 'static,
+    {
+        self.add_pipeline(shaders, create)
+    }
+
+    /// Recreates the shader modules whose files have changed since the last call and the
+    /// pipelines that use them. Objects that fail to compile or that Dawn rejects keep their
+    /// previous version, and the errors are returned.
+    pub fn poll(&mut self) -> Vec<ReloadError> {
+        let mut errors = Vec::new();
+        let mut changed = Vec::new();
+        for (index, shader) in self.shaders.iter_mut().enumerate() {
+            if !shader.update_stamps() {
+                continue;
+            }
+            match create_shader_module(&self.device, &shader.path) {
+                Ok((module, includes)) => {
+                    *shader.module.current.borrow_mut() = module;
+                    // The includes may have changed as well.
+                    shader.files.truncate(1);
+                    shader.files.extend(stamps(includes));
+                    changed.push(index);
+                }
+                Err(err) => errors.push(err),
+            }
+        }
+
+        let shaders = &self.shaders;
+        for pipeline in &mut self.pipelines {
+            if !pipeline.shaders.iter().any(|index| changed.contains(index)) {
+                continue;
+            }
+            let modules: Vec<ShaderModule> = pipeline
+                .shaders
+                .iter()
+                .map(|&index| shaders[index].module.get())
+                .collect();
+            if let Err(message) = (pipeline.recreate)(&self.device, &modules) {
+                errors.push(ReloadError::Pipeline {
+                    paths: pipeline
+                        .shaders
+                        .iter()
+                        .map(|&index| shaders[index].path.clone())
+                        .collect(),
+                    message,
+                });
+            }
+        }
+        errors
+    }
+
+    fn add_pipeline<T, F>(
+        &mut self,
+        shaders: &[&Reloadable<ShaderModule>],
+        mut create: F,
+    ) -> Result<Reloadable<T>, ReloadError>
+    where
+        T: 'static,
+        F: FnMut(&Device, &[ShaderModule]) -> T + 'static,
+    {
+        let indices = shaders
+            .iter()
+            .map(|shader| {
+                self.shaders
+                    .iter()
+                    .position(|watched| Rc::ptr_eq(&watched.module.current, &shader.current))
+                    .ok_or(ReloadError::UnwatchedShader)
+            })
+            .collect::<Result<Vec<usize>, ReloadError>>()?;
+        let modules: Vec<ShaderModule> = shaders.iter().map(|shader| shader.get()).collect();
+        let pipeline =
+            check_errors(&self.device, || create(&self.device, &modules)).map_err(|message| {
+                ReloadError::Pipeline {
+                    paths: indices
+                        .iter()
+                        .map(|&index| self.shaders[index].path.clone())
+                        .collect(),
+                    message,
+                }
+            })?;
+
+        let pipeline = Reloadable {
+            current: Rc::new(RefCell::new(pipeline)),
+        };
+        let current = pipeline.current.clone();
+        self.pipelines.push(WatchedPipeline {
+            shaders: indices,
+            recreate: Box::new(move |device, modules| {
+                let pipeline = check_errors(device, || create(device, modules))?;
+                *current.borrow_mut() = pipeline;
+                Ok(())
+            }),
+        });
+        Ok(pipeline)
+    }
+}
+
+fn stamps(paths: Vec<PathBuf>) -> impl Iterator<Item = (PathBuf, Stamp)> {
+    paths.into_iter().map(|path| {
+        let stamp = stamp(&path);
+        (path, stamp)
+    })
+}
+
+/// Creates the shader module of `path` and returns it with the files `path` includes.
+fn create_shader_module(
+    device: &Device,
+    path: &Path,
+) -> Result<(ShaderModule, Vec<PathBuf>), ReloadError> {
+    let (code, mut includes) = compile(path)?;
+    includes.sort();
+    includes.dedup();
+    includes.retain(|include| include != path);
+    let module = check_errors(device, || device.create_shader_module_with_code(&code)).map_err(
+        |message| ReloadError::ShaderModule {
+            path: path.to_owned(),
+            message,
+        },
+    )?;
+    Ok((module, includes))
+}
+
+/// Compiles `path` to SPIR-V and returns it with the files that were read for it.
+fn compile(path: &Path) -> Result<(Vec<u32>, Vec<PathBuf>), ReloadError> {
+    let mut language = path.extension().and_then(|ext| ext.to_str());
+    if language == Some("glsl") {
+        let stem = Path::new(path.file_stem().unwrap_or_default());
+        language = stem.extension().and_then(|ext| ext.to_str());
+    }
+    let read = || {
+        fs::read(path).map_err(|error| ReloadError::Io {
+            path: path.to_owned(),
+            error,
+        })
+    };
+    match language {
+        Some("spv") => {
+            let source =
+                ShaderSource::from_bytes(&read()?).map_err(|error| ReloadError::Spirv {
+                    path: path.to_owned(),
+                    error,
+                })?;
+            Ok((source.into_words(), Vec::new()))
+        }
+        #[cfg(feature = "wgsl")]
+        Some("wgsl") => {
+            use crate::permutation::{self, PermutationError, ShaderPermutation};
+            use crate::wgsl::WgslError;
+
+            let expanded =
+                permutation::preprocess(path, &ShaderPermutation::new(), &[]).map_err(|error| {
+                    match error {
+                        PermutationError::Io { path, error } => ReloadError::Io { path, error },
+                        error => ReloadError::Preprocess(error),
+                    }
+                })?;
+            let code = crate::wgsl::translate(&expanded.code).map_err(|mut error| {
+                // Refer to the lines of the included files, and to the file of the first label.
+                let mut path = path.to_owned();
+                if let WgslError::Parse { labels, .. } | WgslError::Validation { labels, .. } =
+                    &mut error
+                {
+                    for (i, label) in labels.iter_mut().enumerate() {
+                        if let Some((file, line)) = expanded.location(label.span.line) {
+                            if i == 0 {
+                                path = file.to_owned();
+                            }
+                            label.span.line = line;
+                        }
+                    }
+                }
+                ReloadError::Wgsl { path, error }
+            })?;
+            Ok((code, expanded.files().to_vec()))
+        }
+        #[cfg(feature = "glsl")]
+        Some(stage @ "vert") | Some(stage @ "frag") | Some(stage @ "comp") => {
+            let stage = match stage {
+                "vert" => crate::ShaderStage::VERTEX,
+                "frag" => crate::ShaderStage::FRAGMENT,
+                _ => crate::ShaderStage::COMPUTE,
+            };
+            let source = crate::glsl::GlslSource::File(path);
+            let mut includes = Vec::new();
+            let code = crate::glsl::compile_with_includes(source, stage, &[], &mut includes)
+                .map_err(ReloadError::Glsl)?;
+            Ok((code, includes))
+        }
+        _ => Err(ReloadError::UnsupportedFile(path.to_owned())),
+    }
+}
+
+// Creates an object in a validation error scope and waits for the scope.
+pub(crate) fn check_errors<T>(device: &Device, create: impl FnOnce() -> T) -> Result<T, String> {
+    device.push_error_scope(ErrorFilter::Validation);
+    let object = create();
+    let result = Arc::new(Mutex::new(None));
+    let callback_result = result.clone();
+    let popped = device.pop_error_scope(move |error_type, message| {
+        *callback_result.lock() = Some(match error_type {
+            ErrorType::NoError => Ok(()),
+            _ => Err(message.to_owned()),
+        });
+    });
+    assert!(popped, "error scope stack is empty");
+    loop {
+        if let Some(result) = result.lock().take() {
+            return result.map(|()| object);
+        }
+        device.tick();
+        thread::yield_now();
+    }
+}
+
+#[cfg(feature = "mock")]
+#[test]
+fn changed_shaders_recreate_pipelines() {
+    use crate::{ComputePipelineDescriptor, ProgrammableStageDescriptor};
+
+    let _mock = crate::mock::install();
+    let device = crate::mock::create_device();
+
+    let dir = std::env::temp_dir().join(format!("dawn-hot-reload-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("shader.spv");
+    let bytes = |words: &[u32]| -> Vec<u8> {
+        words
+            .iter()
+            .flat_map(|word| word.to_ne_bytes().to_vec())
+            .collect()
+    };
+    fs::write(&path, bytes(crate::example_shaders::TRIANGLE_VERT.spirv)).unwrap();
+
+    let mut watcher = ShaderWatcher::new(&device);
+    let shader = watcher.add_shader(&path).unwrap();
+    let pipeline = watcher
+        .add_compute_pipeline(&[&shader], move |device, modules| {
+            device.create_compute_pipeline(&ComputePipelineDescriptor {
+                label: None,
+                layout: None,
+                compute_stage: ProgrammableStageDescriptor {
+                    module: &modules[0],
+                    entry_point: "main",
+                },
+            })
+        })
+        .unwrap();
+    let (first_module, first_pipeline) = (shader.get().raw, pipeline.get().raw);
+    assert!(watcher.poll().is_empty());
+    assert_eq!(first_pipeline, pipeline.get().raw);
+
+    fs::write(&path, bytes(crate::example_shaders::TRIANGLE_FRAG.spirv)).unwrap();
+    assert!(watcher.poll().is_empty());
+    let (second_module, second_pipeline) = (shader.get().raw, pipeline.get().raw);
+    assert_ne!(first_module, second_module);
+    assert_ne!(first_pipeline, second_pipeline);
+
+    fs::write(&path, "#version 450\nvoid main() {}\n").unwrap();
+    match &watcher.poll()[..] {
+        [ReloadError::Spirv {
+            error: SpirvError::InvalidMagic(_),
+            ..
+        }] => {}
+        errors => panic!("{:?}", errors),
+    }
+    assert_eq!(second_module, shader.get().raw);
+    assert_eq!(second_pipeline, pipeline.get().raw);
+
+    let mut other_watcher = ShaderWatcher::new(&device);
+    match other_watcher.add_compute_pipeline(&[&shader], |_, _| unreachable!()) {
+        Err(ReloadError::UnwatchedShader) => {}
+        other => panic!("{:?}", other.map(|_| ())),
+    }
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(all(feature = "mock", feature = "wgsl"))]
+#[test]
+fn included_files_are_watched() {
+    let _mock = crate::mock::install();
+    let device = crate::mock::create_device();
+
+    let dir = std::env::temp_dir().join(format!("dawn-hot-reload-include-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let common = dir.join("common.wgsl");
+    let path = dir.join("shader.wgsl");
+    fs::write(
+        &common,
+        "fn color() -> vec4<f32> { return vec4<f32>(1.0); }\n",
+    )
+    .unwrap();
+    fs::write(
+        &path,
+        "#include \"common.wgsl\"\n\
+         @fragment\n\
+         fn main() -> @location(0) vec4<f32> { return color(); }\n",
+    )
+    .unwrap();
+
+    let mut watcher = ShaderWatcher::new(&device);
+    let shader = watcher.add_shader(&path).unwrap();
+    let first_module = shader.get().raw;
+    assert!(watcher.poll().is_empty());
+
+    // Changes the length, in case the modification time has a coarse resolution.
+    fs::write(
+        &common,
+        "fn color() -> vec4<f32> { return vec4<f32>(0.5); }\n\n",
+    )
+    .unwrap();
+    assert!(watcher.poll().is_empty());
+    assert_ne!(first_module, shader.get().raw);
+
+    fs::write(&common, "fn color() -> vec4<f32> { return 1.0; }\n").unwrap();
+    match &watcher.poll()[..] {
+        [ReloadError::Wgsl { path, error }] => {
+            assert_eq!(&common, path);
+            assert_eq!(1, error.labels()[0].span.line);
+        }
+        errors => panic!("{:?}", errors),
+    }
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,1375 @@
+//! Mock WebGPU implementation for unit-testing code written against dawn.
+//!
+//! The mock installs a pure-Rust proc table that hands out fake handles, tracks reference
+//! counts through the `*Reference` and `*Release` entries and records every other call in a
+//! log. No adapter or GPU is needed, and Dawn isn't built: the feature builds dawn-sys with its
+//! `dispatch` feature, which forwards the `wgpu*` functions to the installed table in Rust.
+//! `Instance::enumerate_adapters` finds no adapters in such a build.
+//!
+//! The proc table is process-global, so `install` returns a guard that serializes tests
+//! using the mock.
+//!
+//! ```no_run
+//! let _mock = dawn::mock::install();
+//! let device = dawn::mock::create_device();
+//! // ... run the code under test ...
+//! let calls: Vec<String> = dawn::mock::take_calls().iter().map(|c| c.to_string()).collect();
+//! assert!(calls.contains(&"draw(3, 1, 0, 0)".to_string()));
+//! ```
+
+use std::{collections::HashMap, ffi::CStr, fmt, ptr, slice, sync::Arc};
+
+use libc::{c_char, c_void};
+use parking_lot::{Mutex, MutexGuard};
+
+use dawn_sys as sys;
+
+use crate::{Adapter, BackendType, Device, DeviceInner};
+
+/// The type of object a fake handle refers to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ObjectKind {
+    BindGroup,
+    BindGroupLayout,
+    Buffer,
+    CommandBuffer,
+    CommandEncoder,
+    ComputePassEncoder,
+    ComputePipeline,
+    Device,
+    Fence,
+    Instance,
+    PipelineLayout,
+    Queue,
+    RenderBundle,
+    RenderBundleEncoder,
+    RenderPassEncoder,
+    RenderPipeline,
+    Sampler,
+    ShaderModule,
+    Surface,
+    SwapChain,
+    Texture,
+    TextureView,
+}
+
+/// A fake handle. Handles are numbered from `1` in creation order and never reused while the
+/// mock is installed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Handle(pub usize);
+
+impl fmt::Display for Handle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+/// An argument recorded in the call log.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Arg {
+    Handle(Handle),
+    Uint(u64),
+    Int(i64),
+    Float(f32),
+    Bool(bool),
+    Str(String),
+}
+
+impl fmt::Display for Arg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Arg::Handle(handle) => write!(f, "{}", handle),
+            Arg::Uint(v) => write!(f, "{}", v),
+            Arg::Int(v) => write!(f, "{}", v),
+            Arg::Float(v) => write!(f, "{}", v),
+            Arg::Bool(v) => write!(f, "{}", v),
+            Arg::Str(v) => write!(f, "{:?}", v),
+        }
+    }
+}
+
+impl From<u32> for Arg {
+    fn from(v: u32) -> Arg {
+        Arg::Uint(v.into())
+    }
+}
+
+impl From<u64> for Arg {
+    fn from(v: u64) -> Arg {
+        Arg::Uint(v)
+    }
+}
+
+impl From<i32> for Arg {
+    fn from(v: i32) -> Arg {
+        Arg::Int(v.into())
+    }
+}
+
+impl From<f32> for Arg {
+    fn from(v: f32) -> Arg {
+        Arg::Float(v)
+    }
+}
+
+impl From<bool> for Arg {
+    fn from(v: bool) -> Arg {
+        Arg::Bool(v)
+    }
+}
+
+impl<T> From<*mut T> for Arg {
+    fn from(v: *mut T) -> Arg {
+        Arg::Handle(Handle(v as usize))
+    }
+}
+
+unsafe fn string(v: *const c_char) -> String {
+    if v.is_null() {
+        String::new()
+    } else {
+        CStr::from_ptr(v).to_string_lossy().into_owned()
+    }
+}
+
+/// A recorded call. The name is the dawn method name without the object prefix, for example
+/// `draw` for `wgpuRenderPassEncoderDraw`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Call {
+    pub receiver_kind: ObjectKind,
+    pub receiver: Handle,
+    pub name: &'static str,
+    pub args: Vec<Arg>,
+    /// The handle returned by the call, if it created an object.
+    pub result: Option<Handle>,
+}
+
+impl fmt::Display for Call {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}(", self.name)?;
+        for (i, arg) in self.args.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", arg)?;
+        }
+        write!(f, ")")
+    }
+}
+
+macro_rules! args {
+    ($($arg:expr),*) => {
+        vec![$(Arg::from($arg)),*]
+    };
+}
+
+struct Object {
+    kind: ObjectKind,
+    refs: u32,
+    data: Vec<u8>,
+}
+
+#[derive(Default)]
+struct DeviceState {
+    error_callback: Option<(
+        unsafe extern "C" fn(sys::WGPUErrorType, *const c_char, *mut c_void),
+        usize,
+    )>,
+    error_scopes: Vec<(sys::WGPUErrorFilter, Option<(sys::WGPUErrorType, String)>)>,
+}
+
+#[derive(Default)]
+struct MockState {
+    next_handle: usize,
+    objects: HashMap<usize, Object>,
+    calls: Vec<Call>,
+    devices: HashMap<usize, DeviceState>,
+    fence_values: HashMap<usize, u64>,
+    errors: Vec<String>,
+}
+
+unsafe impl Send for MockState {}
+
+static STATE: Mutex<Option<MockState>> = parking_lot::const_mutex(None);
+static INSTALL_LOCK: Mutex<()> = parking_lot::const_mutex(());
+
+fn with_state<R>(f: impl FnOnce(&mut MockState) -> R) -> R {
+    let mut guard = STATE.lock();
+    f(guard
+        .as_mut()
+        .expect("the mock proc table is not installed"))
+}
+
+impl MockState {
+    fn record(&mut self, receiver: usize, name: &'static str, args: Vec<Arg>) {
+        let receiver_kind = match self.objects.get(&receiver) {
+            Some(object) => object.kind,
+            None => {
+                let error = format!(
+                    "{} called on unknown or released handle #{}",
+                    name, receiver
+                );
+                self.errors.push(error);
+                return;
+            }
+        };
+        self.calls.push(Call {
+            receiver_kind,
+            receiver: Handle(receiver),
+            name,
+            args,
+            result: None,
+        });
+    }
+
+    /// The contents of a buffer, or `None` after recording an error if the handle is unknown.
+    fn buffer_data(&mut self, buffer: usize, name: &'static str) -> Option<&mut Vec<u8>> {
+        match self.objects.get_mut(&buffer) {
+            Some(object) => Some(&mut object.data),
+            None => {
+                let error = format!("{} of unknown or released buffer #{}", name, buffer);
+                self.errors.push(error);
+                None
+            }
+        }
+    }
+
+    fn allocate(&mut self, kind: ObjectKind, data: Vec<u8>) -> usize {
+        self.next_handle += 1;
+        let handle = self.next_handle;
+        self.objects.insert(
+            handle,
+            Object {
+                kind,
+                refs: 1,
+                data,
+            },
+        );
+        handle
+    }
+
+    fn create(
+        &mut self,
+        receiver: usize,
+        name: &'static str,
+        args: Vec<Arg>,
+        kind: ObjectKind,
+    ) -> usize {
+        self.record(receiver, name, args);
+        let handle = self.allocate(kind, Vec::new());
+        if let Some(call) = self.calls.last_mut() {
+            call.result = Some(Handle(handle));
+        }
+        handle
+    }
+}
+
+trait MockObject {
+    const KIND: ObjectKind;
+}
+
+macro_rules! impl_mock_object {
+    ($($Impl:ident => $Kind:ident,)*) => {
+        $(impl MockObject for sys::$Impl {
+            const KIND: ObjectKind = ObjectKind::$Kind;
+        })*
+    };
+}
+
+impl_mock_object! {
+    WGPUBindGroupImpl => BindGroup,
+    WGPUBindGroupLayoutImpl => BindGroupLayout,
+    WGPUBufferImpl => Buffer,
+    WGPUCommandBufferImpl => CommandBuffer,
+    WGPUCommandEncoderImpl => CommandEncoder,
+    WGPUComputePassEncoderImpl => ComputePassEncoder,
+    WGPUComputePipelineImpl => ComputePipeline,
+    WGPUDeviceImpl => Device,
+    WGPUFenceImpl => Fence,
+    WGPUInstanceImpl => Instance,
+    WGPUPipelineLayoutImpl => PipelineLayout,
+    WGPUQueueImpl => Queue,
+    WGPURenderBundleImpl => RenderBundle,
+    WGPURenderBundleEncoderImpl => RenderBundleEncoder,
+    WGPURenderPassEncoderImpl => RenderPassEncoder,
+    WGPURenderPipelineImpl => RenderPipeline,
+    WGPUSamplerImpl => Sampler,
+    WGPUShaderModuleImpl => ShaderModule,
+    WGPUSurfaceImpl => Surface,
+    WGPUSwapChainImpl => SwapChain,
+    WGPUTextureImpl => Texture,
+    WGPUTextureViewImpl => TextureView,
+}
+
+unsafe extern "C" fn reference<T: MockObject>(handle: *mut T) {
+    with_state(|state| match state.objects.get_mut(&(handle as usize)) {
+        Some(object) => object.refs += 1,
+        None => {
+            let error = format!("reference of unknown {:?} #{}", T::KIND, handle as usize);
+            state.errors.push(error);
+        }
+    });
+}
+
+unsafe extern "C" fn release<T: MockObject>(handle: *mut T) {
+    with_state(|state| {
+        let handle = handle as usize;
+        let object = match state.objects.get_mut(&handle) {
+            Some(object) => object,
+            None => {
+                let error = format!("release of unknown {:?} #{}", T::KIND, handle);
+                state.errors.push(error);
+                return;
+            }
+        };
+        object.refs -= 1;
+        if object.refs == 0 {
+            state.objects.remove(&handle);
+            state.devices.remove(&handle);
+            state.fence_values.remove(&handle);
+        }
+    });
+}
+
+unsafe extern "C" fn create<R, D, T: MockObject>(
+    receiver: *mut R,
+    _descriptor: *const D,
+) -> *mut T {
+    // Generic creation for objects whose descriptor isn't worth recording.
+    let name = match T::KIND {
+        ObjectKind::BindGroup => "create_bind_group",
+        ObjectKind::BindGroupLayout => "create_bind_group_layout",
+        ObjectKind::CommandEncoder => "create_command_encoder",
+        ObjectKind::PipelineLayout => "create_pipeline_layout",
+        ObjectKind::ComputePipeline => "create_compute_pipeline",
+        ObjectKind::RenderPipeline => "create_render_pipeline",
+        ObjectKind::Sampler => "create_sampler",
+        ObjectKind::RenderBundleEncoder => "create_render_bundle_encoder",
+        ObjectKind::Surface => "create_surface",
+        ObjectKind::ComputePassEncoder => "begin_compute_pass",
+        ObjectKind::CommandBuffer | ObjectKind::RenderBundle => "finish",
+        ObjectKind::TextureView => "create_view",
+        ObjectKind::Fence => "create_fence",
+        _ => "create",
+    };
+    with_state(|state| state.create(receiver as usize, name, args![], T::KIND) as *mut T)
+}
+
+unsafe extern "C" fn create_instance(
+    _descriptor: *const sys::WGPUInstanceDescriptor,
+) -> sys::WGPUInstance {
+    with_state(|state| state.allocate(ObjectKind::Instance, Vec::new()) as _)
+}
+
+unsafe extern "C" fn get_proc_address(
+    _device: sys::WGPUDevice,
+    _name: *const c_char,
+) -> sys::WGPUProc {
+    None
+}
+
+unsafe extern "C" fn buffer_destroy(buffer: sys::WGPUBuffer) {
+    with_state(|state| state.record(buffer as _, "destroy", args![]));
+}
+
+unsafe extern "C" fn buffer_map_read_async(
+    buffer: sys::WGPUBuffer,
+    callback: sys::WGPUBufferMapReadCallback,
+    userdata: *mut c_void,
+) {
+    let mapped = with_state(|state| {
+        state.record(buffer as _, "map_read_async", args![]);
+        let data = state.buffer_data(buffer as _, "map_read_async")?;
+        Some((data.as_ptr(), data.len()))
+    });
+    if let Some(callback) = callback {
+        match mapped {
+            Some((data, len)) => callback(
+                sys::WGPUBufferMapAsyncStatus_Success,
+                data as _,
+                len as _,
+                userdata,
+            ),
+            None => callback(
+                sys::WGPUBufferMapAsyncStatus_Error,
+                ptr::null(),
+                0,
+                userdata,
+            ),
+        }
+    }
+}
+
+unsafe extern "C" fn buffer_map_write_async(
+    buffer: sys::WGPUBuffer,
+    callback: sys::WGPUBufferMapWriteCallback,
+    userdata: *mut c_void,
+) {
+    let mapped = with_state(|state| {
+        state.record(buffer as _, "map_write_async", args![]);
+        let data = state.buffer_data(buffer as _, "map_write_async")?;
+        Some((data.as_mut_ptr(), data.len()))
+    });
+    if let Some(callback) = callback {
+        match mapped {
+            Some((data, len)) => callback(
+                sys::WGPUBufferMapAsyncStatus_Success,
+                data as _,
+                len as _,
+                userdata,
+            ),
+            None => callback(
+                sys::WGPUBufferMapAsyncStatus_Error,
+                ptr::null_mut(),
+                0,
+                userdata,
+            ),
+        }
+    }
+}
+
+unsafe extern "C" fn buffer_set_sub_data(
+    buffer: sys::WGPUBuffer,
+    start: u64,
+    count: u64,
+    data: *const c_void,
+) {
+    with_state(|state| {
+        state.record(buffer as _, "set_sub_data", args![start, count]);
+        let storage = match state.buffer_data(buffer as _, "set_sub_data") {
+            Some(storage) => storage,
+            None => return,
+        };
+        let (start, count) = (start as usize, count as usize);
+        if start + count <= storage.len() {
+            let source = slice::from_raw_parts(data as *const u8, count);
+            storage[start..start + count].copy_from_slice(source);
+        }
+    });
+}
+
+unsafe extern "C" fn buffer_unmap(buffer: sys::WGPUBuffer) {
+    with_state(|state| state.record(buffer as _, "unmap", args![]));
+}
+
+unsafe extern "C" fn command_encoder_begin_render_pass(
+    encoder: sys::WGPUCommandEncoder,
+    descriptor: *const sys::WGPURenderPassDescriptor,
+) -> sys::WGPURenderPassEncoder {
+    let descriptor = &*descriptor;
+    let has_depth_stencil = !descriptor.depthStencilAttachment.is_null();
+    with_state(|state| {
+        let args = args![descriptor.colorAttachmentCount, has_depth_stencil];
+        state.create(
+            encoder as _,
+            "begin_render_pass",
+            args,
+            ObjectKind::RenderPassEncoder,
+        ) as _
+    })
+}
+
+unsafe extern "C" fn command_encoder_copy_buffer_to_buffer(
+    encoder: sys::WGPUCommandEncoder,
+    source: sys::WGPUBuffer,
+    source_offset: u64,
+    destination: sys::WGPUBuffer,
+    destination_offset: u64,
+    size: u64,
+) {
+    with_state(|state| {
+        let args = args![source, source_offset, destination, destination_offset, size];
+        state.record(encoder as _, "copy_buffer_to_buffer", args);
+        // Copies are executed immediately rather than at submit.
+        let (source_offset, destination_offset, size) = (
+            source_offset as usize,
+            destination_offset as usize,
+            size as usize,
+        );
+        let data = match state.buffer_data(source as _, "copy_buffer_to_buffer") {
+            Some(data) => data
+                .get(source_offset..source_offset + size)
+                .map(<[u8]>::to_vec),
+            None => return,
+        };
+        let storage = match state.buffer_data(destination as _, "copy_buffer_to_buffer") {
+            Some(storage) => storage,
+            None => return,
+        };
+        if let Some(data) = data {
+            if let Some(storage) = storage.get_mut(destination_offset..destination_offset + size) {
+                storage.copy_from_slice(&data);
+            }
+        }
+    });
+}
+
+unsafe extern "C" fn command_encoder_copy_buffer_to_texture(
+    encoder: sys::WGPUCommandEncoder,
+    source: *const sys::WGPUBufferCopyView,
+    destination: *const sys::WGPUTextureCopyView,
+    copy_size: *const sys::WGPUExtent3D,
+) {
+    let (source, destination, copy_size) = (&*source, &*destination, &*copy_size);
+    let args = args![
+        source.buffer,
+        destination.texture,
+        copy_size.width,
+        copy_size.height,
+        copy_size.depth
+    ];
+    with_state(|state| state.record(encoder as _, "copy_buffer_to_texture", args));
+}
+
+unsafe extern "C" fn command_encoder_copy_texture_to_buffer(
+    encoder: sys::WGPUCommandEncoder,
+    source: *const sys::WGPUTextureCopyView,
+    destination: *const sys::WGPUBufferCopyView,
+    copy_size: *const sys::WGPUExtent3D,
+) {
+    let (source, destination, copy_size) = (&*source, &*destination, &*copy_size);
+    let args = args![
+        source.texture,
+        destination.buffer,
+        copy_size.width,
+        copy_size.height,
+        copy_size.depth
+    ];
+    with_state(|state| state.record(encoder as _, "copy_texture_to_buffer", args));
+}
+
+unsafe extern "C" fn command_encoder_copy_texture_to_texture(
+    encoder: sys::WGPUCommandEncoder,
+    source: *const sys::WGPUTextureCopyView,
+    destination: *const sys::WGPUTextureCopyView,
+    copy_size: *const sys::WGPUExtent3D,
+) {
+    let (source, destination, copy_size) = (&*source, &*destination, &*copy_size);
+    let args = args![
+        source.texture,
+        destination.texture,
+        copy_size.width,
+        copy_size.height,
+        copy_size.depth
+    ];
+    with_state(|state| state.record(encoder as _, "copy_texture_to_texture", args));
+}
+
+unsafe extern "C" fn insert_debug_marker<T: MockObject>(receiver: *mut T, label: *const c_char) {
+    let args = vec![Arg::Str(string(label))];
+    with_state(|state| state.record(receiver as _, "insert_debug_marker", args));
+}
+
+unsafe extern "C" fn push_debug_group<T: MockObject>(receiver: *mut T, label: *const c_char) {
+    let args = vec![Arg::Str(string(label))];
+    with_state(|state| state.record(receiver as _, "push_debug_group", args));
+}
+
+unsafe extern "C" fn pop_debug_group<T: MockObject>(receiver: *mut T) {
+    with_state(|state| state.record(receiver as _, "pop_debug_group", args![]));
+}
+
+unsafe extern "C" fn end_pass<T: MockObject>(receiver: *mut T) {
+    with_state(|state| state.record(receiver as _, "end_pass", args![]));
+}
+
+unsafe extern "C" fn set_bind_group<T: MockObject, G>(
+    receiver: *mut T,
+    group_index: u32,
+    group: *mut G,
+    dynamic_offset_count: u32,
+    dynamic_offsets: *const u32,
+) {
+    let mut args = args![group_index, group];
+    if dynamic_offset_count > 0 {
+        let offsets = slice::from_raw_parts(dynamic_offsets, dynamic_offset_count as usize);
+        args.extend(offsets.iter().map(|offset| Arg::from(*offset)));
+    }
+    with_state(|state| state.record(receiver as _, "set_bind_group", args));
+}
+
+unsafe extern "C" fn set_pipeline<T: MockObject, P>(receiver: *mut T, pipeline: *mut P) {
+    with_state(|state| state.record(receiver as _, "set_pipeline", args![pipeline]));
+}
+
+unsafe extern "C" fn draw<T: MockObject>(
+    receiver: *mut T,
+    vertex_count: u32,
+    instance_count: u32,
+    first_vertex: u32,
+    first_instance: u32,
+) {
+    let args = args![vertex_count, instance_count, first_vertex, first_instance];
+    with_state(|state| state.record(receiver as _, "draw", args));
+}
+
+unsafe extern "C" fn draw_indexed<T: MockObject>(
+    receiver: *mut T,
+    index_count: u32,
+    instance_count: u32,
+    first_index: u32,
+    base_vertex: i32,
+    first_instance: u32,
+) {
+    let args = args![
+        index_count,
+        instance_count,
+        first_index,
+        base_vertex,
+        first_instance
+    ];
+    with_state(|state| state.record(receiver as _, "draw_indexed", args));
+}
+
+unsafe extern "C" fn draw_indirect<T: MockObject>(
+    receiver: *mut T,
+    buffer: sys::WGPUBuffer,
+    offset: u64,
+) {
+    with_state(|state| state.record(receiver as _, "draw_indirect", args![buffer, offset]));
+}
+
+unsafe extern "C" fn draw_indexed_indirect<T: MockObject>(
+    receiver: *mut T,
+    buffer: sys::WGPUBuffer,
+    offset: u64,
+) {
+    let args = args![buffer, offset];
+    with_state(|state| state.record(receiver as _, "draw_indexed_indirect", args));
+}
+
+unsafe extern "C" fn set_index_buffer<T: MockObject>(
+    receiver: *mut T,
+    buffer: sys::WGPUBuffer,
+    offset: u64,
+) {
+    with_state(|state| state.record(receiver as _, "set_index_buffer", args![buffer, offset]));
+}
+
+unsafe extern "C" fn set_vertex_buffer<T: MockObject>(
+    receiver: *mut T,
+    slot: u32,
+    buffer: sys::WGPUBuffer,
+    offset: u64,
+) {
+    let args = args![slot, buffer, offset];
+    with_state(|state| state.record(receiver as _, "set_vertex_buffer", args));
+}
+
+unsafe extern "C" fn compute_pass_encoder_dispatch(
+    pass: sys::WGPUComputePassEncoder,
+    x: u32,
+    y: u32,
+    z: u32,
+) {
+    with_state(|state| state.record(pass as _, "dispatch", args![x, y, z]));
+}
+
+unsafe extern "C" fn compute_pass_encoder_dispatch_indirect(
+    pass: sys::WGPUComputePassEncoder,
+    buffer: sys::WGPUBuffer,
+    offset: u64,
+) {
+    with_state(|state| state.record(pass as _, "dispatch_indirect", args![buffer, offset]));
+}
+
+unsafe extern "C" fn get_bind_group_layout<T: MockObject>(
+    pipeline: *mut T,
+    group_index: u32,
+) -> sys::WGPUBindGroupLayout {
+    with_state(|state| {
+        let args = args![group_index];
+        let kind = ObjectKind::BindGroupLayout;
+        state.create(pipeline as _, "get_bind_group_layout", args, kind) as _
+    })
+}
+
+unsafe extern "C" fn device_create_buffer(
+    device: sys::WGPUDevice,
+    descriptor: *const sys::WGPUBufferDescriptor,
+) -> sys::WGPUBuffer {
+    let descriptor = &*descriptor;
+    with_state(|state| {
+        let args = args![descriptor.size, descriptor.usage];
+        let handle = state.create(device as _, "create_buffer", args, ObjectKind::Buffer);
+        state.objects.get_mut(&handle).unwrap().data = vec![0; descriptor.size as usize];
+        handle as _
+    })
+}
+
+unsafe extern "C" fn device_create_buffer_mapped(
+    device: sys::WGPUDevice,
+    descriptor: *const sys::WGPUBufferDescriptor,
+) -> sys::WGPUCreateBufferMappedResult {
+    let descriptor = &*descriptor;
+    with_state(|state| {
+        let args = args![descriptor.size, descriptor.usage];
+        let handle = state.create(
+            device as _,
+            "create_buffer_mapped",
+            args,
+            ObjectKind::Buffer,
+        );
+        let data = &mut state.objects.get_mut(&handle).unwrap().data;
+        *data = vec![0; descriptor.size as usize];
+        sys::WGPUCreateBufferMappedResult {
+            buffer: handle as _,
+            dataLength: descriptor.size,
+            data: data.as_mut_ptr() as _,
+        }
+    })
+}
+
+unsafe extern "C" fn device_create_buffer_mapped_async(
+    device: sys::WGPUDevice,
+    descriptor: *const sys::WGPUBufferDescriptor,
+    callback: sys::WGPUBufferCreateMappedCallback,
+    userdata: *mut c_void,
+) {
+    let result = device_create_buffer_mapped(device, descriptor);
+    if let Some(callback) = callback {
+        callback(sys::WGPUBufferMapAsyncStatus_Success, result, userdata);
+    }
+}
+
+unsafe extern "C" fn device_create_queue(device: sys::WGPUDevice) -> sys::WGPUQueue {
+    with_state(|state| state.create(device as _, "create_queue", args![], ObjectKind::Queue) as _)
+}
+
+unsafe extern "C" fn device_create_shader_module(
+    device: sys::WGPUDevice,
+    descriptor: *const sys::WGPUShaderModuleDescriptor,
+) -> sys::WGPUShaderModule {
+    let descriptor = &*descriptor;
+    with_state(|state| {
+        let args = args![descriptor.codeSize];
+        let kind = ObjectKind::ShaderModule;
+        state.create(device as _, "create_shader_module", args, kind) as _
+    })
+}
+
+unsafe extern "C" fn device_create_swap_chain(
+    device: sys::WGPUDevice,
+    surface: sys::WGPUSurface,
+    descriptor: *const sys::WGPUSwapChainDescriptor,
+) -> sys::WGPUSwapChain {
+    let descriptor = &*descriptor;
+    with_state(|state| {
+        let args = args![
+            surface,
+            descriptor.width,
+            descriptor.height,
+            descriptor.format
+        ];
+        let kind = ObjectKind::SwapChain;
+        state.create(device as _, "create_swap_chain", args, kind) as _
+    })
+}
+
+unsafe extern "C" fn device_create_texture(
+    device: sys::WGPUDevice,
+    descriptor: *const sys::WGPUTextureDescriptor,
+) -> sys::WGPUTexture {
+    let descriptor = &*descriptor;
+    with_state(|state| {
+        let args = args![
+            descriptor.size.width,
+            descriptor.size.height,
+            descriptor.size.depth,
+            descriptor.format,
+            descriptor.usage
+        ];
+        state.create(device as _, "create_texture", args, ObjectKind::Texture) as _
+    })
+}
+
+unsafe extern "C" fn device_inject_error(
+    device: sys::WGPUDevice,
+    ty: sys::WGPUErrorType,
+    message: *const c_char,
+) {
+    let message = string(message);
+    let callback = with_state(|state| {
+        state.record(
+            device as _,
+            "inject_error",
+            args![ty, Arg::Str(message.clone())],
+        );
+        let device_state = state.devices.entry(device as _).or_default();
+        let filter = match ty {
+            sys::WGPUErrorType_Validation => sys::WGPUErrorFilter_Validation,
+            sys::WGPUErrorType_OutOfMemory => sys::WGPUErrorFilter_OutOfMemory,
+            _ => sys::WGPUErrorFilter_None,
+        };
+        let scope = device_state
+            .error_scopes
+            .iter_mut()
+            .rev()
+            .find(|(scope_filter, _)| *scope_filter == filter);
+        match scope {
+            Some((_, captured)) => {
+                if captured.is_none() {
+                    *captured = Some((ty, message.clone()));
+                }
+                None
+            }
+            None => device_state.error_callback,
+        }
+    });
+    if let Some((callback, userdata)) = callback {
+        let message = crate::convert::label(Some(&message));
+        callback(ty, message.as_ptr(), userdata as _);
+    }
+}
+
+unsafe extern "C" fn device_lose_for_testing(device: sys::WGPUDevice) {
+    with_state(|state| state.record(device as _, "lose_for_testing", args![]));
+}
+
+unsafe extern "C" fn device_push_error_scope(
+    device: sys::WGPUDevice,
+    filter: sys::WGPUErrorFilter,
+) {
+    with_state(|state| {
+        state.record(device as _, "push_error_scope", args![filter]);
+        let device_state = state.devices.entry(device as _).or_default();
+        device_state.error_scopes.push((filter, None));
+    });
+}
+
+unsafe extern "C" fn device_pop_error_scope(
+    device: sys::WGPUDevice,
+    callback: sys::WGPUErrorCallback,
+    userdata: *mut c_void,
+) -> bool {
+    let scope = with_state(|state| {
+        state.record(device as _, "pop_error_scope", args![]);
+        let device_state = state.devices.entry(device as _).or_default();
+        device_state.error_scopes.pop()
+    });
+    let (ty, message) = match scope {
+        Some((_, Some((ty, message)))) => (ty, message),
+        Some((_, None)) => (sys::WGPUErrorType_NoError, String::new()),
+        None => return false,
+    };
+    if let Some(callback) = callback {
+        let message = crate::convert::label(Some(&message));
+        callback(ty, message.as_ptr(), userdata);
+    }
+    true
+}
+
+unsafe extern "C" fn device_set_device_lost_callback(
+    device: sys::WGPUDevice,
+    _callback: sys::WGPUDeviceLostCallback,
+    _userdata: *mut c_void,
+) {
+    with_state(|state| state.record(device as _, "set_device_lost_callback", args![]));
+}
+
+unsafe extern "C" fn device_set_uncaptured_error_callback(
+    device: sys::WGPUDevice,
+    callback: sys::WGPUErrorCallback,
+    userdata: *mut c_void,
+) {
+    with_state(|state| {
+        state.record(device as _, "set_uncaptured_error_callback", args![]);
+        let device_state = state.devices.entry(device as _).or_default();
+        device_state.error_callback = callback.map(|callback| (callback, userdata as usize));
+    });
+}
+
+unsafe extern "C" fn device_tick(device: sys::WGPUDevice) {
+    with_state(|state| state.record(device as _, "tick", args![]));
+}
+
+unsafe extern "C" fn fence_get_completed_value(fence: sys::WGPUFence) -> u64 {
+    with_state(|state| {
+        state
+            .fence_values
+            .get(&(fence as usize))
+            .cloned()
+            .unwrap_or(0)
+    })
+}
+
+unsafe extern "C" fn fence_on_completion(
+    fence: sys::WGPUFence,
+    value: u64,
+    callback: sys::WGPUFenceOnCompletionCallback,
+    userdata: *mut c_void,
+) {
+    // Signals complete immediately, so waiting on anything above the signaled value is an error.
+    let completed = with_state(|state| {
+        state.record(fence as _, "on_completion", args![value]);
+        state
+            .fence_values
+            .get(&(fence as usize))
+            .cloned()
+            .unwrap_or(0)
+    });
+    let status = if value <= completed {
+        sys::WGPUFenceCompletionStatus_Success
+    } else {
+        sys::WGPUFenceCompletionStatus_Error
+    };
+    if let Some(callback) = callback {
+        callback(status, userdata);
+    }
+}
+
+unsafe extern "C" fn queue_create_fence(
+    queue: sys::WGPUQueue,
+    descriptor: *const sys::WGPUFenceDescriptor,
+) -> sys::WGPUFence {
+    let descriptor = &*descriptor;
+    with_state(|state| {
+        let args = args![descriptor.initialValue];
+        let handle = state.create(queue as _, "create_fence", args, ObjectKind::Fence);
+        state.fence_values.insert(handle, descriptor.initialValue);
+        handle as _
+    })
+}
+
+unsafe extern "C" fn queue_signal(queue: sys::WGPUQueue, fence: sys::WGPUFence, value: u64) {
+    with_state(|state| {
+        state.record(queue as _, "signal", args![fence, value]);
+        state.fence_values.insert(fence as usize, value);
+    });
+}
+
+unsafe extern "C" fn queue_submit(
+    queue: sys::WGPUQueue,
+    command_count: u32,
+    commands: *const sys::WGPUCommandBuffer,
+) {
+    let commands = if command_count > 0 {
+        slice::from_raw_parts(commands, command_count as usize)
+    } else {
+        &[]
+    };
+    let args = commands.iter().map(|command| Arg::from(*command)).collect();
+    with_state(|state| state.record(queue as _, "submit", args));
+}
+
+unsafe extern "C" fn render_pass_encoder_execute_bundles(
+    pass: sys::WGPURenderPassEncoder,
+    bundles_count: u32,
+    bundles: *const sys::WGPURenderBundle,
+) {
+    let bundles = if bundles_count > 0 {
+        slice::from_raw_parts(bundles, bundles_count as usize)
+    } else {
+        &[]
+    };
+    let args = bundles.iter().map(|bundle| Arg::from(*bundle)).collect();
+    with_state(|state| state.record(pass as _, "execute_bundles", args));
+}
+
+unsafe extern "C" fn render_pass_encoder_set_blend_color(
+    pass: sys::WGPURenderPassEncoder,
+    color: *const sys::WGPUColor,
+) {
+    let color = &*color;
+    let args = args![color.r, color.g, color.b, color.a];
+    with_state(|state| state.record(pass as _, "set_blend_color", args));
+}
+
+unsafe extern "C" fn render_pass_encoder_set_scissor_rect(
+    pass: sys::WGPURenderPassEncoder,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+) {
+    let args = args![x, y, width, height];
+    with_state(|state| state.record(pass as _, "set_scissor_rect", args));
+}
+
+unsafe extern "C" fn render_pass_encoder_set_stencil_reference(
+    pass: sys::WGPURenderPassEncoder,
+    reference: u32,
+) {
+    with_state(|state| state.record(pass as _, "set_stencil_reference", args![reference]));
+}
+
+unsafe extern "C" fn render_pass_encoder_set_viewport(
+    pass: sys::WGPURenderPassEncoder,
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    min_depth: f32,
+    max_depth: f32,
+) {
+    let args = args![x, y, width, height, min_depth, max_depth];
+    with_state(|state| state.record(pass as _, "set_viewport", args));
+}
+
+unsafe extern "C" fn swap_chain_configure(
+    swap_chain: sys::WGPUSwapChain,
+    format: sys::WGPUTextureFormat,
+    allowed_usage: sys::WGPUTextureUsageFlags,
+    width: u32,
+    height: u32,
+) {
+    let args = args![format, allowed_usage, width, height];
+    with_state(|state| state.record(swap_chain as _, "configure", args));
+}
+
+unsafe extern "C" fn swap_chain_get_current_texture_view(
+    swap_chain: sys::WGPUSwapChain,
+) -> sys::WGPUTextureView {
+    with_state(|state| {
+        let name = "get_current_texture_view";
+        state.create(swap_chain as _, name, args![], ObjectKind::TextureView) as _
+    })
+}
+
+unsafe extern "C" fn swap_chain_present(swap_chain: sys::WGPUSwapChain) {
+    with_state(|state| state.record(swap_chain as _, "present", args![]));
+}
+
+unsafe extern "C" fn texture_destroy(texture: sys::WGPUTexture) {
+    with_state(|state| state.record(texture as _, "destroy", args![]));
+}
+
+#[rustfmt::skip]
+fn proc_table() -> sys::DawnProcTable {
+    use sys::*;
+    sys::DawnProcTable {
+        getProcAddress: Some(get_proc_address),
+        createInstance: Some(create_instance),
+        bindGroupReference: Some(reference::<WGPUBindGroupImpl>),
+        bindGroupRelease: Some(release::<WGPUBindGroupImpl>),
+        bindGroupLayoutReference: Some(reference::<WGPUBindGroupLayoutImpl>),
+        bindGroupLayoutRelease: Some(release::<WGPUBindGroupLayoutImpl>),
+        bufferDestroy: Some(buffer_destroy),
+        bufferMapReadAsync: Some(buffer_map_read_async),
+        bufferMapWriteAsync: Some(buffer_map_write_async),
+        bufferSetSubData: Some(buffer_set_sub_data),
+        bufferUnmap: Some(buffer_unmap),
+        bufferReference: Some(reference::<WGPUBufferImpl>),
+        bufferRelease: Some(release::<WGPUBufferImpl>),
+        commandBufferReference: Some(reference::<WGPUCommandBufferImpl>),
+        commandBufferRelease: Some(release::<WGPUCommandBufferImpl>),
+        commandEncoderBeginComputePass: Some(create::<_, _, WGPUComputePassEncoderImpl>),
+        commandEncoderBeginRenderPass: Some(command_encoder_begin_render_pass),
+        commandEncoderCopyBufferToBuffer: Some(command_encoder_copy_buffer_to_buffer),
+        commandEncoderCopyBufferToTexture: Some(command_encoder_copy_buffer_to_texture),
+        commandEncoderCopyTextureToBuffer: Some(command_encoder_copy_texture_to_buffer),
+        commandEncoderCopyTextureToTexture: Some(command_encoder_copy_texture_to_texture),
+        commandEncoderFinish: Some(create::<_, _, WGPUCommandBufferImpl>),
+        commandEncoderInsertDebugMarker: Some(insert_debug_marker),
+        commandEncoderPopDebugGroup: Some(pop_debug_group),
+        commandEncoderPushDebugGroup: Some(push_debug_group),
+        commandEncoderReference: Some(reference::<WGPUCommandEncoderImpl>),
+        commandEncoderRelease: Some(release::<WGPUCommandEncoderImpl>),
+        computePassEncoderDispatch: Some(compute_pass_encoder_dispatch),
+        computePassEncoderDispatchIndirect: Some(compute_pass_encoder_dispatch_indirect),
+        computePassEncoderEndPass: Some(end_pass),
+        computePassEncoderInsertDebugMarker: Some(insert_debug_marker),
+        computePassEncoderPopDebugGroup: Some(pop_debug_group),
+        computePassEncoderPushDebugGroup: Some(push_debug_group),
+        computePassEncoderSetBindGroup: Some(set_bind_group),
+        computePassEncoderSetPipeline: Some(set_pipeline),
+        computePassEncoderReference: Some(reference::<WGPUComputePassEncoderImpl>),
+        computePassEncoderRelease: Some(release::<WGPUComputePassEncoderImpl>),
+        computePipelineGetBindGroupLayout: Some(get_bind_group_layout),
+        computePipelineReference: Some(reference::<WGPUComputePipelineImpl>),
+        computePipelineRelease: Some(release::<WGPUComputePipelineImpl>),
+        deviceCreateBindGroup: Some(create::<_, _, WGPUBindGroupImpl>),
+        deviceCreateBindGroupLayout: Some(create::<_, _, WGPUBindGroupLayoutImpl>),
+        deviceCreateBuffer: Some(device_create_buffer),
+        deviceCreateBufferMapped: Some(device_create_buffer_mapped),
+        deviceCreateBufferMappedAsync: Some(device_create_buffer_mapped_async),
+        deviceCreateCommandEncoder: Some(create::<_, _, WGPUCommandEncoderImpl>),
+        deviceCreateComputePipeline: Some(create::<_, _, WGPUComputePipelineImpl>),
+        deviceCreatePipelineLayout: Some(create::<_, _, WGPUPipelineLayoutImpl>),
+        deviceCreateQueue: Some(device_create_queue),
+        deviceCreateRenderBundleEncoder: Some(create::<_, _, WGPURenderBundleEncoderImpl>),
+        deviceCreateRenderPipeline: Some(create::<_, _, WGPURenderPipelineImpl>),
+        deviceCreateSampler: Some(create::<_, _, WGPUSamplerImpl>),
+        deviceCreateShaderModule: Some(device_create_shader_module),
+        deviceCreateSwapChain: Some(device_create_swap_chain),
+        deviceCreateTexture: Some(device_create_texture),
+        deviceInjectError: Some(device_inject_error),
+        deviceLoseForTesting: Some(device_lose_for_testing),
+        devicePopErrorScope: Some(device_pop_error_scope),
+        devicePushErrorScope: Some(device_push_error_scope),
+        deviceSetDeviceLostCallback: Some(device_set_device_lost_callback),
+        deviceSetUncapturedErrorCallback: Some(device_set_uncaptured_error_callback),
+        deviceTick: Some(device_tick),
+        deviceReference: Some(reference::<WGPUDeviceImpl>),
+        deviceRelease: Some(release::<WGPUDeviceImpl>),
+        fenceGetCompletedValue: Some(fence_get_completed_value),
+        fenceOnCompletion: Some(fence_on_completion),
+        fenceReference: Some(reference::<WGPUFenceImpl>),
+        fenceRelease: Some(release::<WGPUFenceImpl>),
+        instanceCreateSurface: Some(create::<_, _, WGPUSurfaceImpl>),
+        instanceReference: Some(reference::<WGPUInstanceImpl>),
+        instanceRelease: Some(release::<WGPUInstanceImpl>),
+        pipelineLayoutReference: Some(reference::<WGPUPipelineLayoutImpl>),
+        pipelineLayoutRelease: Some(release::<WGPUPipelineLayoutImpl>),
+        queueCreateFence: Some(queue_create_fence),
+        queueSignal: Some(queue_signal),
+        queueSubmit: Some(queue_submit),
+        queueReference: Some(reference::<WGPUQueueImpl>),
+        queueRelease: Some(release::<WGPUQueueImpl>),
+        renderBundleReference: Some(reference::<WGPURenderBundleImpl>),
+        renderBundleRelease: Some(release::<WGPURenderBundleImpl>),
+        renderBundleEncoderDraw: Some(draw),
+        renderBundleEncoderDrawIndexed: Some(draw_indexed),
+        renderBundleEncoderDrawIndexedIndirect: Some(draw_indexed_indirect),
+        renderBundleEncoderDrawIndirect: Some(draw_indirect),
+        renderBundleEncoderFinish: Some(create::<_, _, WGPURenderBundleImpl>),
+        renderBundleEncoderInsertDebugMarker: Some(insert_debug_marker),
+        renderBundleEncoderPopDebugGroup: Some(pop_debug_group),
+        renderBundleEncoderPushDebugGroup: Some(push_debug_group),
+        renderBundleEncoderSetBindGroup: Some(set_bind_group),
+        renderBundleEncoderSetIndexBuffer: Some(set_index_buffer),
+        renderBundleEncoderSetPipeline: Some(set_pipeline),
+        renderBundleEncoderSetVertexBuffer: Some(set_vertex_buffer),
+        renderBundleEncoderReference: Some(reference::<WGPURenderBundleEncoderImpl>),
+        renderBundleEncoderRelease: Some(release::<WGPURenderBundleEncoderImpl>),
+        renderPassEncoderDraw: Some(draw),
+        renderPassEncoderDrawIndexed: Some(draw_indexed),
+        renderPassEncoderDrawIndexedIndirect: Some(draw_indexed_indirect),
+        renderPassEncoderDrawIndirect: Some(draw_indirect),
+        renderPassEncoderEndPass: Some(end_pass),
+        renderPassEncoderExecuteBundles: Some(render_pass_encoder_execute_bundles),
+        renderPassEncoderInsertDebugMarker: Some(insert_debug_marker),
+        renderPassEncoderPopDebugGroup: Some(pop_debug_group),
+        renderPassEncoderPushDebugGroup: Some(push_debug_group),
+        renderPassEncoderSetBindGroup: Some(set_bind_group),
+        renderPassEncoderSetBlendColor: Some(render_pass_encoder_set_blend_color),
+        renderPassEncoderSetIndexBuffer: Some(set_index_buffer),
+        renderPassEncoderSetPipeline: Some(set_pipeline),
+        renderPassEncoderSetScissorRect: Some(render_pass_encoder_set_scissor_rect),
+        renderPassEncoderSetStencilReference: Some(render_pass_encoder_set_stencil_reference),
+        renderPassEncoderSetVertexBuffer: Some(set_vertex_buffer),
+        renderPassEncoderSetViewport: Some(render_pass_encoder_set_viewport),
+        renderPassEncoderReference: Some(reference::<WGPURenderPassEncoderImpl>),
+        renderPassEncoderRelease: Some(release::<WGPURenderPassEncoderImpl>),
+        renderPipelineGetBindGroupLayout: Some(get_bind_group_layout),
+        renderPipelineReference: Some(reference::<WGPURenderPipelineImpl>),
+        renderPipelineRelease: Some(release::<WGPURenderPipelineImpl>),
+        samplerReference: Some(reference::<WGPUSamplerImpl>),
+        samplerRelease: Some(release::<WGPUSamplerImpl>),
+        shaderModuleReference: Some(reference::<WGPUShaderModuleImpl>),
+        shaderModuleRelease: Some(release::<WGPUShaderModuleImpl>),
+        surfaceReference: Some(reference::<WGPUSurfaceImpl>),
+        surfaceRelease: Some(release::<WGPUSurfaceImpl>),
+        swapChainConfigure: Some(swap_chain_configure),
+        swapChainGetCurrentTextureView: Some(swap_chain_get_current_texture_view),
+        swapChainPresent: Some(swap_chain_present),
+        swapChainReference: Some(reference::<WGPUSwapChainImpl>),
+        swapChainRelease: Some(release::<WGPUSwapChainImpl>),
+        textureCreateView: Some(create::<_, _, WGPUTextureViewImpl>),
+        textureDestroy: Some(texture_destroy),
+        textureReference: Some(reference::<WGPUTextureImpl>),
+        textureRelease: Some(release::<WGPUTextureImpl>),
+        textureViewReference: Some(reference::<WGPUTextureViewImpl>),
+        textureViewRelease: Some(release::<WGPUTextureViewImpl>),
+    }
+}
+
+/// Keeps the mock installed. Dropping the guard leaves the proc table in place but allows
+/// another test to call `install`.
+pub struct MockGuard {
+    _lock: MutexGuard<'static, ()>,
+}
+
+/// Installs the mock proc table with an empty object table and call log. Blocks until any
+/// other `MockGuard` is dropped.
+pub fn install() -> MockGuard {
+    let lock = INSTALL_LOCK.lock();
+    *STATE.lock() = Some(MockState::default());
+    unsafe {
+        crate::set_dawn_proc_table(proc_table());
+    }
+    MockGuard { _lock: lock }
+}
+
+/// Creates a device backed by the mock. The adapter of the returned device has no properties,
+/// so `Adapter::properties` and `Adapter::extensions` must not be called on it.
+pub fn create_device() -> Device {
+    unsafe {
+        let instance = sys::wgpuCreateInstance(ptr::null());
+        let adapter = Adapter::from_raw(instance, 0);
+        sys::wgpuInstanceRelease(instance);
+        let raw = with_state(|state| state.allocate(ObjectKind::Device, Vec::new())) as _;
+        let raw_default_queue = sys::wgpuDeviceCreateQueue(raw);
+        let inner = DeviceInner {
+            raw,
+            raw_default_queue,
+            adapter,
+            backend_type: BackendType::Null,
+            pipeline_validation: false,
+        };
+        Device {
+            inner: Arc::new(Mutex::new(inner)),
+        }
+    }
+}
+
+/// Returns a copy of the call log.
+pub fn calls() -> Vec<Call> {
+    with_state(|state| state.calls.clone())
+}
+
+/// Returns the call log and clears it.
+pub fn take_calls() -> Vec<Call> {
+    with_state(|state| std::mem::take(&mut state.calls))
+}
+
+/// Returns the reference count of `handle`, or `0` if it has been released.
+pub fn ref_count(handle: Handle) -> u32 {
+    with_state(|state| state.objects.get(&handle.0).map(|o| o.refs).unwrap_or(0))
+}
+
+/// Returns the handles that are still alive, in creation order.
+pub fn live_objects() -> Vec<(Handle, ObjectKind)> {
+    with_state(|state| {
+        let mut objects: Vec<_> = state
+            .objects
+            .iter()
+            .map(|(handle, object)| (Handle(*handle), object.kind))
+            .collect();
+        objects.sort_by_key(|(handle, _)| *handle);
+        objects
+    })
+}
+
+/// Returns misuse detected by the mock, such as releasing a handle that was already released
+/// or calling a method on one.
+pub fn errors() -> Vec<String> {
+    with_state(|state| state.errors.clone())
+}
+
+/// Objects that can be identified in the call log.
+pub trait AsHandle {
+    fn handle(&self) -> Handle;
+}
+
+macro_rules! impl_as_handle {
+    ($($Type:ty),*) => {
+        $(impl AsHandle for $Type {
+            fn handle(&self) -> Handle {
+                Handle(self.raw as usize)
+            }
+        })*
+    };
+}
+
+impl_as_handle!(
+    crate::BindGroup,
+    crate::BindGroupLayout,
+    crate::Buffer,
+    crate::CommandBuffer,
+    crate::CommandEncoder,
+    crate::ComputePipeline,
+    crate::Fence,
+    crate::PipelineLayout,
+    crate::Queue,
+    crate::RenderBundle,
+    crate::RenderBundleEncoder,
+    crate::RenderPipeline,
+    crate::Sampler,
+    crate::ShaderModule,
+    crate::Surface,
+    crate::Texture,
+    crate::TextureView
+);
+
+impl AsHandle for Device {
+    fn handle(&self) -> Handle {
+        Handle(self.inner.lock().raw as usize)
+    }
+}
+
+impl AsHandle for crate::SwapChain {
+    fn handle(&self) -> Handle {
+        Handle(self.inner.raw as usize)
+    }
+}
+
+/// Returns the contents of a mock buffer, or `None` if the buffer isn't known to the installed
+/// mock. Writes through `set_sub_data`, mapping and `copy_buffer_to_buffer` are applied to this
+/// storage.
+pub fn buffer_data(buffer: &crate::Buffer) -> Option<Vec<u8>> {
+    with_state(|state| {
+        state
+            .objects
+            .get(&(buffer.raw as usize))
+            .map(|object| object.data.clone())
+    })
+}
+
+#[test]
+fn mock_records_calls_and_releases_objects() {
+    use crate::{BufferUsage, CommandEncoderDescriptor, RenderPassDescriptor};
+
+    let _mock = install();
+    {
+        let device = create_device();
+        let buffer = device.create_buffer_with_data(&[1, 2, 3, 4], BufferUsage::VERTEX);
+        assert_eq!(Some(vec![1, 2, 3, 4]), buffer_data(&buffer));
+
+        let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor::default());
+        let mut pass = encoder.begin_render_pass(&RenderPassDescriptor {
+            label: None,
+            color_attachments: &[],
+            depth_stencil_attachment: None,
+        });
+        pass.set_vertex_buffer(0, &buffer, 0);
+        pass.draw(3, 1, 0, 0);
+        pass.end_pass();
+        device.default_queue().submit(&[encoder.finish()]);
+
+        let calls: Vec<String> = take_calls().iter().map(|call| call.to_string()).collect();
+        let pass_calls: Vec<&str> = calls
+            .iter()
+            .map(String::as_str)
+            .skip_while(|call| !call.starts_with("begin_render_pass"))
+            .take(4)
+            .collect();
+        let vertex_buffer = format!("set_vertex_buffer(0, {}, 0)", buffer.handle());
+        assert_eq!(
+            vec![
+                "begin_render_pass(0, false)",
+                &vertex_buffer,
+                "draw(3, 1, 0, 0)",
+                "end_pass()",
+            ],
+            pass_calls
+        );
+    }
+    assert_eq!(Vec::<(Handle, ObjectKind)>::new(), live_objects());
+    assert_eq!(Vec::<String>::new(), errors());
+}
+
+#[test]
+fn unknown_buffers_are_reported_instead_of_panicking() {
+    unsafe extern "C" fn map_read_callback(
+        status: sys::WGPUBufferMapAsyncStatus,
+        data: *const c_void,
+        _data_length: u64,
+        userdata: *mut c_void,
+    ) {
+        assert!(data.is_null());
+        *(userdata as *mut sys::WGPUBufferMapAsyncStatus) = status;
+    }
+
+    let _mock = install();
+    let unknown = 0xdead as sys::WGPUBuffer;
+    let mut status = sys::WGPUBufferMapAsyncStatus_Success;
+    unsafe {
+        buffer_map_read_async(unknown, Some(map_read_callback), &mut status as *mut _ as _);
+        buffer_set_sub_data(unknown, 0, 0, ptr::null());
+    }
+    assert_eq!(sys::WGPUBufferMapAsyncStatus_Error, status);
+    let errors = errors();
+    assert!(errors.contains(&"map_read_async of unknown or released buffer #57005".to_string()));
+    assert!(errors.contains(&"set_sub_data of unknown or released buffer #57005".to_string()));
+}
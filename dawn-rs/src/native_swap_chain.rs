@@ -1,7 +1,12 @@
-use crate::{BackendType, Device, PresentMode, SwapChain, SwapChainDescriptor, TextureUsage};
+use crate::{
+    BackendType, Device, PresentMode, SwapChain, SwapChainDescriptor, TextureFormat, TextureUsage,
+};
 
 use dawn_sys as sys;
 
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
 pub enum NativeSwapChainSurfaceParams {
@@ -55,7 +60,9 @@ pub fn create_swap_chain(device: &Device, descriptor: NativeSwapChainDescriptor)
         label: None,
         width: descriptor.width,
         height: descriptor.height,
-        format: unsafe { std::mem::transmute(format) },
+        // Implementation based swap chains are configured with the raw format below, so an
+        // unknown preferred format only needs a placeholder here.
+        format: TextureFormat::try_from(format).unwrap_or(TextureFormat::BGRA8Unorm),
         present_mode: descriptor.present_mode,
         usage: TextureUsage::OUTPUT_ATTACHMENT,
         implementation: dawn_swap_chain_impl.as_ref() as *const _ as u64,
@@ -66,11 +73,13 @@ pub fn create_swap_chain(device: &Device, descriptor: NativeSwapChainDescriptor)
         sys::wgpuSwapChainConfigure(
             swap_chain.inner.raw,
             format as _,
-            descriptor.usage.bits as _,
+            descriptor.usage.bits() as _,
             descriptor.width,
             descriptor.height,
         )
     }
+    swap_chain.configured = AtomicBool::new(true);
+    swap_chain.preferred_format = TextureFormat::try_from(format).ok();
     swap_chain.dawn_swap_chain_impl = Some(dawn_swap_chain_impl);
     swap_chain
 }
@@ -85,3 +94,212 @@ pub fn get_vulkan_instance(device: &Device) -> sys::VkInstance {
     );
     unsafe { dawn_sys::dawn_native__vulkan__GetInstance(guard.raw) }
 }
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum VulkanSurfaceError {
+    /// The Vulkan loader library couldn't be opened.
+    LoaderNotFound,
+    /// The instance doesn't provide the surface creation entry point. The Vulkan driver may
+    /// not support the window system.
+    MissingEntryPoint(&'static str),
+    /// The surface creation entry point returned an error `VkResult`.
+    CreateSurface(i32),
+    /// The window handle isn't an Xlib, Xcb or Wayland window.
+    UnsupportedWindowHandle,
+}
+
+impl fmt::Display for VulkanSurfaceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VulkanSurfaceError::LoaderNotFound => write!(f, "failed to load libvulkan"),
+            VulkanSurfaceError::MissingEntryPoint(name) => {
+                write!(f, "missing entry point: {}", name)
+            }
+            VulkanSurfaceError::CreateSurface(result) => {
+                write!(f, "failed to create surface: VkResult {}", result)
+            }
+            VulkanSurfaceError::UnsupportedWindowHandle => write!(f, "unsupported window handle"),
+        }
+    }
+}
+
+impl std::error::Error for VulkanSurfaceError {}
+
+/// Creates a `VkSurfaceKHR` for an Xlib, Xcb or Wayland window with the instance of a Vulkan
+/// device. Pass the surface to `NativeSwapChainSurfaceParams::Vulkan`; the native swap chain
+/// takes ownership of it and destroys it when the swap chain is released.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+pub fn create_vulkan_surface<W: raw_window_handle::HasRawWindowHandle>(
+    device: &Device,
+    window: &W,
+) -> Result<sys::VkSurfaceKHR, VulkanSurfaceError> {
+    use raw_window_handle::RawWindowHandle;
+
+    let instance = get_vulkan_instance(device);
+    let mut surface = 0;
+    let result = unsafe {
+        match window.raw_window_handle() {
+            RawWindowHandle::Xlib(handle) => {
+                let create_info = vk::XlibSurfaceCreateInfoKHR {
+                    s_type: vk::STRUCTURE_TYPE_XLIB_SURFACE_CREATE_INFO_KHR,
+                    p_next: std::ptr::null(),
+                    flags: 0,
+                    dpy: handle.display,
+                    window: handle.window,
+                };
+                let create: vk::CreateXlibSurfaceKHR =
+                    vk::instance_proc(instance, "vkCreateXlibSurfaceKHR")?;
+                create(instance, &create_info, std::ptr::null(), &mut surface)
+            }
+            RawWindowHandle::Xcb(handle) => {
+                let create_info = vk::XcbSurfaceCreateInfoKHR {
+                    s_type: vk::STRUCTURE_TYPE_XCB_SURFACE_CREATE_INFO_KHR,
+                    p_next: std::ptr::null(),
+                    flags: 0,
+                    connection: handle.connection,
+                    window: handle.window,
+                };
+                let create: vk::CreateXcbSurfaceKHR =
+                    vk::instance_proc(instance, "vkCreateXcbSurfaceKHR")?;
+                create(instance, &create_info, std::ptr::null(), &mut surface)
+            }
+            RawWindowHandle::Wayland(handle) => {
+                let create_info = vk::WaylandSurfaceCreateInfoKHR {
+                    s_type: vk::STRUCTURE_TYPE_WAYLAND_SURFACE_CREATE_INFO_KHR,
+                    p_next: std::ptr::null(),
+                    flags: 0,
+                    display: handle.display,
+                    surface: handle.surface,
+                };
+                let create: vk::CreateWaylandSurfaceKHR =
+                    vk::instance_proc(instance, "vkCreateWaylandSurfaceKHR")?;
+                create(instance, &create_info, std::ptr::null(), &mut surface)
+            }
+            _ => return Err(VulkanSurfaceError::UnsupportedWindowHandle),
+        }
+    };
+    if result != vk::SUCCESS {
+        return Err(VulkanSurfaceError::CreateSurface(result));
+    }
+    Ok(surface)
+}
+
+/// The few Vulkan definitions needed to create surfaces, resolved through the loader that
+/// dawn uses.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+mod vk {
+    use super::VulkanSurfaceError;
+    use dawn_sys as sys;
+    use libc::{c_char, c_ulong, c_void};
+    use std::ffi::CString;
+    use std::mem;
+
+    pub const SUCCESS: i32 = 0;
+    pub const STRUCTURE_TYPE_XLIB_SURFACE_CREATE_INFO_KHR: u32 = 1_000_004_000;
+    pub const STRUCTURE_TYPE_XCB_SURFACE_CREATE_INFO_KHR: u32 = 1_000_005_000;
+    pub const STRUCTURE_TYPE_WAYLAND_SURFACE_CREATE_INFO_KHR: u32 = 1_000_006_000;
+
+    #[repr(C)]
+    pub struct XlibSurfaceCreateInfoKHR {
+        pub s_type: u32,
+        pub p_next: *const c_void,
+        pub flags: u32,
+        pub dpy: *mut c_void,
+        pub window: c_ulong,
+    }
+
+    #[repr(C)]
+    pub struct XcbSurfaceCreateInfoKHR {
+        pub s_type: u32,
+        pub p_next: *const c_void,
+        pub flags: u32,
+        pub connection: *mut c_void,
+        pub window: u32,
+    }
+
+    #[repr(C)]
+    pub struct WaylandSurfaceCreateInfoKHR {
+        pub s_type: u32,
+        pub p_next: *const c_void,
+        pub flags: u32,
+        pub display: *mut c_void,
+        pub surface: *mut c_void,
+    }
+
+    type GetInstanceProcAddr =
+        unsafe extern "C" fn(instance: sys::VkInstance, name: *const c_char) -> *const c_void;
+
+    pub type CreateXlibSurfaceKHR = unsafe extern "C" fn(
+        instance: sys::VkInstance,
+        create_info: *const XlibSurfaceCreateInfoKHR,
+        allocator: *const c_void,
+        surface: *mut sys::VkSurfaceKHR,
+    ) -> i32;
+
+    pub type CreateXcbSurfaceKHR = unsafe extern "C" fn(
+        instance: sys::VkInstance,
+        create_info: *const XcbSurfaceCreateInfoKHR,
+        allocator: *const c_void,
+        surface: *mut sys::VkSurfaceKHR,
+    ) -> i32;
+
+    pub type CreateWaylandSurfaceKHR = unsafe extern "C" fn(
+        instance: sys::VkInstance,
+        create_info: *const WaylandSurfaceCreateInfoKHR,
+        allocator: *const c_void,
+        surface: *mut sys::VkSurfaceKHR,
+    ) -> i32;
+
+    unsafe fn get_instance_proc_addr() -> Result<GetInstanceProcAddr, VulkanSurfaceError> {
+        // Dawn has already loaded the library, so this only takes another reference to it.
+        // It's never closed.
+        let mut library = libc::dlopen(
+            b"libvulkan.so.1\0".as_ptr() as _,
+            libc::RTLD_NOW | libc::RTLD_LOCAL,
+        );
+        if library.is_null() {
+            library = libc::dlopen(
+                b"libvulkan.so\0".as_ptr() as _,
+                libc::RTLD_NOW | libc::RTLD_LOCAL,
+            );
+        }
+        if library.is_null() {
+            return Err(VulkanSurfaceError::LoaderNotFound);
+        }
+        let symbol = libc::dlsym(library, b"vkGetInstanceProcAddr\0".as_ptr() as _);
+        if symbol.is_null() {
+            return Err(VulkanSurfaceError::MissingEntryPoint(
+                "vkGetInstanceProcAddr",
+            ));
+        }
+        Ok(mem::transmute::<*mut c_void, GetInstanceProcAddr>(symbol))
+    }
+
+    /// Resolves an instance level entry point. `F` must be the function pointer type of
+    /// `name`.
+    pub unsafe fn instance_proc<F: Copy>(
+        instance: sys::VkInstance,
+        name: &'static str,
+    ) -> Result<F, VulkanSurfaceError> {
+        assert_eq!(mem::size_of::<F>(), mem::size_of::<*const c_void>());
+        let get_instance_proc_addr = get_instance_proc_addr()?;
+        let c_name = CString::new(name).unwrap();
+        let proc = get_instance_proc_addr(instance, c_name.as_ptr());
+        if proc.is_null() {
+            return Err(VulkanSurfaceError::MissingEntryPoint(name));
+        }
+        Ok(mem::transmute_copy::<*const c_void, F>(&proc))
+    }
+}
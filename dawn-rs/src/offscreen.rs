@@ -0,0 +1,525 @@
+//! Headless swap chains that render into a ring of textures.
+//!
+//! An offscreen swap chain behaves like a normal `SwapChain`: render into
+//! `get_current_texture_view` and call `present`. Each presented image is copied into a
+//! readback buffer and handed to the frame callback, or queued for `take_frames` when no
+//! callback is set. Frames are delivered from `present` and `flush`, never from inside dawn.
+//!
+//! A format without a fixed texel size or an `image_count` of `0` is reported as a validation
+//! error on the device, like other invalid descriptors. Presenting such a swap chain reports
+//! the error again and delivers no frames.
+//!
+//! ```no_run
+//! # let device: dawn::Device = unimplemented!();
+//! use dawn::offscreen::{self, OffscreenSwapChainDescriptor};
+//! use dawn::{TextureFormat, TextureUsage};
+//!
+//! let swap_chain = offscreen::create_swap_chain(
+//!     &device,
+//!     &OffscreenSwapChainDescriptor {
+//!         label: Some("thumbnails"),
+//!         usage: TextureUsage::OUTPUT_ATTACHMENT,
+//!         format: TextureFormat::RGBA8Unorm,
+//!         width: 256,
+//!         height: 256,
+//!         image_count: 3,
+//!     },
+//! );
+//! swap_chain.set_frame_callback(|frame| println!("frame {}: {:?}", frame.index, frame.format));
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::ptr;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::capture::{self, PendingMapRead};
+use crate::{
+    convert, Buffer, BufferCopyView, BufferDescriptor, BufferUsage, CommandEncoderDescriptor,
+    Device, ErrorType, Extent3d, Origin3d, SwapChain, SwapChainInner, Texture, TextureAspect,
+    TextureCopyView, TextureDescriptor, TextureDimension, TextureFormat, TextureUsage, TextureView,
+    TextureViewDescriptor, TextureViewDimension,
+};
+
+#[derive(Debug, Copy, Clone)]
+pub struct OffscreenSwapChainDescriptor<'a> {
+    pub label: Option<&'a str>,
+    /// Usage of the images. `COPY_SRC` is always added for the readback.
+    pub usage: TextureUsage,
+    /// Must be a color format with a fixed texel size.
+    pub format: TextureFormat,
+    pub width: u32,
+    pub height: u32,
+    /// Number of images in the ring, at least `1`. Presenting waits for the readback of the
+    /// image that is about to be reused.
+    pub image_count: u32,
+}
+
+/// A presented image.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    /// Number of the `present` call that produced this frame, starting at `0`.
+    pub index: u64,
+    pub width: u32,
+    pub height: u32,
+    pub format: TextureFormat,
+    /// Tightly packed rows of texels, top to bottom.
+    pub data: Vec<u8>,
+}
+
+struct Image {
+    texture: Texture,
+    readback: Buffer,
+    /// The readback of the last frame presented from this image, until it is received.
+    pending: Option<Readback>,
+}
+
+pub(crate) struct Offscreen {
+    device: Device,
+    label: Option<String>,
+    usage: TextureUsage,
+    pub(crate) format: TextureFormat,
+    width: u32,
+    height: u32,
+    image_count: u32,
+    /// Why the descriptor or the last `configure` is invalid. Reported on every `present`.
+    error: Option<String>,
+    images: Vec<Image>,
+    current: usize,
+    presented: u64,
+    /// Indices into `images` in the order they were presented.
+    in_flight: VecDeque<usize>,
+    ready: VecDeque<Frame>,
+    callback: Option<Box<dyn FnMut(Frame) + Send>>,
+    /// Filled with a copy of the next presented frame. See `SwapChain::capture_next_frame`.
+    pub(crate) captures: Vec<Arc<Mutex<Option<Frame>>>>,
+}
+
+impl fmt::Debug for Offscreen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Offscreen")
+            .field("format", &self.format)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("image_count", &self.images.len())
+            .field("presented", &self.presented)
+            .finish()
+    }
+}
+
+struct Readback {
+    map: PendingMapRead,
+    captures: Vec<Arc<Mutex<Option<Frame>>>>,
+    index: u64,
+    width: u32,
+    height: u32,
+    format: TextureFormat,
+    bytes_per_row: usize,
+    row_size: usize,
+}
+
+/// Returns the row size and the padded row size of the readback, or `None` if the format
+/// can't be read back.
+fn bytes_per_row(format: TextureFormat, width: u32) -> Option<(u32, u32)> {
+    let row_size = convert::texel_size(format)? * width;
+    Some((row_size, convert::padded_bytes_per_row(row_size)))
+}
+
+fn validate(format: TextureFormat, image_count: u32) -> Result<(), String> {
+    if convert::texel_size(format).is_none() {
+        return Err(format!(
+            "unsupported offscreen swap chain format: {:?}",
+            format
+        ));
+    }
+    if image_count == 0 {
+        return Err("offscreen swap chain image_count must not be 0".to_string());
+    }
+    Ok(())
+}
+
+impl Offscreen {
+    /// Validates the configuration, reporting an error to the device, and recreates the images.
+    fn create_images(&mut self) {
+        self.error = validate(self.format, self.image_count).err();
+        if let Some(error) = &self.error {
+            self.device.inject_error(error, ErrorType::Validation);
+        }
+        // An invalid swap chain still hands out a texture to render into, without a readback.
+        let readback_size = bytes_per_row(self.format, self.width)
+            .map_or(0, |(_, bytes_per_row)| u64::from(bytes_per_row))
+            * u64::from(self.height);
+        let label = self.label.as_deref();
+        self.images = (0..self.image_count.max(1))
+            .map(|_| Image {
+                texture: self.device.create_texture(&TextureDescriptor {
+                    label,
+                    // The images are ordinary textures, which can't have `PRESENT` usage.
+                    usage: (self.usage - TextureUsage::PRESENT) | TextureUsage::COPY_SRC,
+                    dimension: TextureDimension::D2,
+                    size: Extent3d {
+                        width: self.width,
+                        height: self.height,
+                        depth: 1,
+                    },
+                    array_layer_count: 1,
+                    format: self.format,
+                    mip_level_count: 1,
+                    sample_count: 1,
+                }),
+                readback: self.device.create_buffer(&BufferDescriptor {
+                    label,
+                    usage: BufferUsage::MAP_READ | BufferUsage::COPY_DST,
+                    size: readback_size,
+                }),
+                pending: None,
+            })
+            .collect();
+        self.current = 0;
+    }
+
+    /// Queues the frame of the oldest readback in flight. Returns `false` if there is none
+    /// or it hasn't completed and `wait` is `false`.
+    fn receive(&mut self, wait: bool) -> bool {
+        let image = match self.in_flight.front() {
+            Some(&image) => image,
+            None => return false,
+        };
+        let map = &self.images[image].pending.as_ref().unwrap().map;
+        let mapped = if wait {
+            map.wait(&self.device)
+        } else {
+            match map.take() {
+                Some(mapped) => mapped,
+                None => return false,
+            }
+        };
+        self.in_flight.pop_front();
+        let readback = self.images[image].pending.take().unwrap();
+        if let Some(mapped) = mapped {
+            let mut data = Vec::with_capacity(readback.row_size * readback.height as usize);
+            for row in mapped
+                .chunks(readback.bytes_per_row)
+                .take(readback.height as usize)
+            {
+                data.extend_from_slice(&row[..readback.row_size]);
+            }
+            let frame = Frame {
+                index: readback.index,
+                width: readback.width,
+                height: readback.height,
+                format: readback.format,
+                data,
+            };
+            for capture in &readback.captures {
+                *capture.lock() = Some(frame.clone());
+            }
+            self.ready.push_back(frame);
+        }
+        true
+    }
+
+    /// Queues the frames whose readback has completed, without ticking the device.
+    fn receive_completed(&mut self) {
+        while self.receive(false) {}
+    }
+
+    /// Ticks the device until the readback of `image` has completed.
+    fn wait(&mut self, image: usize) {
+        while self.images[image].pending.is_some() {
+            self.receive(true);
+        }
+    }
+
+    fn wait_all(&mut self) {
+        while self.receive(true) {}
+    }
+
+    fn present(&mut self) {
+        let (row_size, bytes_per_row) = match (&self.error, bytes_per_row(self.format, self.width))
+        {
+            (None, Some(sizes)) => sizes,
+            (error, _) => {
+                let error = error.as_deref().unwrap_or("invalid offscreen swap chain");
+                self.device.inject_error(error, ErrorType::Validation);
+                return;
+            }
+        };
+        let image = &self.images[self.current];
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&CommandEncoderDescriptor { label: None });
+        encoder.copy_texture_to_buffer(
+            &TextureCopyView {
+                texture: &image.texture,
+                mip_level: 0,
+                array_layer: 0,
+                origin: Origin3d { x: 0, y: 0, z: 0 },
+            },
+            &BufferCopyView {
+                buffer: &image.readback,
+                offset: 0,
+                bytes_per_row,
+                rows_per_image: self.height,
+            },
+            &Extent3d {
+                width: self.width,
+                height: self.height,
+                depth: 1,
+            },
+        );
+        let commands = encoder.finish();
+        self.device.default_queue().submit(&[commands]);
+
+        let readback = Readback {
+            map: PendingMapRead::new(&self.device, &image.readback),
+            captures: std::mem::take(&mut self.captures),
+            index: self.presented,
+            width: self.width,
+            height: self.height,
+            format: self.format,
+            bytes_per_row: bytes_per_row as usize,
+            row_size: row_size as usize,
+        };
+        self.images[self.current].pending = Some(readback);
+        self.in_flight.push_back(self.current);
+
+        self.presented += 1;
+        self.current = (self.current + 1) % self.images.len();
+        self.wait(self.current);
+        self.receive_completed();
+    }
+}
+
+/// Creates a swap chain that isn't backed by a window.
+pub fn create_swap_chain(device: &Device, descriptor: &OffscreenSwapChainDescriptor) -> SwapChain {
+    let mut offscreen = Offscreen {
+        device: device.clone(),
+        label: descriptor.label.map(str::to_string),
+        usage: descriptor.usage,
+        format: descriptor.format,
+        width: descriptor.width,
+        height: descriptor.height,
+        image_count: descriptor.image_count,
+        error: None,
+        images: Vec::new(),
+        current: 0,
+        presented: 0,
+        in_flight: VecDeque::new(),
+        ready: VecDeque::new(),
+        callback: None,
+        captures: Vec::new(),
+    };
+    offscreen.create_images();
+    let backend_type = device.inner.lock().backend_type;
+    SwapChain {
+        inner: SwapChainInner {
+            raw: ptr::null_mut(),
+            device: device.clone(),
+        },
+        backend_type,
+        dawn_swap_chain_impl: None,
+        offscreen: Some(Box::new(Mutex::new(offscreen))),
+        preferred_format: Some(descriptor.format),
+        present_mode: None,
+        configured: AtomicBool::new(true),
+        capture: Mutex::default(),
+    }
+}
+
+pub(crate) fn present(offscreen: &Mutex<Offscreen>) {
+    offscreen.lock().present();
+    deliver_frames(offscreen);
+}
+
+pub(crate) fn current_texture_view(offscreen: &Mutex<Offscreen>) -> TextureView {
+    let offscreen = offscreen.lock();
+    offscreen.images[offscreen.current]
+        .texture
+        .create_view(&TextureViewDescriptor {
+            label: None,
+            format: offscreen.format,
+            dimension: TextureViewDimension::D2,
+            base_mip_level: 0,
+            mip_level_count: 1,
+            base_array_layer: 0,
+            array_layer_count: 1,
+            aspect: TextureAspect::All,
+        })
+}
+
+pub(crate) fn configure(
+    offscreen: &Mutex<Offscreen>,
+    format: TextureFormat,
+    allowed_usage: TextureUsage,
+    width: u32,
+    height: u32,
+) {
+    let mut offscreen = offscreen.lock();
+    offscreen.wait_all();
+    offscreen.format = format;
+    offscreen.usage = allowed_usage;
+    offscreen.width = width;
+    offscreen.height = height;
+    offscreen.create_images();
+}
+
+fn deliver_frames(offscreen: &Mutex<Offscreen>) {
+    let (frames, callback) = {
+        let mut offscreen = offscreen.lock();
+        if offscreen.callback.is_none() {
+            return;
+        }
+        let frames: Vec<Frame> = offscreen.ready.drain(..).collect();
+        (frames, offscreen.callback.take())
+    };
+    // The lock is released so that the callback may use the swap chain.
+    if let Some(mut callback) = callback {
+        for frame in frames {
+            callback(frame);
+        }
+        let mut offscreen = offscreen.lock();
+        if offscreen.callback.is_none() {
+            offscreen.callback = Some(callback);
+        }
+    }
+}
+
+impl SwapChain {
+    /// Sets the callback that receives presented frames of an offscreen swap chain. Frames
+    /// that were queued before the callback was set are delivered on the next `present` or
+    /// `flush`. Does nothing for swap chains that present to a surface.
+    pub fn set_frame_callback<F: FnMut(Frame) + Send + 'static>(&self, callback: F) {
+        if let Some(offscreen) = &self.offscreen {
+            offscreen.lock().callback = Some(Box::new(callback));
+        }
+    }
+
+    /// Removes and returns the frames that have been read back, oldest first. Frames are only
+    /// queued when no frame callback is set.
+    pub fn take_frames(&self) -> Vec<Frame> {
+        match &self.offscreen {
+            Some(offscreen) => {
+                let mut offscreen = offscreen.lock();
+                offscreen.receive_completed();
+                offscreen.ready.drain(..).collect()
+            }
+            None => Vec::new(),
+        }
+    }
+
+    /// Waits until every presented frame has been read back and delivered. For a window swap
+    /// chain, waits for the frames captured with `capture_next_frame`.
+    pub fn flush(&self) {
+        match &self.offscreen {
+            Some(offscreen) => {
+                offscreen.lock().wait_all();
+                deliver_frames(offscreen);
+            }
+            None => capture::flush(&self.capture, &self.inner.device),
+        }
+    }
+
+    /// Returns `true` if this swap chain renders into an offscreen ring of textures.
+    pub fn is_offscreen(&self) -> bool {
+        self.offscreen.is_some()
+    }
+}
+
+#[cfg(feature = "mock")]
+#[test]
+fn offscreen_frames_are_read_back_in_order() {
+    let _guard = crate::mock::install();
+    let device = crate::mock::create_device();
+    let swap_chain = create_swap_chain(
+        &device,
+        &OffscreenSwapChainDescriptor {
+            label: None,
+            usage: TextureUsage::OUTPUT_ATTACHMENT,
+            format: TextureFormat::RGBA8Unorm,
+            width: 4,
+            height: 2,
+            image_count: 2,
+        },
+    );
+    for _ in 0..3 {
+        drop(swap_chain.get_current_texture_view());
+        swap_chain.present();
+    }
+    swap_chain.flush();
+    let frames = swap_chain.take_frames();
+    assert_eq!(
+        vec![0, 1, 2],
+        frames.iter().map(|f| f.index).collect::<Vec<_>>()
+    );
+    assert!(frames.iter().all(|f| f.data.len() == 4 * 4 * 2));
+}
+
+#[cfg(feature = "mock")]
+#[test]
+fn invalid_offscreen_descriptors_are_validation_errors() {
+    use crate::mock::{self, Arg};
+
+    let _guard = crate::mock::install();
+    let device = crate::mock::create_device();
+    let injected_errors = || {
+        mock::take_calls()
+            .into_iter()
+            .filter(|call| call.name == "inject_error")
+            .map(|call| call.args[1].clone())
+            .collect::<Vec<_>>()
+    };
+    let descriptor = OffscreenSwapChainDescriptor {
+        label: None,
+        usage: TextureUsage::OUTPUT_ATTACHMENT,
+        format: TextureFormat::Depth24Plus,
+        width: 4,
+        height: 2,
+        image_count: 2,
+    };
+
+    let swap_chain = create_swap_chain(&device, &descriptor);
+    assert_eq!(
+        vec![Arg::Str(
+            "unsupported offscreen swap chain format: Depth24Plus".to_string()
+        )],
+        injected_errors()
+    );
+    drop(swap_chain.get_current_texture_view());
+    swap_chain.present();
+    swap_chain.flush();
+    assert_eq!(1, injected_errors().len());
+    assert!(swap_chain.take_frames().is_empty());
+
+    // Configuring a valid format makes it usable.
+    swap_chain.configure(
+        TextureFormat::RGBA8Unorm,
+        TextureUsage::OUTPUT_ATTACHMENT,
+        4,
+        2,
+    );
+    swap_chain.present();
+    swap_chain.flush();
+    assert!(injected_errors().is_empty());
+    assert_eq!(1, swap_chain.take_frames().len());
+
+    let swap_chain = create_swap_chain(
+        &device,
+        &OffscreenSwapChainDescriptor {
+            format: TextureFormat::RGBA8Unorm,
+            image_count: 0,
+            ..descriptor
+        },
+    );
+    assert_eq!(
+        vec![Arg::Str(
+            "offscreen swap chain image_count must not be 0".to_string()
+        )],
+        injected_errors()
+    );
+    drop(swap_chain);
+}
@@ -0,0 +1,758 @@
+//! Shader permutations.
+//!
+//! A shader source is written once with `#include`s and `#ifdef`s, and each
+//! `ShaderPermutation`, a set of flags that are `#define`d to `1`, selects a variant of it.
+//! `ShaderLibrary` preprocesses and compiles a permutation the first time it is requested and
+//! returns the same `ShaderModule` for later requests, so pipelines that use the same
+//! permutation share the module.
+//!
+//! The preprocessor doesn't depend on the shader language, so it also provides `#include` and
+//! `#define` for WGSL. It supports object-like `#define`s, `#undef`, `#include "file"`
+//! relative to the including file, `#include <file>` relative to the include directories,
+//! `#pragma once`, `#error` and conditionals with `defined`, integers, `!`, `&&` and `||`.
+//! Other directives such as `#version` are passed to the compiler.
+//!
+//! The compiler is a function from the preprocessed source to SPIR-V. `compile_glsl` and
+//! `compile_wgsl` are provided with the `glsl` and `wgsl` features, and map the lines in
+//! their errors back to the original files.
+//!
+//! ```no_run
+//! # #[cfg(feature = "glsl")]
+//! # fn run(device: &dawn::Device) -> Result<(), dawn::permutation::PermutationError> {
+//! use dawn::permutation::{compile_glsl, ShaderLibrary, ShaderPermutation};
+//!
+//! let mut library = ShaderLibrary::new(device, compile_glsl);
+//! library.add_include_dir("shaders/include");
+//! let permutation = ShaderPermutation::new().with("NORMAL_MAP").with("SHADOWS");
+//! let fragment_shader = library.get("shaders/lit.frag", &permutation)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{Device, ShaderModule};
+
+/// A set of flags that are defined to `1` when a shader is preprocessed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ShaderPermutation {
+    flags: BTreeSet<String>,
+}
+
+impl ShaderPermutation {
+    pub fn new() -> ShaderPermutation {
+        ShaderPermutation::default()
+    }
+
+    pub fn with(mut self, flag: &str) -> ShaderPermutation {
+        self.insert(flag);
+        self
+    }
+
+    pub fn insert(&mut self, flag: &str) {
+        self.flags.insert(flag.to_owned());
+    }
+
+    pub fn remove(&mut self, flag: &str) {
+        self.flags.remove(flag);
+    }
+
+    pub fn contains(&self, flag: &str) -> bool {
+        self.flags.contains(flag)
+    }
+
+    pub fn flags(&self) -> impl Iterator<Item = &str> {
+        self.flags.iter().map(|flag| flag.as_str())
+    }
+}
+
+impl fmt::Display for ShaderPermutation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, flag) in self.flags().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", flag)?;
+        }
+        write!(f, "]")
+    }
+}
+
+#[derive(Debug)]
+pub enum PermutationError {
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+    Preprocess {
+        path: PathBuf,
+        line: u32,
+        message: String,
+    },
+    Compile {
+        path: PathBuf,
+        permutation: ShaderPermutation,
+        message: String,
+    },
+    /// Dawn reported an error when creating the shader module.
+    ShaderModule {
+        path: PathBuf,
+        permutation: ShaderPermutation,
+        message: String,
+    },
+}
+
+impl fmt::Display for PermutationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PermutationError::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+            PermutationError::Preprocess {
+                path,
+                line,
+                message,
+            } => write!(f, "{}:{}: {}", path.display(), line, message),
+            PermutationError::Compile {
+                path,
+                permutation,
+                message,
+            }
+            | PermutationError::ShaderModule {
+                path,
+                permutation,
+                message,
+            } => write!(
+                f,
+                "failed to compile {} {}:\n{}",
+                path.display(),
+                permutation,
+                message
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PermutationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PermutationError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Preprocessed source code.
+#[derive(Debug, Clone)]
+pub struct Expanded {
+    /// The file that was preprocessed.
+    pub path: PathBuf,
+    pub code: String,
+    files: Vec<PathBuf>,
+    // The file index and line of each line of `code`.
+    lines: Vec<(usize, u32)>,
+}
+
+impl Expanded {
+    /// The file and 1-based line that the 1-based `line` of `code` came from.
+    pub fn location(&self, line: u32) -> Option<(&Path, u32)> {
+        let &(file, line) = self.lines.get((line as usize).checked_sub(1)?)?;
+        Some((&self.files[file], line))
+    }
+
+    /// The files that were read, starting with `path`.
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+}
+
+/// Preprocesses the file at `path` with the flags of `permutation` defined to `1`.
+pub fn preprocess(
+    path: &Path,
+    permutation: &ShaderPermutation,
+    include_dirs: &[PathBuf],
+) -> Result<Expanded, PermutationError> {
+    let mut preprocessor = Preprocessor {
+        include_dirs,
+        defines: permutation
+            .flags()
+            .map(|flag| (flag.to_owned(), "1".to_owned()))
+            .collect(),
+        once: HashSet::new(),
+        stack: Vec::new(),
+        expanded: Expanded {
+            path: path.to_owned(),
+            code: String::new(),
+            files: Vec::new(),
+            lines: Vec::new(),
+        },
+    };
+    preprocessor.process(path)?;
+    Ok(preprocessor.expanded)
+}
+
+struct Preprocessor<'a> {
+    include_dirs: &'a [PathBuf],
+    defines: HashMap<String, String>,
+    once: HashSet<PathBuf>,
+    stack: Vec<PathBuf>,
+    expanded: Expanded,
+}
+
+struct Conditional {
+    // Whether lines are kept.
+    active: bool,
+    // Whether a branch of this conditional has been taken.
+    taken: bool,
+    // Whether the enclosing block is active.
+    parent: bool,
+    has_else: bool,
+}
+
+impl<'a> Preprocessor<'a> {
+    fn process(&mut self, path: &Path) -> Result<(), PermutationError> {
+        let source = fs::read_to_string(path).map_err(|error| PermutationError::Io {
+            path: path.to_owned(),
+            error,
+        })?;
+        let canonical = fs::canonicalize(path).unwrap_or_else(|_| path.to_owned());
+        if self.once.contains(&canonical) {
+            return Ok(());
+        }
+        let file = match self.expanded.files.iter().position(|file| file == path) {
+            Some(file) => file,
+            None => {
+                self.expanded.files.push(path.to_owned());
+                self.expanded.files.len() - 1
+            }
+        };
+        self.stack.push(canonical.clone());
+
+        let mut conditionals: Vec<Conditional> = Vec::new();
+        let mut line_number = 0;
+        for line in source.lines() {
+            line_number += 1;
+            let error = |message: String| PermutationError::Preprocess {
+                path: path.to_owned(),
+                line: line_number,
+                message,
+            };
+            let active = conditionals.last().map(|c| c.active).unwrap_or(true);
+            let directive = match line.trim_start().strip_prefix('#') {
+                Some(directive) => directive.trim(),
+                None => {
+                    if active {
+                        let line = substitute(line, &self.defines, &mut Vec::new());
+                        self.expanded.code.push_str(&line);
+                        self.expanded.code.push('\n');
+                        self.expanded.lines.push((file, line_number));
+                    }
+                    continue;
+                }
+            };
+            let (name, rest) = match directive.find(char::is_whitespace) {
+                Some(end) => (&directive[..end], directive[end..].trim()),
+                None => (directive, ""),
+            };
+            match name {
+                "if" | "ifdef" | "ifndef" => {
+                    let value = match (active, name) {
+                        (false, _) => false,
+                        (true, "ifdef") => self.defines.contains_key(rest),
+                        (true, "ifndef") => !self.defines.contains_key(rest),
+                        (true, _) => self.evaluate(rest).map_err(error)?,
+                    };
+                    conditionals.push(Conditional {
+                        active: value,
+                        taken: value,
+                        parent: active,
+                        has_else: false,
+                    });
+                }
+                "elif" | "else" => {
+                    let conditional = match conditionals.last() {
+                        Some(conditional) if !conditional.has_else => conditional,
+                        _ => return Err(error(format!("#{} without #if", name))),
+                    };
+                    let (parent, taken) = (conditional.parent, conditional.taken);
+                    let value = match name {
+                        "elif" => parent && !taken && self.evaluate(rest).map_err(error)?,
+                        _ => parent && !taken,
+                    };
+                    let conditional = conditionals.last_mut().unwrap();
+                    conditional.active = value;
+                    conditional.taken |= value;
+                    conditional.has_else = name == "else";
+                }
+                "endif" => {
+                    if conditionals.pop().is_none() {
+                        return Err(error("#endif without #if".to_owned()));
+                    }
+                }
+                _ if !active => {}
+                "define" => {
+                    let end = rest
+                        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                        .unwrap_or(rest.len());
+                    if end == 0 || rest[end..].starts_with('(') {
+                        return Err(error("only object-like #defines are supported".to_owned()));
+                    }
+                    let value = rest[end..].trim().to_owned();
+                    self.defines.insert(rest[..end].to_owned(), value);
+                }
+                "undef" => {
+                    self.defines.remove(rest);
+                }
+                "include" => {
+                    let include = self.resolve_include(path, rest).map_err(error)?;
+                    let canonical = fs::canonicalize(&include).unwrap_or_else(|_| include.clone());
+                    if self.stack.contains(&canonical) {
+                        let message = format!("{} includes itself", include.display());
+                        return Err(error(message));
+                    }
+                    self.process(&include)?;
+                }
+                "pragma" if rest == "once" => {
+                    self.once.insert(canonical.clone());
+                }
+                "error" => return Err(error(rest.to_owned())),
+                _ => {
+                    self.expanded.code.push_str(line);
+                    self.expanded.code.push('\n');
+                    self.expanded.lines.push((file, line_number));
+                }
+            }
+        }
+        if !conditionals.is_empty() {
+            return Err(PermutationError::Preprocess {
+                path: path.to_owned(),
+                line: line_number,
+                message: "unterminated #if".to_owned(),
+            });
+        }
+        self.stack.pop();
+        Ok(())
+    }
+
+    fn resolve_include(&self, path: &Path, name: &str) -> Result<PathBuf, String> {
+        if name.len() >= 2 && name.starts_with('"') && name.ends_with('"') {
+            let dir = path.parent().unwrap_or_else(|| Path::new(""));
+            return Ok(dir.join(&name[1..name.len() - 1]));
+        }
+        if name.len() >= 2 && name.starts_with('<') && name.ends_with('>') {
+            let name = &name[1..name.len() - 1];
+            return self
+                .include_dirs
+                .iter()
+                .map(|dir| dir.join(name))
+                .find(|path| path.is_file())
+                .ok_or_else(|| format!("{} isn't in the include directories", name));
+        }
+        Err(format!("invalid #include {}", name))
+    }
+
+    fn evaluate(&self, expression: &str) -> Result<bool, String> {
+        let tokens = tokenize(expression)?;
+        let mut parser = Parser {
+            tokens: &tokens,
+            defines: &self.defines,
+        };
+        let value = parser.or()?;
+        if !parser.tokens.is_empty() {
+            return Err(format!("unexpected tokens in #if {}", expression));
+        }
+        Ok(value != 0)
+    }
+}
+
+// Replaces the defined identifiers of `line` with their values. `expanding` holds the macros
+// being expanded, which aren't replaced again.
+fn substitute(
+    line: &str,
+    defines: &HashMap<String, String>,
+    expanding: &mut Vec<String>,
+) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(start) = rest.find(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        // Skip numbers such as `1e5` and suffixes such as `1u`.
+        let number = rest[..start]
+            .chars()
+            .last()
+            .map(|c| c.is_ascii_alphanumeric() || c == '.')
+            == Some(true);
+        let end = rest[start..]
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .map_or(rest.len(), |end| start + end);
+        let identifier = &rest[start..end];
+        result.push_str(&rest[..start]);
+        match defines.get(identifier) {
+            Some(value) if !number && !expanding.iter().any(|name| name == identifier) => {
+                expanding.push(identifier.to_owned());
+                result.push_str(&substitute(value, defines, expanding));
+                expanding.pop();
+            }
+            _ => result.push_str(identifier),
+        }
+        rest = &rest[end..];
+    }
+    result.push_str(rest);
+    result
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Identifier(String),
+    Number(i64),
+    Not,
+    And,
+    Or,
+    Open,
+    Close,
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut rest = expression.trim_start();
+    while let Some(c) = rest.chars().next() {
+        let (token, len) = match c {
+            '!' => (Token::Not, 1),
+            '(' => (Token::Open, 1),
+            ')' => (Token::Close, 1),
+            '&' if rest.starts_with("&&") => (Token::And, 2),
+            '|' if rest.starts_with("||") => (Token::Or, 2),
+            c if c.is_ascii_alphanumeric() || c == '_' => {
+                let len = rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(rest.len());
+                let word = &rest[..len];
+                let token = match word.parse() {
+                    Ok(number) => Token::Number(number),
+                    Err(_) if c.is_ascii_digit() => return Err(format!("invalid number {}", word)),
+                    Err(_) => Token::Identifier(word.to_owned()),
+                };
+                (token, len)
+            }
+            c => return Err(format!("unsupported operator {:?} in #if", c)),
+        };
+        tokens.push(token);
+        rest = rest[len..].trim_start();
+    }
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    defines: &'a HashMap<String, String>,
+}
+
+impl<'a> Parser<'a> {
+    fn next(&mut self) -> Option<&'a Token> {
+        let (token, rest) = self.tokens.split_first()?;
+        self.tokens = rest;
+        Some(token)
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.tokens.first() == Some(token) {
+            self.tokens = &self.tokens[1..];
+            return true;
+        }
+        false
+    }
+
+    fn or(&mut self) -> Result<i64, String> {
+        let mut value = self.and()?;
+        while self.eat(&Token::Or) {
+            let right = self.and()?;
+            value = (value != 0 || right != 0) as i64;
+        }
+        Ok(value)
+    }
+
+    fn and(&mut self) -> Result<i64, String> {
+        let mut value = self.unary()?;
+        while self.eat(&Token::And) {
+            let right = self.unary()?;
+            value = (value != 0 && right != 0) as i64;
+        }
+        Ok(value)
+    }
+
+    fn unary(&mut self) -> Result<i64, String> {
+        match self.next() {
+            Some(Token::Not) => Ok((self.unary()? == 0) as i64),
+            Some(Token::Number(number)) => Ok(*number),
+            Some(Token::Open) => {
+                let value = self.or()?;
+                if !self.eat(&Token::Close) {
+                    return Err("missing ) in #if".to_owned());
+                }
+                Ok(value)
+            }
+            Some(Token::Identifier(name)) if name == "defined" => {
+                let parenthesized = self.eat(&Token::Open);
+                let name = match self.next() {
+                    Some(Token::Identifier(name)) => name,
+                    _ => return Err("expected a name after defined".to_owned()),
+                };
+                if parenthesized && !self.eat(&Token::Close) {
+                    return Err("missing ) in #if".to_owned());
+                }
+                Ok(self.defines.contains_key(name) as i64)
+            }
+            // Like in C, undefined names are 0.
+            Some(Token::Identifier(name)) => match self.defines.get(name) {
+                Some(value) => value
+                    .parse()
+                    .map_err(|_| format!("{} isn't an integer in #if", name)),
+                None => Ok(0),
+            },
+            _ => Err("expected a value in #if".to_owned()),
+        }
+    }
+}
+
+type Compile = Box<dyn Fn(&Expanded) -> Result<Vec<u32>, String>>;
+
+/// Compiles shader permutations on first use and keeps their modules.
+pub struct ShaderLibrary {
+    device: Device,
+    include_dirs: Vec<PathBuf>,
+    compile: Compile,
+    modules: HashMap<(PathBuf, ShaderPermutation), ShaderModule>,
+}
+
+impl ShaderLibrary {
+    /// Creates a library that compiles preprocessed sources with `compile`.
+    pub fn new<F>(device: &Device, compile: F) -> ShaderLibrary
+    where
+        F: Fn(&Expanded) -> Result<Vec<u32>, String> + 'static,
+    {
+        ShaderLibrary {
+            device: device.clone(),
+            include_dirs: Vec::new(),
+            compile: Box::new(compile),
+            modules: HashMap::new(),
+        }
+    }
+
+    /// Adds a directory to search for `#include <file>`.
+    pub fn add_include_dir<P: AsRef<Path>>(&mut self, dir: P) {
+        self.include_dirs.push(dir.as_ref().to_owned());
+    }
+
+    /// Returns the module of the permutation of the shader at `path`, compiling it if it
+    /// hasn't been requested before.
+    pub fn get<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        permutation: &ShaderPermutation,
+    ) -> Result<ShaderModule, PermutationError> {
+        let key = (path.as_ref().to_owned(), permutation.clone());
+        if let Some(module) = self.modules.get(&key) {
+            return Ok(module.clone());
+        }
+        let path = path.as_ref();
+        let expanded = preprocess(path, permutation, &self.include_dirs)?;
+        let code = (self.compile)(&expanded).map_err(|message| PermutationError::Compile {
+            path: path.to_owned(),
+            permutation: permutation.clone(),
+            message,
+        })?;
+        let device = &self.device;
+        let module = crate::hot_reload::check_errors(device, || {
+            device.create_shader_module_with_code(&code)
+        })
+        .map_err(|message| PermutationError::ShaderModule {
+            path: path.to_owned(),
+            permutation: permutation.clone(),
+            message,
+        })?;
+        self.modules.insert(key, module.clone());
+        Ok(module)
+    }
+
+    /// The number of compiled permutations.
+    pub fn len(&self) -> usize {
+        self.modules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+
+    /// Drops the compiled modules, for example after the sources have changed.
+    pub fn clear(&mut self) {
+        self.modules.clear();
+    }
+}
+
+/// Compiles preprocessed GLSL. The stage is taken from the extension of the path: `.vert`,
+/// `.frag` or `.comp`, optionally followed by `.glsl`.
+#[cfg(feature = "glsl")]
+pub fn compile_glsl(expanded: &Expanded) -> Result<Vec<u32>, String> {
+    use crate::glsl::{self, GlslError, GlslSource};
+    use crate::ShaderStage;
+
+    let extension = |path: &Path| {
+        path.extension()
+            .and_then(|ext| ext.to_str().map(str::to_owned))
+    };
+    let mut language = extension(&expanded.path);
+    if language.as_deref() == Some("glsl") {
+        language = expanded
+            .path
+            .file_stem()
+            .and_then(|stem| extension(Path::new(stem)));
+    }
+    let stage = match language.as_deref() {
+        Some("vert") => ShaderStage::VERTEX,
+        Some("frag") => ShaderStage::FRAGMENT,
+        Some("comp") => ShaderStage::COMPUTE,
+        _ => return Err("the stage isn't known from the file extension".to_owned()),
+    };
+    let name = expanded.path.to_string_lossy();
+    let source = GlslSource::Code {
+        name: &name,
+        code: &expanded.code,
+    };
+    match glsl::compile(source, stage, &[]) {
+        Ok(code) => Ok(code),
+        Err(GlslError::Compile(mut diagnostics)) => {
+            for diagnostic in &mut diagnostics {
+                let location = match (&diagnostic.file, diagnostic.line) {
+                    (Some(file), Some(line)) if *file == name => expanded.location(line),
+                    _ => None,
+                };
+                if let Some((file, line)) = location {
+                    diagnostic.file = Some(file.to_string_lossy().into_owned());
+                    diagnostic.line = Some(line);
+                }
+            }
+            Err(GlslError::Compile(diagnostics).to_string())
+        }
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+/// Translates preprocessed WGSL to SPIR-V.
+#[cfg(feature = "wgsl")]
+pub fn compile_wgsl(expanded: &Expanded) -> Result<Vec<u32>, String> {
+    use crate::wgsl::{self, WgslError};
+    use std::fmt::Write;
+
+    let err = match wgsl::translate(&expanded.code) {
+        Ok(code) => return Ok(code),
+        Err(err) => err,
+    };
+    // The line and column are mapped back to the files of the labels.
+    let message = match &err {
+        WgslError::Parse { message, .. }
+        | WgslError::Validation { message, .. }
+        | WgslError::Translation(message) => message,
+    };
+    let mut text = message.clone();
+    for label in err.labels() {
+        match expanded.location(label.span.line) {
+            Some((file, line)) => write!(
+                text,
+                "\n  {}:{}:{}: {}",
+                file.display(),
+                line,
+                label.span.column,
+                label.message
+            ),
+            None => write!(text, "\n  {}", label.message),
+        }
+        .unwrap();
+    }
+    Err(text)
+}
+
+#[test]
+fn preprocessor_expands_includes_and_conditionals() {
+    let dir = std::env::temp_dir().join(format!("dawn-permutation-{}", std::process::id()));
+    fs::create_dir_all(dir.join("include")).unwrap();
+    fs::write(
+        dir.join("include/common.inc"),
+        "#pragma once\n#define SCALE 2.0\nconst float scale = SCALE;\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("shader.frag"),
+        "#version 450\n\
+         #include <common.inc>\n\
+         #include \"include/common.inc\"\n\
+         #if defined(SHADOWS) && !defined(UNLIT)\n\
+         float shadow = SCALE * 0.5;\n\
+         #elif LIGHTS\n\
+         float lights = 1.0;\n\
+         #else\n\
+         float shadow = 1.0;\n\
+         #endif\n",
+    )
+    .unwrap();
+    let include_dirs = [dir.join("include")];
+    let path = dir.join("shader.frag");
+
+    let expanded = preprocess(&path, &ShaderPermutation::new(), &include_dirs).unwrap();
+    assert_eq!(
+        "#version 450\nconst float scale = 2.0;\nfloat shadow = 1.0;\n",
+        expanded.code
+    );
+    assert_eq!(
+        Some((&*dir.join("include/common.inc"), 3)),
+        expanded.location(2)
+    );
+    assert_eq!(Some((&*path, 9)), expanded.location(3));
+
+    let shadows = ShaderPermutation::new().with("SHADOWS");
+    let expanded = preprocess(&path, &shadows, &include_dirs).unwrap();
+    assert!(expanded.code.ends_with("float shadow = 2.0 * 0.5;\n"));
+    let lights = ShaderPermutation::new().with("LIGHTS").with("UNLIT");
+    let expanded = preprocess(&path, &lights, &include_dirs).unwrap();
+    assert!(expanded.code.ends_with("float lights = 1.0;\n"));
+
+    fs::write(dir.join("cycle.frag"), "#include \"cycle.frag\"\n").unwrap();
+    match preprocess(&dir.join("cycle.frag"), &shadows, &include_dirs) {
+        Err(PermutationError::Preprocess { line: 1, .. }) => {}
+        other => panic!("{:?}", other),
+    }
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(feature = "mock")]
+#[test]
+fn library_shares_modules_per_permutation() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let _mock = crate::mock::install();
+    let device = crate::mock::create_device();
+    let dir = std::env::temp_dir().join(format!("dawn-library-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("shader.vert");
+    fs::write(&path, "#version 450\nvoid main() {}\n").unwrap();
+
+    let compiled = Rc::new(Cell::new(0));
+    let counter = compiled.clone();
+    let mut library = ShaderLibrary::new(&device, move |_| {
+        counter.set(counter.get() + 1);
+        Ok(crate::example_shaders::TRIANGLE_VERT.spirv.to_vec())
+    });
+    let shadows = ShaderPermutation::new().with("SHADOWS");
+    let first = library.get(&path, &shadows).unwrap();
+    let second = library.get(&path, &shadows).unwrap();
+    assert_eq!(first.raw, second.raw);
+    let other = library.get(&path, &ShaderPermutation::new()).unwrap();
+    assert_ne!(first.raw, other.raw);
+    assert_eq!(2, compiled.get());
+    assert_eq!(2, library.len());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,637 @@
+//! Checks pipelines against the SPIR-V of their shaders.
+//!
+//! Dawn reports mismatches between a pipeline descriptor and its shaders with messages that
+//! don't say which attribute or binding is wrong. With `Device::set_pipeline_validation`
+//! enabled, shader modules keep the reflection of their code and layouts keep their entries,
+//! and `create_render_pipeline` and `create_compute_pipeline` check:
+//!
+//! * that the entry points exist and are of the right stage,
+//! * that every vertex input has an attribute with a matching `shader_location` and format,
+//! * that every fragment output has a color state with a matching format,
+//! * that every binding used by a stage is in the pipeline layout, is visible to the stage
+//!   and has a matching type.
+//!
+//! The first mismatch is reported as a validation error on the device before the pipeline is
+//! created. The checks can also be run directly with `validate_render_pipeline` and
+//! `validate_compute_pipeline`. Parts of a pipeline that were created while validation was
+//! disabled are skipped.
+
+use std::fmt;
+
+use crate::reflect::{Binding, EntryPoint, ReflectError, ScalarType, ShaderReflection};
+use crate::{
+    BindGroupLayoutEntry, BindingType, ComputePipelineDescriptor, PipelineLayout,
+    ProgrammableStageDescriptor, RenderPipelineDescriptor, ShaderStage, TextureFormat,
+    VertexFormat,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PipelineValidationError {
+    /// The SPIR-V of the stage couldn't be parsed.
+    Reflection {
+        stage: ShaderStage,
+        error: ReflectError,
+    },
+    EntryPointNotFound {
+        stage: ShaderStage,
+        entry_point: String,
+        available: Vec<String>,
+    },
+    /// The entry point exists but is of a different stage.
+    WrongStage {
+        stage: ShaderStage,
+        entry_point: String,
+        actual: ShaderStage,
+    },
+    /// A vertex shader input has no attribute in the vertex state.
+    MissingVertexAttribute {
+        location: u32,
+        name: Option<String>,
+        shader_type: ScalarType,
+    },
+    /// The vertex format doesn't produce the scalar type of the vertex shader input.
+    VertexFormatMismatch {
+        location: u32,
+        name: Option<String>,
+        format: VertexFormat,
+        shader_type: ScalarType,
+    },
+    /// A fragment shader output has no color state.
+    MissingColorState { location: u32, name: Option<String> },
+    /// The color format doesn't store the scalar type of the fragment shader output.
+    ColorFormatMismatch {
+        location: u32,
+        name: Option<String>,
+        format: TextureFormat,
+        shader_type: ScalarType,
+    },
+    /// A binding used by the stage isn't in the pipeline layout.
+    MissingBinding {
+        stage: ShaderStage,
+        set: u32,
+        binding: u32,
+        name: Option<String>,
+        shader_type: BindingType,
+    },
+    /// The binding is in the pipeline layout, but not visible to the stage.
+    BindingNotVisible {
+        stage: ShaderStage,
+        set: u32,
+        binding: u32,
+        name: Option<String>,
+        visibility: ShaderStage,
+    },
+    BindingTypeMismatch {
+        stage: ShaderStage,
+        set: u32,
+        binding: u32,
+        name: Option<String>,
+        shader_type: BindingType,
+        layout_type: BindingType,
+    },
+}
+
+impl fmt::Display for PipelineValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PipelineValidationError::Reflection { stage, error } => {
+                write!(f, "{} stage: {}", stage_name(*stage), error)
+            }
+            PipelineValidationError::EntryPointNotFound {
+                stage,
+                entry_point,
+                available,
+            } => write!(
+                f,
+                "{} stage: entry point {:?} not found, the module has {:?}",
+                stage_name(*stage),
+                entry_point,
+                available
+            ),
+            PipelineValidationError::WrongStage {
+                stage,
+                entry_point,
+                actual,
+            } => write!(
+                f,
+                "{} stage: entry point {:?} is a {} shader",
+                stage_name(*stage),
+                entry_point,
+                stage_name(*actual)
+            ),
+            PipelineValidationError::MissingVertexAttribute {
+                location,
+                name,
+                shader_type,
+            } => write!(
+                f,
+                "vertex stage: input {}at location {} ({:?}) has no vertex attribute with \
+                 shader_location {}",
+                display_name(name),
+                location,
+                shader_type,
+                location
+            ),
+            PipelineValidationError::VertexFormatMismatch {
+                location,
+                name,
+                format,
+                shader_type,
+            } => write!(
+                f,
+                "vertex stage: input {}at location {} is {:?}, but its attribute has format {:?}",
+                display_name(name),
+                location,
+                shader_type,
+                format
+            ),
+            PipelineValidationError::MissingColorState { location, name } => write!(
+                f,
+                "fragment stage: output {}at location {} has no color state",
+                display_name(name),
+                location
+            ),
+            PipelineValidationError::ColorFormatMismatch {
+                location,
+                name,
+                format,
+                shader_type,
+            } => write!(
+                f,
+                "fragment stage: output {}at location {} is {:?}, but color state {} has \
+                 format {:?}",
+                display_name(name),
+                location,
+                shader_type,
+                location,
+                format
+            ),
+            PipelineValidationError::MissingBinding {
+                stage,
+                set,
+                binding,
+                name,
+                shader_type,
+            } => write!(
+                f,
+                "{} stage: {}set {} binding {} ({:?}) is not in the pipeline layout",
+                stage_name(*stage),
+                display_name(name),
+                set,
+                binding,
+                shader_type
+            ),
+            PipelineValidationError::BindingNotVisible {
+                stage,
+                set,
+                binding,
+                name,
+                visibility,
+            } => write!(
+                f,
+                "{} stage: {}set {} binding {} is only visible to {:?}",
+                stage_name(*stage),
+                display_name(name),
+                set,
+                binding,
+                visibility
+            ),
+            PipelineValidationError::BindingTypeMismatch {
+                stage,
+                set,
+                binding,
+                name,
+                shader_type,
+                layout_type,
+            } => write!(
+                f,
+                "{} stage: {}set {} binding {} is {:?} in the shader, but {:?} in the layout",
+                stage_name(*stage),
+                display_name(name),
+                set,
+                binding,
+                shader_type,
+                layout_type
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PipelineValidationError {}
+
+fn stage_name(stage: ShaderStage) -> &'static str {
+    match stage {
+        ShaderStage::VERTEX => "vertex",
+        ShaderStage::FRAGMENT => "fragment",
+        ShaderStage::COMPUTE => "compute",
+        _ => "unknown",
+    }
+}
+
+// `Some("a_Color")` -> "`a_Color` ", so that unnamed variables read naturally.
+fn display_name(name: &Option<String>) -> String {
+    match name {
+        Some(name) if !name.is_empty() => format!("`{}` ", name),
+        _ => String::new(),
+    }
+}
+
+pub fn validate_render_pipeline(
+    descriptor: &RenderPipelineDescriptor,
+) -> Result<(), PipelineValidationError> {
+    let vertex = stage_entry_point(ShaderStage::VERTEX, &descriptor.vertex_stage)?;
+    if let Some(vertex) = vertex {
+        validate_vertex_inputs(vertex, descriptor)?;
+        validate_bindings(vertex, descriptor.layout)?;
+    }
+    if let Some(fragment_stage) = &descriptor.fragment_stage {
+        let fragment = stage_entry_point(ShaderStage::FRAGMENT, fragment_stage)?;
+        if let Some(fragment) = fragment {
+            validate_fragment_outputs(fragment, descriptor)?;
+            validate_bindings(fragment, descriptor.layout)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn validate_compute_pipeline(
+    descriptor: &ComputePipelineDescriptor,
+) -> Result<(), PipelineValidationError> {
+    let compute = stage_entry_point(ShaderStage::COMPUTE, &descriptor.compute_stage)?;
+    if let Some(compute) = compute {
+        validate_bindings(compute, descriptor.layout)?;
+    }
+    Ok(())
+}
+
+/// Returns `None` if the module was created without pipeline validation.
+fn stage_entry_point<'a>(
+    stage: ShaderStage,
+    descriptor: &'a ProgrammableStageDescriptor,
+) -> Result<Option<&'a EntryPoint>, PipelineValidationError> {
+    let reflection: &ShaderReflection = match descriptor.module.reflection.as_deref() {
+        Some(Ok(reflection)) => reflection,
+        Some(Err(error)) => {
+            return Err(PipelineValidationError::Reflection {
+                stage,
+                error: error.clone(),
+            })
+        }
+        None => return Ok(None),
+    };
+    let entry_point = reflection
+        .entry_point(descriptor.entry_point)
+        .ok_or_else(|| PipelineValidationError::EntryPointNotFound {
+            stage,
+            entry_point: descriptor.entry_point.to_string(),
+            available: reflection
+                .entry_points()
+                .iter()
+                .map(|entry_point| entry_point.name.clone())
+                .collect(),
+        })?;
+    if entry_point.stage != stage {
+        return Err(PipelineValidationError::WrongStage {
+            stage,
+            entry_point: entry_point.name.clone(),
+            actual: entry_point.stage,
+        });
+    }
+    Ok(Some(entry_point))
+}
+
+fn validate_vertex_inputs(
+    entry_point: &EntryPoint,
+    descriptor: &RenderPipelineDescriptor,
+) -> Result<(), PipelineValidationError> {
+    for input in &entry_point.inputs {
+        for location in input.location..input.location + input.ty.columns {
+            let attribute = descriptor
+                .vertex_state
+                .vertex_buffers
+                .iter()
+                .flat_map(|buffer| buffer.attributes.iter())
+                .find(|attribute| attribute.shader_location == location);
+            let attribute = match attribute {
+                Some(attribute) => attribute,
+                None => {
+                    return Err(PipelineValidationError::MissingVertexAttribute {
+                        location,
+                        name: input.name.clone(),
+                        shader_type: input.ty.scalar,
+                    })
+                }
+            };
+            if vertex_format_scalar_type(attribute.format) != input.ty.scalar {
+                return Err(PipelineValidationError::VertexFormatMismatch {
+                    location,
+                    name: input.name.clone(),
+                    format: attribute.format,
+                    shader_type: input.ty.scalar,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+fn validate_fragment_outputs(
+    entry_point: &EntryPoint,
+    descriptor: &RenderPipelineDescriptor,
+) -> Result<(), PipelineValidationError> {
+    for output in &entry_point.outputs {
+        let color_state = match descriptor.color_states.get(output.location as usize) {
+            Some(color_state) => color_state,
+            None => {
+                return Err(PipelineValidationError::MissingColorState {
+                    location: output.location,
+                    name: output.name.clone(),
+                })
+            }
+        };
+        match texture_format_scalar_type(color_state.format) {
+            Some(scalar) if scalar != output.ty.scalar => {
+                return Err(PipelineValidationError::ColorFormatMismatch {
+                    location: output.location,
+                    name: output.name.clone(),
+                    format: color_state.format,
+                    shader_type: output.ty.scalar,
+                })
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn validate_bindings(
+    entry_point: &EntryPoint,
+    layout: Option<&PipelineLayout>,
+) -> Result<(), PipelineValidationError> {
+    // Layouts derived from the shaders match them.
+    let bind_group_layouts = match layout.and_then(|layout| layout.bind_group_layouts.as_ref()) {
+        Some(bind_group_layouts) => bind_group_layouts,
+        None => return Ok(()),
+    };
+    for binding in &entry_point.bindings {
+        let entry = bind_group_layouts
+            .get(binding.set as usize)
+            .and_then(|entries| entries.iter().find(|e| e.binding == binding.binding));
+        let entry = match entry {
+            Some(entry) => entry,
+            None => {
+                return Err(PipelineValidationError::MissingBinding {
+                    stage: entry_point.stage,
+                    set: binding.set,
+                    binding: binding.binding,
+                    name: binding.name.clone(),
+                    shader_type: binding.ty,
+                })
+            }
+        };
+        validate_binding(entry_point.stage, binding, entry)?;
+    }
+    Ok(())
+}
+
+fn validate_binding(
+    stage: ShaderStage,
+    binding: &Binding,
+    entry: &BindGroupLayoutEntry,
+) -> Result<(), PipelineValidationError> {
+    if !entry.visibility.contains(stage) {
+        return Err(PipelineValidationError::BindingNotVisible {
+            stage,
+            set: binding.set,
+            binding: binding.binding,
+            name: binding.name.clone(),
+            visibility: entry.visibility,
+        });
+    }
+    if !binding_types_compatible(entry.ty, binding.ty) {
+        return Err(PipelineValidationError::BindingTypeMismatch {
+            stage,
+            set: binding.set,
+            binding: binding.binding,
+            name: binding.name.clone(),
+            shader_type: binding.ty,
+            layout_type: entry.ty,
+        });
+    }
+    Ok(())
+}
+
+/// Dynamic offsets and comparison samplers can't be seen in SPIR-V, and a writable storage
+/// buffer in the layout may be read only in the shader.
+fn binding_types_compatible(layout: BindingType, shader: BindingType) -> bool {
+    match (layout, shader) {
+        (BindingType::UniformBuffer { .. }, BindingType::UniformBuffer { .. }) => true,
+        (
+            BindingType::StorageBuffer { readonly, .. },
+            BindingType::StorageBuffer {
+                readonly: shader_readonly,
+                ..
+            },
+        ) => !readonly || shader_readonly,
+        (BindingType::Sampler { .. }, BindingType::Sampler { .. }) => true,
+        (layout @ BindingType::SampledTexture { .. }, shader) => layout == shader,
+        (layout @ BindingType::StorageTexture { .. }, shader) => layout == shader,
+        _ => false,
+    }
+}
+
+fn vertex_format_scalar_type(format: VertexFormat) -> ScalarType {
+    match format {
+        VertexFormat::UChar2
+        | VertexFormat::UChar4
+        | VertexFormat::UShort2
+        | VertexFormat::UShort4
+        | VertexFormat::UInt
+        | VertexFormat::UInt2
+        | VertexFormat::UInt3
+        | VertexFormat::UInt4 => ScalarType::Uint,
+        VertexFormat::Char2
+        | VertexFormat::Char4
+        | VertexFormat::Short2
+        | VertexFormat::Short4
+        | VertexFormat::Int
+        | VertexFormat::Int2
+        | VertexFormat::Int3
+        | VertexFormat::Int4 => ScalarType::Sint,
+        _ => ScalarType::Float,
+    }
+}
+
+/// The scalar type that shaders write to a color attachment of `format`, `None` for depth
+/// formats.
+fn texture_format_scalar_type(format: TextureFormat) -> Option<ScalarType> {
+    let scalar = match format {
+        TextureFormat::R8Uint
+        | TextureFormat::R16Uint
+        | TextureFormat::RG8Uint
+        | TextureFormat::R32Uint
+        | TextureFormat::RG16Uint
+        | TextureFormat::RGBA8Uint
+        | TextureFormat::RG32Uint
+        | TextureFormat::RGBA16Uint
+        | TextureFormat::RGBA32Uint => ScalarType::Uint,
+        TextureFormat::R8Sint
+        | TextureFormat::R16Sint
+        | TextureFormat::RG8Sint
+        | TextureFormat::R32Sint
+        | TextureFormat::RG16Sint
+        | TextureFormat::RGBA8Sint
+        | TextureFormat::RG32Sint
+        | TextureFormat::RGBA16Sint
+        | TextureFormat::RGBA32Sint => ScalarType::Sint,
+        TextureFormat::Depth32Float
+        | TextureFormat::Depth24Plus
+        | TextureFormat::Depth24PlusStencil8 => return None,
+        _ => ScalarType::Float,
+    };
+    Some(scalar)
+}
+
+#[cfg(feature = "mock")]
+#[test]
+fn render_pipeline_mismatches_are_reported() {
+    use crate::{
+        BindGroupLayoutDescriptor, BlendDescriptor, BlendFactor, BlendOperation,
+        ColorStateDescriptor, ColorWrite, ErrorFilter, ErrorType, IndexFormat, InputStepMode,
+        PipelineLayoutDescriptor, PrimitiveTopology, VertexAttributeDescriptor,
+        VertexBufferLayoutDescriptor, VertexStateDescriptor,
+    };
+    use std::sync::Arc;
+
+    use parking_lot::Mutex;
+
+    let _mock = crate::mock::install();
+    let device = crate::mock::create_device();
+    device.set_pipeline_validation(true);
+
+    let vertex_shader = device.create_shader_module_with_code(&crate::util::spirv(include_bytes!(
+        "../examples/triangle.vert.spv"
+    )));
+    let fragment_shader = device.create_shader_module_with_code(&crate::util::spirv(
+        include_bytes!("../examples/triangle.frag.spv"),
+    ));
+    let pipeline_layout = |visibility| {
+        let bind_group_layout = device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            label: None,
+            entries: &[BindGroupLayoutEntry {
+                binding: 0,
+                visibility,
+                ty: BindingType::UniformBuffer { dynamic: false },
+            }],
+        });
+        device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: None,
+            bind_group_layouts: &[bind_group_layout],
+        })
+    };
+    let replace = BlendDescriptor {
+        operation: BlendOperation::Add,
+        src_factor: BlendFactor::One,
+        dst_factor: BlendFactor::Zero,
+    };
+    let validate = |layout: Option<&PipelineLayout>, color_format, color_location| {
+        validate_render_pipeline(&RenderPipelineDescriptor {
+            label: None,
+            layout,
+            vertex_stage: ProgrammableStageDescriptor {
+                module: &vertex_shader,
+                entry_point: "main",
+            },
+            fragment_stage: Some(ProgrammableStageDescriptor {
+                module: &fragment_shader,
+                entry_point: "main",
+            }),
+            vertex_state: &VertexStateDescriptor {
+                index_format: IndexFormat::Uint16,
+                vertex_buffers: &[VertexBufferLayoutDescriptor {
+                    array_stride: 24,
+                    step_mode: InputStepMode::Vertex,
+                    attributes: &[
+                        VertexAttributeDescriptor {
+                            format: VertexFormat::Float3,
+                            offset: 0,
+                            shader_location: 0,
+                        },
+                        VertexAttributeDescriptor {
+                            format: color_format,
+                            offset: 12,
+                            shader_location: color_location,
+                        },
+                    ],
+                }],
+            },
+            primitive_topology: PrimitiveTopology::TriangleList,
+            rasterization_state: None,
+            sample_count: 1,
+            depth_stencil_state: None,
+            color_states: &[ColorStateDescriptor {
+                format: TextureFormat::BGRA8Unorm,
+                alpha_blend: replace,
+                color_blend: replace,
+                write_mask: ColorWrite::ALL,
+            }],
+            sample_mask: !0,
+            alpha_to_coverage_enabled: false,
+        })
+    };
+
+    let vertex_layout = pipeline_layout(ShaderStage::VERTEX);
+    assert_eq!(
+        Ok(()),
+        validate(Some(&vertex_layout), VertexFormat::Float3, 1)
+    );
+    assert_eq!(
+        Err(PipelineValidationError::MissingVertexAttribute {
+            location: 1,
+            name: Some("a_Color".into()),
+            shader_type: ScalarType::Float,
+        }),
+        validate(Some(&vertex_layout), VertexFormat::Float3, 2)
+    );
+    assert_eq!(
+        Err(PipelineValidationError::VertexFormatMismatch {
+            location: 1,
+            name: Some("a_Color".into()),
+            format: VertexFormat::UInt3,
+            shader_type: ScalarType::Float,
+        }),
+        validate(Some(&vertex_layout), VertexFormat::UInt3, 1)
+    );
+    let err = validate(
+        Some(&pipeline_layout(ShaderStage::FRAGMENT)),
+        VertexFormat::Float3,
+        1,
+    );
+    assert_eq!(
+        "vertex stage: set 0 binding 0 is only visible to FRAGMENT",
+        err.unwrap_err().to_string()
+    );
+    assert_eq!(Ok(()), validate(None, VertexFormat::Float3, 1));
+
+    // `create_compute_pipeline` reports the first mismatch as a validation error.
+    let compute_shader_entry = ComputePipelineDescriptor {
+        label: None,
+        layout: Some(&vertex_layout),
+        compute_stage: ProgrammableStageDescriptor {
+            module: &vertex_shader,
+            entry_point: "main",
+        },
+    };
+    let message = Arc::new(Mutex::new(None));
+    let callback_message = message.clone();
+    device.push_error_scope(ErrorFilter::Validation);
+    device.create_compute_pipeline(&compute_shader_entry);
+    device.pop_error_scope(move |error_type, message| {
+        assert_eq!(ErrorType::Validation, error_type);
+        *callback_message.lock() = Some(message.to_string());
+    });
+    assert_eq!(
+        Some("compute stage: entry point \"main\" is a vertex shader".to_string()),
+        message.lock().take()
+    );
+}
@@ -0,0 +1,417 @@
+//! Recording consecutive frames to a Y4M stream or a numbered PNG sequence.
+//!
+//! `Recorder::record_texture` copies a render target into the next buffer of a ring of
+//! staging buffers and maps it asynchronously. Frames are converted and written once their
+//! map completes, so recording only waits for the GPU when every staging buffer is still in
+//! flight. Offscreen swap chains already read their images back through a ring of buffers, so
+//! `Recorder::record_swap_chain` just writes the frames they have queued.
+//!
+//! Y4M streams use 8 bit 4:4:4 BT.601 YUV, which ffmpeg and most players read directly:
+//!
+//! ```no_run
+//! # fn run(device: &dawn::Device, render_target: &dawn::Texture) -> Result<(), dawn::recorder::RecordError> {
+//! use dawn::recorder::Recorder;
+//!
+//! let file = std::fs::File::create("demo.y4m")?;
+//! let mut recorder = Recorder::y4m(device, std::io::BufWriter::new(file), 60, 3);
+//! for _ in 0..600 {
+//!     // ... render into `render_target` and submit ...
+//!     recorder.record_texture(render_target)?;
+//! }
+//! recorder.finish()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::capture::{CaptureError, Image, PendingMapRead};
+use crate::{
+    convert, Buffer, BufferCopyView, BufferDescriptor, BufferUsage, CommandEncoderDescriptor,
+    Device, Extent3d, Origin3d, SwapChain, Texture, TextureCopyView, TextureFormat, TextureUsage,
+};
+
+#[derive(Debug)]
+pub enum RecordError {
+    Io(io::Error),
+    Capture(CaptureError),
+    #[cfg(feature = "png")]
+    Png(png::EncodingError),
+    /// All frames of a Y4M stream must have the size of the first one.
+    SizeChanged {
+        expected: (u32, u32),
+        actual: (u32, u32),
+    },
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RecordError::Io(err) => write!(f, "failed to write frame: {}", err),
+            RecordError::Capture(err) => write!(f, "failed to read frame: {}", err),
+            #[cfg(feature = "png")]
+            RecordError::Png(err) => write!(f, "failed to encode frame: {}", err),
+            RecordError::SizeChanged { expected, actual } => write!(
+                f,
+                "frame size changed from {}x{} to {}x{}",
+                expected.0, expected.1, actual.0, actual.1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RecordError {}
+
+impl From<io::Error> for RecordError {
+    fn from(err: io::Error) -> RecordError {
+        RecordError::Io(err)
+    }
+}
+
+impl From<CaptureError> for RecordError {
+    fn from(err: CaptureError) -> RecordError {
+        RecordError::Capture(err)
+    }
+}
+
+enum Output {
+    Y4m {
+        writer: Box<dyn Write>,
+        fps: u32,
+        size: Option<(u32, u32)>,
+    },
+    #[cfg(feature = "png")]
+    PngSequence { directory: std::path::PathBuf },
+}
+
+struct Staging {
+    buffer: Option<Buffer>,
+    size: u64,
+    map: Option<PendingMapRead>,
+    format: TextureFormat,
+    width: u32,
+    height: u32,
+    row_size: u32,
+    bytes_per_row: u32,
+}
+
+pub struct Recorder {
+    device: Device,
+    output: Output,
+    staging: Vec<Staging>,
+    /// Indices into `staging` in the order the copies were submitted.
+    in_flight: VecDeque<usize>,
+    next_staging: usize,
+    frames_written: u64,
+}
+
+impl Recorder {
+    /// Writes a Y4M stream with `fps` frames per second to `writer`, reading back through
+    /// `staging_count` staging buffers.
+    pub fn y4m<W: Write + 'static>(
+        device: &Device,
+        writer: W,
+        fps: u32,
+        staging_count: usize,
+    ) -> Recorder {
+        let output = Output::Y4m {
+            writer: Box::new(writer),
+            fps,
+            size: None,
+        };
+        Recorder::new(device, output, staging_count)
+    }
+
+    /// Writes `frame_00000.png`, `frame_00001.png`, ... to `directory`, reading back through
+    /// `staging_count` staging buffers.
+    #[cfg(feature = "png")]
+    pub fn png_sequence<P: Into<std::path::PathBuf>>(
+        device: &Device,
+        directory: P,
+        staging_count: usize,
+    ) -> Recorder {
+        let output = Output::PngSequence {
+            directory: directory.into(),
+        };
+        Recorder::new(device, output, staging_count)
+    }
+
+    fn new(device: &Device, output: Output, staging_count: usize) -> Recorder {
+        assert!(staging_count > 0, "staging_count must not be 0");
+        let staging = (0..staging_count)
+            .map(|_| Staging {
+                buffer: None,
+                size: 0,
+                map: None,
+                format: TextureFormat::RGBA8Unorm,
+                width: 0,
+                height: 0,
+                row_size: 0,
+                bytes_per_row: 0,
+            })
+            .collect();
+        Recorder {
+            device: device.clone(),
+            output,
+            staging,
+            in_flight: VecDeque::new(),
+            next_staging: 0,
+            frames_written: 0,
+        }
+    }
+
+    /// The number of frames written so far.
+    pub fn frames_written(&self) -> u64 {
+        self.frames_written
+    }
+
+    /// Records mip level 0 of `texture`, which must have `TextureUsage::COPY_SRC` and a format
+    /// supported by `Image::from_texels`. Call after the commands that render the frame have
+    /// been submitted.
+    pub fn record_texture(&mut self, texture: &Texture) -> Result<(), RecordError> {
+        let format = texture.format();
+        let texel_size =
+            convert::texel_size(format).ok_or(CaptureError::UnsupportedFormat(format))?;
+        Image::from_texels(format, 0, 0, &[])?;
+        if !texture.usage().contains(TextureUsage::COPY_SRC) {
+            return Err(CaptureError::MissingCopySrc.into());
+        }
+
+        if self.in_flight.len() == self.staging.len() {
+            self.write_oldest(true)?;
+        }
+
+        let index = self.next_staging;
+        self.next_staging = (index + 1) % self.staging.len();
+        let staging = &mut self.staging[index];
+        let size = texture.size();
+        staging.format = format;
+        staging.width = size.width;
+        staging.height = size.height;
+        staging.row_size = texel_size * size.width;
+        staging.bytes_per_row = convert::padded_bytes_per_row(staging.row_size);
+        let buffer_size = u64::from(staging.bytes_per_row) * u64::from(size.height);
+        if staging.buffer.is_none() || staging.size != buffer_size {
+            staging.buffer = Some(self.device.create_buffer(&BufferDescriptor {
+                label: Some("recorder staging"),
+                usage: BufferUsage::MAP_READ | BufferUsage::COPY_DST,
+                size: buffer_size,
+            }));
+            staging.size = buffer_size;
+        }
+        let buffer = staging.buffer.as_ref().unwrap();
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&CommandEncoderDescriptor { label: None });
+        encoder.copy_texture_to_buffer(
+            &TextureCopyView {
+                texture,
+                mip_level: 0,
+                array_layer: 0,
+                origin: Origin3d { x: 0, y: 0, z: 0 },
+            },
+            &BufferCopyView {
+                buffer,
+                offset: 0,
+                bytes_per_row: staging.bytes_per_row,
+                rows_per_image: size.height,
+            },
+            &Extent3d {
+                width: size.width,
+                height: size.height,
+                depth: 1,
+            },
+        );
+        self.device.default_queue().submit(&[encoder.finish()]);
+        staging.map = Some(PendingMapRead::new(&self.device, buffer));
+        self.in_flight.push_back(index);
+
+        self.poll()
+    }
+
+    /// Writes the frames queued by an offscreen swap chain. The swap chain must not have a
+    /// frame callback, which would receive the frames instead.
+    pub fn record_swap_chain(&mut self, swap_chain: &SwapChain) -> Result<(), RecordError> {
+        if !swap_chain.is_offscreen() {
+            return Err(CaptureError::NotOffscreen.into());
+        }
+        // Keep the order with frames recorded from textures.
+        self.flush()?;
+        for frame in swap_chain.take_frames() {
+            let image = Image::from_texels(frame.format, frame.width, frame.height, &frame.data)?;
+            self.write_image(&image)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the frames whose readback has completed, without waiting.
+    pub fn poll(&mut self) -> Result<(), RecordError> {
+        while !self.in_flight.is_empty() {
+            if !self.write_oldest(false)? {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Waits for all recorded frames and writes them.
+    pub fn flush(&mut self) -> Result<(), RecordError> {
+        while !self.in_flight.is_empty() {
+            self.write_oldest(true)?;
+        }
+        match &mut self.output {
+            Output::Y4m { writer, .. } => writer.flush()?,
+            #[cfg(feature = "png")]
+            Output::PngSequence { .. } => {}
+        }
+        Ok(())
+    }
+
+    /// Writes the remaining frames and closes the recording.
+    pub fn finish(mut self) -> Result<(), RecordError> {
+        self.flush()
+    }
+
+    /// Writes the oldest frame in flight. Returns `false` if it isn't ready and `wait` is
+    /// `false`.
+    fn write_oldest(&mut self, wait: bool) -> Result<bool, RecordError> {
+        let index = self.in_flight[0];
+        let map = self.staging[index].map.as_ref().unwrap();
+        let mapped = if wait {
+            map.wait(&self.device)
+        } else {
+            match map.poll(&self.device) {
+                Some(mapped) => mapped,
+                None => return Ok(false),
+            }
+        };
+        self.in_flight.pop_front();
+
+        let staging = &mut self.staging[index];
+        staging.map = None;
+        let mapped = mapped.ok_or(CaptureError::MapFailed)?;
+        let mut texels = Vec::with_capacity(staging.row_size as usize * staging.height as usize);
+        for row in mapped
+            .chunks(staging.bytes_per_row as usize)
+            .take(staging.height as usize)
+        {
+            texels.extend_from_slice(&row[..staging.row_size as usize]);
+        }
+        let image = Image::from_texels(staging.format, staging.width, staging.height, &texels)?;
+        self.write_image(&image)?;
+        Ok(true)
+    }
+
+    fn write_image(&mut self, image: &Image) -> Result<(), RecordError> {
+        match &mut self.output {
+            Output::Y4m { writer, fps, size } => {
+                let actual = (image.width, image.height);
+                match size {
+                    Some(expected) if *expected != actual => {
+                        return Err(RecordError::SizeChanged {
+                            expected: *expected,
+                            actual,
+                        });
+                    }
+                    Some(_) => {}
+                    None => {
+                        writeln!(
+                            writer,
+                            "YUV4MPEG2 W{} H{} F{}:1 Ip A1:1 C444",
+                            image.width, image.height, fps
+                        )?;
+                        *size = Some(actual);
+                    }
+                }
+                writer.write_all(b"FRAME\n")?;
+                writer.write_all(&rgba_to_yuv444(&image.data))?;
+            }
+            #[cfg(feature = "png")]
+            Output::PngSequence { directory } => {
+                let path = directory.join(format!("frame_{:05}.png", self.frames_written));
+                image.save_png(path).map_err(RecordError::Png)?;
+            }
+        }
+        self.frames_written += 1;
+        Ok(())
+    }
+}
+
+/// Converts RGBA8 pixels to the Y, U and V planes of a 4:4:4 frame with BT.601 limited range.
+fn rgba_to_yuv444(rgba: &[u8]) -> Vec<u8> {
+    let pixel_count = rgba.len() / 4;
+    let mut yuv = vec![0; pixel_count * 3];
+    let (y_plane, uv_planes) = yuv.split_at_mut(pixel_count);
+    let (u_plane, v_plane) = uv_planes.split_at_mut(pixel_count);
+    for (i, pixel) in rgba.chunks(4).enumerate() {
+        let (r, g, b) = (
+            i32::from(pixel[0]),
+            i32::from(pixel[1]),
+            i32::from(pixel[2]),
+        );
+        y_plane[i] = (((66 * r + 129 * g + 25 * b + 128) >> 8) + 16) as u8;
+        u_plane[i] = (((-38 * r - 74 * g + 112 * b + 128) >> 8) + 128) as u8;
+        v_plane[i] = (((112 * r - 94 * g - 18 * b + 128) >> 8) + 128) as u8;
+    }
+    yuv
+}
+
+#[test]
+fn rgba_to_yuv444_limited_range() {
+    let yuv = rgba_to_yuv444(&[0, 0, 0, 255, 255, 255, 255, 255]);
+    assert_eq!(vec![16, 235, 128, 128, 128, 128], yuv);
+}
+
+#[cfg(feature = "mock")]
+#[test]
+fn recorder_writes_y4m_frames_in_order() {
+    use crate::{TextureDescriptor, TextureDimension};
+    use parking_lot::Mutex;
+    use std::sync::Arc;
+
+    #[derive(Clone)]
+    struct Shared(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let _guard = crate::mock::install();
+    let device = crate::mock::create_device();
+    let texture = device.create_texture(&TextureDescriptor {
+        label: None,
+        usage: TextureUsage::OUTPUT_ATTACHMENT | TextureUsage::COPY_SRC,
+        dimension: TextureDimension::D2,
+        size: Extent3d {
+            width: 2,
+            height: 2,
+            depth: 1,
+        },
+        array_layer_count: 1,
+        format: TextureFormat::BGRA8Unorm,
+        mip_level_count: 1,
+        sample_count: 1,
+    });
+    let output = Shared(Arc::new(Mutex::new(Vec::new())));
+    let mut recorder = Recorder::y4m(&device, output.clone(), 30, 2);
+    for _ in 0..3 {
+        recorder.record_texture(&texture).unwrap();
+    }
+    recorder.finish().unwrap();
+
+    let header = b"YUV4MPEG2 W2 H2 F30:1 Ip A1:1 C444\n";
+    let frame_size = b"FRAME\n".len() + 2 * 2 * 3;
+    let data = output.0.lock();
+    assert!(data.starts_with(header));
+    assert_eq!(header.len() + 3 * frame_size, data.len());
+}
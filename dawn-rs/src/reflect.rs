@@ -0,0 +1,997 @@
+//! SPIR-V reflection.
+//!
+//! `ShaderReflection::parse` reads the words passed to `Device::create_shader_module` and
+//! reports the entry points of the module, their vertex inputs and fragment outputs, and the
+//! resources bound with `set` and `binding` decorations. `PipelineLayoutReflection` combines
+//! the bindings used by the entry points of a pipeline into bind group layouts.
+//!
+//! ```no_run
+//! # let device: dawn::Device = unimplemented!();
+//! use dawn::reflect::{PipelineLayoutReflection, ShaderReflection};
+//! use dawn::util;
+//!
+//! let vs = util::spirv(include_bytes!("../examples/triangle.vert.spv"));
+//! let fs = util::spirv(include_bytes!("../examples/triangle.frag.spv"));
+//! let vs_reflection = ShaderReflection::parse(&vs).unwrap();
+//! let fs_reflection = ShaderReflection::parse(&fs).unwrap();
+//!
+//! let layout =
+//!     PipelineLayoutReflection::new(&[(&vs_reflection, "main"), (&fs_reflection, "main")])
+//!         .unwrap();
+//! let (bind_group_layouts, pipeline_layout) = layout.create_pipeline_layout(&device);
+//! let vertex_layout = vs_reflection.entry_point("main").unwrap().vertex_layout().unwrap();
+//! ```
+//!
+//! Dynamic offsets and comparison samplers can't be derived from SPIR-V. Adjust the generated
+//! entries in `PipelineLayoutReflection::sets` if a pipeline needs them.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::{
+    BindGroupLayout, BindGroupLayoutDescriptor, BindGroupLayoutEntry, BindingType, Device,
+    InputStepMode, PipelineLayout, PipelineLayoutDescriptor, ShaderStage, TextureComponentType,
+    TextureFormat, TextureViewDimension, VertexAttributeDescriptor, VertexBufferLayoutDescriptor,
+    VertexFormat,
+};
+
+pub(crate) const MAGIC: u32 = 0x0723_0203;
+pub(crate) const HEADER_LEN: usize = 5;
+
+pub(crate) mod op {
+    pub const NAME: u32 = 5;
+    pub const ENTRY_POINT: u32 = 15;
+    pub const TYPE_BOOL: u32 = 20;
+    pub const TYPE_INT: u32 = 21;
+    pub const TYPE_FLOAT: u32 = 22;
+    pub const TYPE_VECTOR: u32 = 23;
+    pub const TYPE_MATRIX: u32 = 24;
+    pub const TYPE_IMAGE: u32 = 25;
+    pub const TYPE_SAMPLER: u32 = 26;
+    pub const TYPE_SAMPLED_IMAGE: u32 = 27;
+    pub const TYPE_ARRAY: u32 = 28;
+    pub const TYPE_RUNTIME_ARRAY: u32 = 29;
+    pub const TYPE_STRUCT: u32 = 30;
+    pub const TYPE_POINTER: u32 = 32;
+    pub const CONSTANT_TRUE: u32 = 41;
+    pub const CONSTANT_FALSE: u32 = 42;
+    pub const CONSTANT: u32 = 43;
+    pub const SPEC_CONSTANT_TRUE: u32 = 48;
+    pub const SPEC_CONSTANT_FALSE: u32 = 49;
+    pub const SPEC_CONSTANT: u32 = 50;
+    pub const FUNCTION: u32 = 54;
+    pub const FUNCTION_END: u32 = 56;
+    pub const FUNCTION_CALL: u32 = 57;
+    pub const VARIABLE: u32 = 59;
+    pub const DECORATE: u32 = 71;
+    pub const MEMBER_DECORATE: u32 = 72;
+}
+
+pub(crate) mod decoration {
+    pub const SPEC_ID: u32 = 1;
+    pub const BLOCK: u32 = 2;
+    pub const BUFFER_BLOCK: u32 = 3;
+    pub const BUILT_IN: u32 = 11;
+    pub const NON_WRITABLE: u32 = 24;
+    pub const NON_READABLE: u32 = 25;
+    pub const LOCATION: u32 = 30;
+    pub const BINDING: u32 = 33;
+    pub const DESCRIPTOR_SET: u32 = 34;
+}
+
+mod storage_class {
+    pub const UNIFORM_CONSTANT: u32 = 0;
+    pub const INPUT: u32 = 1;
+    pub const UNIFORM: u32 = 2;
+    pub const OUTPUT: u32 = 3;
+    pub const STORAGE_BUFFER: u32 = 12;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReflectError {
+    /// The code doesn't start with the SPIR-V magic number.
+    InvalidHeader,
+    /// An instruction extends past the end of the code or has too few operands.
+    Truncated { offset: usize },
+    /// The module doesn't have an entry point with this name.
+    EntryPointNotFound(String),
+    /// The entry point isn't a vertex shader.
+    NotAVertexShader(String),
+    /// The resource can't be bound in WebGPU, e.g. a combined image sampler or an array of
+    /// textures.
+    UnsupportedBinding {
+        set: u32,
+        binding: u32,
+        reason: &'static str,
+    },
+    /// Two stages declare the same binding with different types.
+    BindingMismatch {
+        set: u32,
+        binding: u32,
+        first: BindingType,
+        second: BindingType,
+    },
+    /// A vertex input whose type has no `VertexFormat`.
+    UnsupportedVertexInput { location: u32 },
+}
+
+impl fmt::Display for ReflectError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReflectError::InvalidHeader => write!(f, "not a SPIR-V module"),
+            ReflectError::Truncated { offset } => {
+                write!(f, "truncated SPIR-V instruction at word {}", offset)
+            }
+            ReflectError::EntryPointNotFound(name) => write!(f, "no entry point named {:?}", name),
+            ReflectError::NotAVertexShader(name) => {
+                write!(f, "entry point {:?} isn't a vertex shader", name)
+            }
+            ReflectError::UnsupportedBinding {
+                set,
+                binding,
+                reason,
+            } => write!(f, "set {} binding {}: {}", set, binding, reason),
+            ReflectError::BindingMismatch {
+                set,
+                binding,
+                first,
+                second,
+            } => write!(
+                f,
+                "set {} binding {} is declared as {:?} and as {:?}",
+                set, binding, first, second
+            ),
+            ReflectError::UnsupportedVertexInput { location } => {
+                write!(
+                    f,
+                    "vertex input at location {} has no vertex format",
+                    location
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReflectError {}
+
+/// The scalar type of an interface variable.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ScalarType {
+    Bool,
+    Float,
+    Sint,
+    Uint,
+}
+
+/// A scalar, vector or matrix type. Scalars have one component and vectors and scalars have
+/// one column.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct NumericType {
+    pub scalar: ScalarType,
+    pub components: u32,
+    pub columns: u32,
+}
+
+impl NumericType {
+    /// The vertex format of one column of this type.
+    pub fn vertex_format(&self) -> Option<VertexFormat> {
+        let format = match (self.scalar, self.components) {
+            (ScalarType::Float, 1) => VertexFormat::Float,
+            (ScalarType::Float, 2) => VertexFormat::Float2,
+            (ScalarType::Float, 3) => VertexFormat::Float3,
+            (ScalarType::Float, 4) => VertexFormat::Float4,
+            (ScalarType::Sint, 1) => VertexFormat::Int,
+            (ScalarType::Sint, 2) => VertexFormat::Int2,
+            (ScalarType::Sint, 3) => VertexFormat::Int3,
+            (ScalarType::Sint, 4) => VertexFormat::Int4,
+            (ScalarType::Uint, 1) => VertexFormat::UInt,
+            (ScalarType::Uint, 2) => VertexFormat::UInt2,
+            (ScalarType::Uint, 3) => VertexFormat::UInt3,
+            (ScalarType::Uint, 4) => VertexFormat::UInt4,
+            _ => return None,
+        };
+        Some(format)
+    }
+}
+
+/// A shader input or output with a `location` decoration. Built-ins aren't reported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterfaceVariable {
+    pub name: Option<String>,
+    pub location: u32,
+    pub ty: NumericType,
+}
+
+/// A resource with `set` and `binding` decorations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Binding {
+    pub set: u32,
+    pub binding: u32,
+    pub name: Option<String>,
+    pub ty: BindingType,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryPoint {
+    pub name: String,
+    pub stage: ShaderStage,
+    /// Inputs, sorted by location.
+    pub inputs: Vec<InterfaceVariable>,
+    /// Outputs, sorted by location.
+    pub outputs: Vec<InterfaceVariable>,
+    /// The resources used by the entry point or the functions it calls, sorted by set and
+    /// binding.
+    pub bindings: Vec<Binding>,
+}
+
+impl EntryPoint {
+    /// A vertex buffer layout with one tightly packed attribute per input location, in
+    /// location order. Matrix inputs use one attribute per column.
+    pub fn vertex_layout(&self) -> Result<VertexLayout, ReflectError> {
+        if self.stage != ShaderStage::VERTEX {
+            return Err(ReflectError::NotAVertexShader(self.name.clone()));
+        }
+        let mut attributes = Vec::new();
+        let mut offset = 0;
+        for input in &self.inputs {
+            let format = input
+                .ty
+                .vertex_format()
+                .ok_or(ReflectError::UnsupportedVertexInput {
+                    location: input.location,
+                })?;
+            for column in 0..input.ty.columns {
+                attributes.push(VertexAttributeDescriptor {
+                    format,
+                    offset,
+                    shader_location: input.location + column,
+                });
+                offset += 4 * u64::from(input.ty.components);
+            }
+        }
+        Ok(VertexLayout {
+            array_stride: offset,
+            attributes,
+        })
+    }
+}
+
+/// Owns the attributes of a `VertexBufferLayoutDescriptor`.
+#[derive(Debug, Clone)]
+pub struct VertexLayout {
+    pub array_stride: u64,
+    pub attributes: Vec<VertexAttributeDescriptor>,
+}
+
+impl VertexLayout {
+    pub fn descriptor(&self, step_mode: InputStepMode) -> VertexBufferLayoutDescriptor<'_> {
+        VertexBufferLayoutDescriptor {
+            array_stride: self.array_stride,
+            step_mode,
+            attributes: &self.attributes,
+        }
+    }
+}
+
+/// The interface of a SPIR-V module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShaderReflection {
+    entry_points: Vec<EntryPoint>,
+}
+
+impl ShaderReflection {
+    pub fn parse(code: &[u32]) -> Result<ShaderReflection, ReflectError> {
+        Module::parse(code)?.reflect()
+    }
+
+    pub fn entry_points(&self) -> &[EntryPoint] {
+        &self.entry_points
+    }
+
+    pub fn entry_point(&self, name: &str) -> Option<&EntryPoint> {
+        self.entry_points.iter().find(|entry| entry.name == name)
+    }
+
+    fn find_entry_point(&self, name: &str) -> Result<&EntryPoint, ReflectError> {
+        self.entry_point(name)
+            .ok_or_else(|| ReflectError::EntryPointNotFound(name.to_string()))
+    }
+}
+
+/// The bind group layouts of a pipeline, derived from the bindings of its entry points.
+#[derive(Debug, Clone)]
+pub struct PipelineLayoutReflection {
+    /// Entries by bind group index, sorted by binding. Sets that no stage uses are empty.
+    pub sets: Vec<Vec<BindGroupLayoutEntry>>,
+}
+
+impl PipelineLayoutReflection {
+    /// Combines the bindings of each `(module, entry point)` pair. A binding used by several
+    /// stages is visible to all of them.
+    pub fn new(
+        stages: &[(&ShaderReflection, &str)],
+    ) -> Result<PipelineLayoutReflection, ReflectError> {
+        let mut sets: Vec<Vec<BindGroupLayoutEntry>> = Vec::new();
+        for (reflection, entry_point) in stages {
+            let entry_point = reflection.find_entry_point(entry_point)?;
+            for binding in &entry_point.bindings {
+                let set = binding.set as usize;
+                if sets.len() <= set {
+                    sets.resize(set + 1, Vec::new());
+                }
+                let entries = &mut sets[set];
+                match entries.iter_mut().find(|e| e.binding == binding.binding) {
+                    Some(entry) => {
+                        entry.ty = merge_binding_types(binding, entry.ty)?;
+                        entry.visibility |= entry_point.stage;
+                    }
+                    None => entries.push(BindGroupLayoutEntry {
+                        binding: binding.binding,
+                        visibility: entry_point.stage,
+                        ty: binding.ty,
+                    }),
+                }
+            }
+        }
+        for entries in &mut sets {
+            entries.sort_by_key(|entry| entry.binding);
+        }
+        Ok(PipelineLayoutReflection { sets })
+    }
+
+    pub fn bind_group_layout_descriptors(&self) -> Vec<BindGroupLayoutDescriptor<'_>> {
+        self.sets
+            .iter()
+            .map(|entries| BindGroupLayoutDescriptor {
+                label: None,
+                entries,
+            })
+            .collect()
+    }
+
+    pub fn create_bind_group_layouts(&self, device: &Device) -> Vec<BindGroupLayout> {
+        self.bind_group_layout_descriptors()
+            .iter()
+            .map(|descriptor| device.create_bind_group_layout(descriptor))
+            .collect()
+    }
+
+    /// Creates the bind group layouts and a pipeline layout that uses them.
+    pub fn create_pipeline_layout(
+        &self,
+        device: &Device,
+    ) -> (Vec<BindGroupLayout>, PipelineLayout) {
+        let bind_group_layouts = self.create_bind_group_layouts(device);
+        let pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: None,
+            bind_group_layouts: &bind_group_layouts,
+        });
+        (bind_group_layouts, pipeline_layout)
+    }
+}
+
+fn merge_binding_types(binding: &Binding, ty: BindingType) -> Result<BindingType, ReflectError> {
+    let merged = match (ty, binding.ty) {
+        (a, b) if a == b => a,
+        (
+            BindingType::StorageBuffer { dynamic, readonly },
+            BindingType::StorageBuffer {
+                dynamic: other_dynamic,
+                readonly: other_readonly,
+            },
+        ) if dynamic == other_dynamic => BindingType::StorageBuffer {
+            dynamic,
+            readonly: readonly && other_readonly,
+        },
+        (
+            BindingType::StorageTexture {
+                dimension,
+                component_type,
+                format,
+                ..
+            },
+            BindingType::StorageTexture {
+                dimension: other_dimension,
+                component_type: other_component_type,
+                format: other_format,
+                ..
+            },
+        ) if (dimension, component_type, format)
+            == (other_dimension, other_component_type, other_format) =>
+        {
+            BindingType::StorageTexture {
+                dimension,
+                component_type,
+                format,
+                readonly: false,
+                writeonly: false,
+            }
+        }
+        (first, second) => {
+            return Err(ReflectError::BindingMismatch {
+                set: binding.set,
+                binding: binding.binding,
+                first,
+                second,
+            })
+        }
+    };
+    Ok(merged)
+}
+
+/// Iterates over the `(opcode, operands)` of the instructions after the header.
+pub(crate) fn instructions(
+    code: &[u32],
+) -> impl Iterator<Item = Result<(usize, u32, &[u32]), ReflectError>> {
+    let mut offset = HEADER_LEN;
+    std::iter::from_fn(move || {
+        if offset >= code.len() {
+            return None;
+        }
+        let word = code[offset];
+        let (opcode, word_count) = (word & 0xffff, (word >> 16) as usize);
+        if word_count == 0 || offset + word_count > code.len() {
+            let error = ReflectError::Truncated { offset };
+            offset = code.len();
+            return Some(Err(error));
+        }
+        let instruction = (offset, opcode, &code[offset + 1..offset + word_count]);
+        offset += word_count;
+        Some(Ok(instruction))
+    })
+}
+
+pub(crate) fn check_header(code: &[u32]) -> Result<(), ReflectError> {
+    if code.len() < HEADER_LEN || code[0] != MAGIC {
+        return Err(ReflectError::InvalidHeader);
+    }
+    Ok(())
+}
+
+/// Decodes a nul terminated literal string and returns it with the number of words it used.
+pub(crate) fn literal_string(words: &[u32]) -> (String, usize) {
+    let mut bytes = Vec::new();
+    for (index, word) in words.iter().enumerate() {
+        for byte in word.to_le_bytes().iter() {
+            if *byte == 0 {
+                return (String::from_utf8_lossy(&bytes).into_owned(), index + 1);
+            }
+            bytes.push(*byte);
+        }
+    }
+    (String::from_utf8_lossy(&bytes).into_owned(), words.len())
+}
+
+#[derive(Debug, Clone)]
+enum Type {
+    Bool,
+    Int {
+        signed: bool,
+    },
+    Float,
+    Vector {
+        component: u32,
+        count: u32,
+    },
+    Matrix {
+        column: u32,
+        count: u32,
+    },
+    Image {
+        sampled_type: u32,
+        dim: u32,
+        arrayed: bool,
+        multisampled: bool,
+        sampled: u32,
+        format: u32,
+    },
+    Sampler,
+    SampledImage,
+    Array,
+    Struct,
+    Pointer {
+        pointee: u32,
+    },
+}
+
+#[derive(Debug, Default)]
+struct Decorations {
+    location: Option<u32>,
+    set: Option<u32>,
+    binding: Option<u32>,
+    block: bool,
+    buffer_block: bool,
+    built_in: bool,
+    non_writable: bool,
+    non_readable: bool,
+}
+
+struct RawEntryPoint {
+    execution_model: u32,
+    function: u32,
+    name: String,
+    interface: Vec<u32>,
+}
+
+#[derive(Default)]
+struct Module {
+    entry_points: Vec<RawEntryPoint>,
+    names: HashMap<u32, String>,
+    types: HashMap<u32, Type>,
+    decorations: HashMap<u32, Decorations>,
+    // Struct id -> (member count, number of members decorated NonWritable).
+    struct_members: HashMap<u32, (usize, usize)>,
+    // Struct ids with a BuiltIn member, such as gl_PerVertex.
+    built_in_structs: HashSet<u32>,
+    // Global variable id -> (storage class, pointer type).
+    variables: HashMap<u32, (u32, u32)>,
+    // Function id -> (callees, global variables referenced).
+    functions: HashMap<u32, (Vec<u32>, HashSet<u32>)>,
+}
+
+impl Module {
+    fn parse(code: &[u32]) -> Result<Module, ReflectError> {
+        check_header(code)?;
+        let mut module = Module::default();
+        let mut current_function = None;
+        for instruction in instructions(code) {
+            let (offset, opcode, operands) = instruction?;
+            let operand = |index: usize| {
+                operands
+                    .get(index)
+                    .copied()
+                    .ok_or(ReflectError::Truncated { offset })
+            };
+            if let Some(function) = current_function {
+                match opcode {
+                    op::FUNCTION_END => current_function = None,
+                    op::FUNCTION_CALL => {
+                        let callee = operand(2)?;
+                        let (callees, _) = module.functions.entry(function).or_default();
+                        callees.push(callee);
+                    }
+                    _ => {}
+                }
+                // Global variables can only be referenced through ids. Literal operands may
+                // alias an id, which at worst makes a binding visible to an extra stage.
+                let used: Vec<u32> = operands
+                    .iter()
+                    .copied()
+                    .filter(|id| module.variables.contains_key(id))
+                    .collect();
+                let (_, globals) = module.functions.entry(function).or_default();
+                globals.extend(used);
+                continue;
+            }
+            match opcode {
+                op::NAME => {
+                    let (name, _) = literal_string(operands.get(1..).unwrap_or(&[]));
+                    module.names.insert(operand(0)?, name);
+                }
+                op::ENTRY_POINT => {
+                    let (execution_model, function) = (operand(0)?, operand(1)?);
+                    let (name, name_len) = literal_string(&operands[2..]);
+                    module.entry_points.push(RawEntryPoint {
+                        execution_model,
+                        function,
+                        name,
+                        interface: operands[2 + name_len..].to_vec(),
+                    });
+                }
+                op::TYPE_BOOL => {
+                    module.types.insert(operand(0)?, Type::Bool);
+                }
+                op::TYPE_INT => {
+                    let signed = operand(2)? != 0;
+                    module.types.insert(operand(0)?, Type::Int { signed });
+                }
+                op::TYPE_FLOAT => {
+                    module.types.insert(operand(0)?, Type::Float);
+                }
+                op::TYPE_VECTOR => {
+                    let (component, count) = (operand(1)?, operand(2)?);
+                    module
+                        .types
+                        .insert(operand(0)?, Type::Vector { component, count });
+                }
+                op::TYPE_MATRIX => {
+                    let (column, count) = (operand(1)?, operand(2)?);
+                    module
+                        .types
+                        .insert(operand(0)?, Type::Matrix { column, count });
+                }
+                op::TYPE_IMAGE => {
+                    let image = Type::Image {
+                        sampled_type: operand(1)?,
+                        dim: operand(2)?,
+                        arrayed: operand(4)? != 0,
+                        multisampled: operand(5)? != 0,
+                        sampled: operand(6)?,
+                        format: operand(7)?,
+                    };
+                    module.types.insert(operand(0)?, image);
+                }
+                op::TYPE_SAMPLER => {
+                    module.types.insert(operand(0)?, Type::Sampler);
+                }
+                op::TYPE_SAMPLED_IMAGE => {
+                    module.types.insert(operand(0)?, Type::SampledImage);
+                }
+                op::TYPE_ARRAY | op::TYPE_RUNTIME_ARRAY => {
+                    module.types.insert(operand(0)?, Type::Array);
+                }
+                op::TYPE_STRUCT => {
+                    let id = operand(0)?;
+                    module.types.insert(id, Type::Struct);
+                    module.struct_members.entry(id).or_default().0 = operands.len() - 1;
+                }
+                op::TYPE_POINTER => {
+                    let pointee = operand(2)?;
+                    module.types.insert(operand(0)?, Type::Pointer { pointee });
+                }
+                op::VARIABLE => {
+                    let (ty, id, storage_class) = (operand(0)?, operand(1)?, operand(2)?);
+                    module.variables.insert(id, (storage_class, ty));
+                }
+                op::FUNCTION => {
+                    let id = operand(1)?;
+                    module.functions.entry(id).or_default();
+                    current_function = Some(id);
+                }
+                op::DECORATE => {
+                    let decorations = module.decorations.entry(operand(0)?).or_default();
+                    match operand(1)? {
+                        decoration::LOCATION => decorations.location = Some(operand(2)?),
+                        decoration::DESCRIPTOR_SET => decorations.set = Some(operand(2)?),
+                        decoration::BINDING => decorations.binding = Some(operand(2)?),
+                        decoration::BLOCK => decorations.block = true,
+                        decoration::BUFFER_BLOCK => decorations.buffer_block = true,
+                        decoration::BUILT_IN => decorations.built_in = true,
+                        decoration::NON_WRITABLE => decorations.non_writable = true,
+                        decoration::NON_READABLE => decorations.non_readable = true,
+                        _ => {}
+                    }
+                }
+                op::MEMBER_DECORATE => {
+                    let id = operand(0)?;
+                    match operand(2)? {
+                        decoration::NON_WRITABLE => {
+                            module.struct_members.entry(id).or_default().1 += 1;
+                        }
+                        decoration::BUILT_IN => {
+                            module.built_in_structs.insert(id);
+                        }
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(module)
+    }
+
+    fn reflect(&self) -> Result<ShaderReflection, ReflectError> {
+        let mut entry_points = Vec::new();
+        for raw in &self.entry_points {
+            let stage = match raw.execution_model {
+                0 => ShaderStage::VERTEX,
+                4 => ShaderStage::FRAGMENT,
+                5 => ShaderStage::COMPUTE,
+                // Tessellation, geometry and kernels aren't supported by WebGPU.
+                _ => continue,
+            };
+            let mut inputs = Vec::new();
+            let mut outputs = Vec::new();
+            for id in &raw.interface {
+                let (storage_class, pointer) = match self.variables.get(id) {
+                    Some(variable) => *variable,
+                    None => continue,
+                };
+                let location = match self.decorations.get(id).and_then(|d| d.location) {
+                    Some(location) => location,
+                    None => continue,
+                };
+                let ty = match self.numeric_type(self.pointee(pointer)) {
+                    Some(ty) => ty,
+                    None => continue,
+                };
+                let variable = InterfaceVariable {
+                    name: self.names.get(id).cloned(),
+                    location,
+                    ty,
+                };
+                match storage_class {
+                    storage_class::INPUT => inputs.push(variable),
+                    storage_class::OUTPUT => outputs.push(variable),
+                    _ => {}
+                }
+            }
+            inputs.sort_by_key(|variable| variable.location);
+            outputs.sort_by_key(|variable| variable.location);
+
+            let mut bindings = Vec::new();
+            for id in self.reachable_globals(raw.function) {
+                if let Some(binding) = self.binding(id)? {
+                    bindings.push(binding);
+                }
+            }
+            bindings.sort_by_key(|binding| (binding.set, binding.binding));
+
+            entry_points.push(EntryPoint {
+                name: raw.name.clone(),
+                stage,
+                inputs,
+                outputs,
+                bindings,
+            });
+        }
+        Ok(ShaderReflection { entry_points })
+    }
+
+    fn reachable_globals(&self, function: u32) -> HashSet<u32> {
+        let mut visited = HashSet::new();
+        let mut pending = vec![function];
+        let mut globals = HashSet::new();
+        while let Some(function) = pending.pop() {
+            if !visited.insert(function) {
+                continue;
+            }
+            if let Some((callees, used)) = self.functions.get(&function) {
+                pending.extend(callees);
+                globals.extend(used);
+            }
+        }
+        globals
+    }
+
+    fn pointee(&self, pointer: u32) -> u32 {
+        match self.types.get(&pointer) {
+            Some(Type::Pointer { pointee }) => *pointee,
+            _ => pointer,
+        }
+    }
+
+    fn scalar_type(&self, id: u32) -> Option<ScalarType> {
+        match self.types.get(&id)? {
+            Type::Bool => Some(ScalarType::Bool),
+            Type::Float => Some(ScalarType::Float),
+            Type::Int { signed: true } => Some(ScalarType::Sint),
+            Type::Int { signed: false } => Some(ScalarType::Uint),
+            _ => None,
+        }
+    }
+
+    fn numeric_type(&self, id: u32) -> Option<NumericType> {
+        match self.types.get(&id)? {
+            Type::Vector { component, count } => Some(NumericType {
+                scalar: self.scalar_type(*component)?,
+                components: *count,
+                columns: 1,
+            }),
+            Type::Matrix { column, count } => Some(NumericType {
+                columns: *count,
+                ..self.numeric_type(*column)?
+            }),
+            _ => Some(NumericType {
+                scalar: self.scalar_type(id)?,
+                components: 1,
+                columns: 1,
+            }),
+        }
+    }
+
+    fn binding(&self, id: u32) -> Result<Option<Binding>, ReflectError> {
+        let decorations = match self.decorations.get(&id) {
+            Some(decorations) => decorations,
+            None => return Ok(None),
+        };
+        let (set, binding) = match (decorations.set, decorations.binding) {
+            (Some(set), Some(binding)) => (set, binding),
+            (None, Some(binding)) => (0, binding),
+            _ => return Ok(None),
+        };
+        let unsupported = |reason| ReflectError::UnsupportedBinding {
+            set,
+            binding,
+            reason,
+        };
+        let (storage_class, pointer) = self.variables[&id];
+        let pointee = self.pointee(pointer);
+        let resource_decorations = self.decorations.get(&pointee);
+        let ty = match (storage_class, self.types.get(&pointee)) {
+            (storage_class::UNIFORM, Some(Type::Struct))
+                if resource_decorations.map(|d| d.buffer_block) == Some(true) =>
+            {
+                BindingType::StorageBuffer {
+                    dynamic: false,
+                    readonly: self.is_readonly(pointee, decorations),
+                }
+            }
+            (storage_class::UNIFORM, Some(Type::Struct)) => {
+                BindingType::UniformBuffer { dynamic: false }
+            }
+            (storage_class::STORAGE_BUFFER, Some(Type::Struct)) => BindingType::StorageBuffer {
+                dynamic: false,
+                readonly: self.is_readonly(pointee, decorations),
+            },
+            (storage_class::UNIFORM_CONSTANT, Some(Type::Sampler)) => {
+                BindingType::Sampler { comparison: false }
+            }
+            (storage_class::UNIFORM_CONSTANT, Some(Type::SampledImage)) => {
+                return Err(unsupported("combined image samplers aren't supported"))
+            }
+            (
+                storage_class::UNIFORM_CONSTANT,
+                Some(Type::Image {
+                    sampled_type,
+                    dim,
+                    arrayed,
+                    multisampled,
+                    sampled,
+                    format,
+                }),
+            ) => {
+                let dimension = texture_view_dimension(*dim, *arrayed)
+                    .ok_or_else(|| unsupported("unsupported image dimension"))?;
+                let component_type = match self.scalar_type(*sampled_type) {
+                    Some(ScalarType::Float) => TextureComponentType::Float,
+                    Some(ScalarType::Sint) => TextureComponentType::Sint,
+                    Some(ScalarType::Uint) => TextureComponentType::Uint,
+                    _ => return Err(unsupported("unsupported image sampled type")),
+                };
+                if *sampled == 2 {
+                    BindingType::StorageTexture {
+                        dimension,
+                        component_type,
+                        format: storage_texture_format(*format)
+                            .ok_or_else(|| unsupported("unsupported storage image format"))?,
+                        readonly: decorations.non_writable,
+                        writeonly: decorations.non_readable,
+                    }
+                } else {
+                    BindingType::SampledTexture {
+                        dimension,
+                        component_type,
+                        multisampled: *multisampled,
+                    }
+                }
+            }
+            (_, Some(Type::Array)) => {
+                return Err(unsupported("arrays of resources aren't supported"))
+            }
+            _ => return Err(unsupported("unsupported resource type")),
+        };
+        Ok(Some(Binding {
+            set,
+            binding,
+            name: self.names.get(&id).cloned(),
+            ty,
+        }))
+    }
+
+    fn is_readonly(&self, block: u32, variable_decorations: &Decorations) -> bool {
+        if variable_decorations.non_writable {
+            return true;
+        }
+        match self.struct_members.get(&block) {
+            Some((members, non_writable)) => *members > 0 && members == non_writable,
+            None => false,
+        }
+    }
+}
+
+fn texture_view_dimension(dim: u32, arrayed: bool) -> Option<TextureViewDimension> {
+    let dimension = match (dim, arrayed) {
+        (0, false) => TextureViewDimension::D1,
+        (1, false) => TextureViewDimension::D2,
+        (1, true) => TextureViewDimension::D2Array,
+        (2, false) => TextureViewDimension::D3,
+        (3, false) => TextureViewDimension::Cube,
+        (3, true) => TextureViewDimension::CubeArray,
+        _ => return None,
+    };
+    Some(dimension)
+}
+
+/// Maps a SPIR-V `ImageFormat` to the texture format with the same layout.
+fn storage_texture_format(format: u32) -> Option<TextureFormat> {
+    let format = match format {
+        1 => TextureFormat::RGBA32Float,
+        2 => TextureFormat::RGBA16Float,
+        3 => TextureFormat::R32Float,
+        4 => TextureFormat::RGBA8Unorm,
+        5 => TextureFormat::RGBA8Snorm,
+        6 => TextureFormat::RG32Float,
+        7 => TextureFormat::RG16Float,
+        8 => TextureFormat::RG11B10Float,
+        9 => TextureFormat::R16Float,
+        11 => TextureFormat::RGB10A2Unorm,
+        13 => TextureFormat::RG8Unorm,
+        15 => TextureFormat::R8Unorm,
+        18 => TextureFormat::RG8Snorm,
+        20 => TextureFormat::R8Snorm,
+        21 => TextureFormat::RGBA32Sint,
+        22 => TextureFormat::RGBA16Sint,
+        23 => TextureFormat::RGBA8Sint,
+        24 => TextureFormat::R32Sint,
+        25 => TextureFormat::RG32Sint,
+        26 => TextureFormat::RG16Sint,
+        27 => TextureFormat::RG8Sint,
+        28 => TextureFormat::R16Sint,
+        29 => TextureFormat::R8Sint,
+        30 => TextureFormat::RGBA32Uint,
+        31 => TextureFormat::RGBA16Uint,
+        32 => TextureFormat::RGBA8Uint,
+        33 => TextureFormat::R32Uint,
+        35 => TextureFormat::RG32Uint,
+        36 => TextureFormat::RG16Uint,
+        37 => TextureFormat::RG8Uint,
+        38 => TextureFormat::R16Uint,
+        39 => TextureFormat::R8Uint,
+        _ => return None,
+    };
+    Some(format)
+}
+
+#[test]
+fn reflect_triangle_shaders() {
+    let vs = crate::util::spirv(include_bytes!("../examples/triangle.vert.spv"));
+    let fs = crate::util::spirv(include_bytes!("../examples/triangle.frag.spv"));
+    let vs = ShaderReflection::parse(&vs).unwrap();
+    let fs = ShaderReflection::parse(&fs).unwrap();
+
+    let main = vs.entry_point("main").unwrap();
+    assert_eq!(ShaderStage::VERTEX, main.stage);
+    let float3 = NumericType {
+        scalar: ScalarType::Float,
+        components: 3,
+        columns: 1,
+    };
+    assert_eq!(
+        vec![
+            (0, Some("a_Position"), float3),
+            (1, Some("a_Color"), float3)
+        ],
+        main.inputs
+            .iter()
+            .map(|input| (input.location, input.name.as_deref(), input.ty))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![Binding {
+            set: 0,
+            binding: 0,
+            name: Some(String::new()),
+            ty: BindingType::UniformBuffer { dynamic: false },
+        }],
+        main.bindings
+    );
+
+    let vertex_layout = main.vertex_layout().unwrap();
+    assert_eq!(24, vertex_layout.array_stride);
+    assert_eq!(
+        vec![(VertexFormat::Float3, 0, 0), (VertexFormat::Float3, 12, 1)],
+        vertex_layout
+            .attributes
+            .iter()
+            .map(|a| (a.format, a.offset, a.shader_location))
+            .collect::<Vec<_>>()
+    );
+
+    let fs_main = fs.entry_point("main").unwrap();
+    assert_eq!(ShaderStage::FRAGMENT, fs_main.stage);
+    assert_eq!(4, fs_main.outputs[0].ty.components);
+    assert!(fs_main.vertex_layout().is_err());
+
+    let layout = PipelineLayoutReflection::new(&[(&vs, "main"), (&fs, "main")]).unwrap();
+    assert_eq!(1, layout.sets.len());
+    assert_eq!(ShaderStage::VERTEX, layout.sets[0][0].visibility);
+    assert_eq!(
+        Err(ReflectError::EntryPointNotFound("vs_main".into())),
+        PipelineLayoutReflection::new(&[(&vs, "vs_main")]).map(|_| ())
+    );
+    assert_eq!(
+        Err(ReflectError::InvalidHeader),
+        ShaderReflection::parse(&[0; 5])
+    );
+}
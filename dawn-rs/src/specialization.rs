@@ -0,0 +1,361 @@
+//! Fixing specialization constants in SPIR-V.
+//!
+//! The WebGPU API of this Dawn revision can't set pipeline constants, so shaders that only
+//! differ by a constant would have to be compiled once per value. `specialize` rewrites the
+//! `OpSpecConstantTrue`, `OpSpecConstantFalse` and `OpSpecConstant` instructions with the
+//! given `SpecId`s into plain constants before the module is passed to
+//! `Device::create_shader_module`. Constants that aren't given keep their default values.
+//!
+//! ```no_run
+//! # let device: dawn::Device = unimplemented!();
+//! # let code: Vec<u32> = unimplemented!();
+//! use dawn::specialization::{SpecValue, SpecializationCache};
+//! use std::collections::HashMap;
+//!
+//! let mut cache = SpecializationCache::new();
+//! let mut constants = HashMap::new();
+//! constants.insert(0, SpecValue::UInt(64));
+//! constants.insert(1, SpecValue::Bool(true));
+//! let specialized = cache.specialize(&code, &constants).unwrap();
+//! let shader = device.create_shader_module_with_code(&specialized);
+//! ```
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use crate::reflect::{self, decoration, op, ReflectError};
+
+/// The value of a specialization constant. Integers and floats are converted to the width of
+/// the constant's type.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SpecValue {
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+}
+
+impl Hash for SpecValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match *self {
+            SpecValue::Bool(value) => (0, value as u64).hash(state),
+            SpecValue::Int(value) => (1, value as u64).hash(state),
+            SpecValue::UInt(value) => (2, value).hash(state),
+            SpecValue::Float(value) => (3, value.to_bits()).hash(state),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpecializationError {
+    Reflect(ReflectError),
+    /// No specialization constant is decorated with the `SpecId`.
+    UnknownSpecId(u32),
+    /// The value isn't of the constant's type or doesn't fit its width.
+    TypeMismatch {
+        spec_id: u32,
+        value: SpecValue,
+    },
+}
+
+impl fmt::Display for SpecializationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SpecializationError::Reflect(err) => write!(f, "{}", err),
+            SpecializationError::UnknownSpecId(spec_id) => {
+                write!(f, "no specialization constant with SpecId {}", spec_id)
+            }
+            SpecializationError::TypeMismatch { spec_id, value } => write!(
+                f,
+                "{:?} doesn't match the type of the specialization constant with SpecId {}",
+                value, spec_id
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SpecializationError {}
+
+impl From<ReflectError> for SpecializationError {
+    fn from(err: ReflectError) -> SpecializationError {
+        SpecializationError::Reflect(err)
+    }
+}
+
+// Like `==`, but floats are compared by their bits, as they are hashed.
+fn same_value(a: SpecValue, b: SpecValue) -> bool {
+    match (a, b) {
+        (SpecValue::Float(a), SpecValue::Float(b)) => a.to_bits() == b.to_bits(),
+        _ => a == b,
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum ScalarType {
+    Bool,
+    Int(u32),
+    Float(u32),
+}
+
+/// Returns a copy of `code` with the specialization constants in `constants`, by `SpecId`,
+/// replaced by constants with those values.
+pub fn specialize(
+    code: &[u32],
+    constants: &HashMap<u32, SpecValue>,
+) -> Result<Vec<u32>, SpecializationError> {
+    reflect::check_header(code)?;
+
+    let mut types = HashMap::new();
+    let mut spec_ids = HashMap::new();
+    let mut spec_constants = HashMap::new();
+    for instruction in reflect::instructions(code) {
+        let (offset, opcode, operands) = instruction?;
+        match (opcode, operands) {
+            (op::TYPE_BOOL, &[id]) => {
+                types.insert(id, ScalarType::Bool);
+            }
+            (op::TYPE_INT, &[id, width, _]) => {
+                types.insert(id, ScalarType::Int(width));
+            }
+            (op::TYPE_FLOAT, &[id, width]) => {
+                types.insert(id, ScalarType::Float(width));
+            }
+            (op::DECORATE, &[target, decoration::SPEC_ID, spec_id]) => {
+                spec_ids.insert(spec_id, target);
+            }
+            (op::SPEC_CONSTANT_TRUE, &[ty, id])
+            | (op::SPEC_CONSTANT_FALSE, &[ty, id])
+            | (op::SPEC_CONSTANT, &[ty, id, ..]) => {
+                spec_constants.insert(id, (offset, ty));
+            }
+            _ => {}
+        }
+    }
+
+    // The rewritten instructions by offset. They have the same length as the originals.
+    let mut replacements = HashMap::new();
+    for (&spec_id, &value) in constants {
+        let constant = spec_ids.get(&spec_id).and_then(|id| spec_constants.get(id));
+        let (offset, ty) = match constant {
+            Some(&constant) => constant,
+            None => return Err(SpecializationError::UnknownSpecId(spec_id)),
+        };
+        let mismatch = SpecializationError::TypeMismatch { spec_id, value };
+        let id = code[offset + 2];
+        let instruction = match (types.get(&ty), value) {
+            (Some(ScalarType::Bool), SpecValue::Bool(value)) => {
+                let opcode = if value {
+                    op::CONSTANT_TRUE
+                } else {
+                    op::CONSTANT_FALSE
+                };
+                vec![3 << 16 | opcode, ty, id]
+            }
+            (Some(&ScalarType::Int(width)), SpecValue::Int(_))
+            | (Some(&ScalarType::Int(width)), SpecValue::UInt(_))
+            | (Some(&ScalarType::Float(width)), SpecValue::Float(_)) => {
+                let literal = literal(width, value).ok_or(mismatch)?;
+                let mut instruction = vec![(literal.len() as u32 + 3) << 16 | op::CONSTANT, ty, id];
+                instruction.extend(literal);
+                instruction
+            }
+            _ => return Err(mismatch),
+        };
+        replacements.insert(offset, instruction);
+    }
+
+    let mut specialized = Vec::with_capacity(code.len());
+    specialized.extend_from_slice(&code[..reflect::HEADER_LEN]);
+    for instruction in reflect::instructions(code) {
+        let (offset, opcode, operands) = instruction?;
+        if let Some(replacement) = replacements.get(&offset) {
+            specialized.extend_from_slice(replacement);
+            continue;
+        }
+        // SpecId can only decorate specialization constants.
+        if let (op::DECORATE, &[target, decoration::SPEC_ID, spec_id]) = (opcode, operands) {
+            if constants.contains_key(&spec_id) && spec_ids.get(&spec_id) == Some(&target) {
+                continue;
+            }
+        }
+        specialized.extend_from_slice(&code[offset..offset + operands.len() + 1]);
+    }
+    Ok(specialized)
+}
+
+// Encodes a number as a literal of a 32 or 64-bit type, low-order word first.
+fn literal(width: u32, value: SpecValue) -> Option<Vec<u32>> {
+    let bits = match (width, value) {
+        // Negative values are stored in two's complement.
+        (32, SpecValue::Int(value))
+            if (i64::from(i32::MIN)..=i64::from(u32::MAX)).contains(&value) =>
+        {
+            return Some(vec![value as u32]);
+        }
+        (32, SpecValue::UInt(value)) => return Some(vec![u32::try_from(value).ok()?]),
+        (32, SpecValue::Float(value)) => return Some(vec![(value as f32).to_bits()]),
+        (64, SpecValue::Int(value)) => value as u64,
+        (64, SpecValue::UInt(value)) => value,
+        (64, SpecValue::Float(value)) => value.to_bits(),
+        _ => return None,
+    };
+    Some(vec![bits as u32, (bits >> 32) as u32])
+}
+
+/// Specialized modules by code and constants, so each variant is only rewritten once.
+#[derive(Debug, Default)]
+pub struct SpecializationCache {
+    // Bucketed by the hash of the code and the constants, which are compared on a hit.
+    modules: HashMap<u64, Vec<CacheEntry>>,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    code: Arc<[u32]>,
+    constants: Vec<(u32, SpecValue)>,
+    module: Arc<[u32]>,
+}
+
+impl CacheEntry {
+    fn matches(&self, code: &[u32], constants: &[(u32, SpecValue)]) -> bool {
+        *self.code == *code
+            && self.constants.len() == constants.len()
+            && self
+                .constants
+                .iter()
+                .zip(constants)
+                .all(|(a, b)| a.0 == b.0 && same_value(a.1, b.1))
+    }
+}
+
+fn cache_key(code: &[u32], constants: &[(u32, SpecValue)]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    code.hash(&mut hasher);
+    constants.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl SpecializationCache {
+    pub fn new() -> SpecializationCache {
+        SpecializationCache::default()
+    }
+
+    /// Returns the cached result of `specialize(code, constants)`.
+    pub fn specialize(
+        &mut self,
+        code: &[u32],
+        constants: &HashMap<u32, SpecValue>,
+    ) -> Result<Arc<[u32]>, SpecializationError> {
+        let mut constants_by_id: Vec<_> = constants
+            .iter()
+            .map(|(&spec_id, &value)| (spec_id, value))
+            .collect();
+        constants_by_id.sort_by_key(|&(spec_id, _)| spec_id);
+        let key = cache_key(code, &constants_by_id);
+
+        let hit = self.modules.get(&key).and_then(|entries| {
+            entries
+                .iter()
+                .find(|entry| entry.matches(code, &constants_by_id))
+        });
+        if let Some(entry) = hit {
+            return Ok(entry.module.clone());
+        }
+        let module: Arc<[u32]> = specialize(code, constants)?.into();
+        self.modules.entry(key).or_default().push(CacheEntry {
+            code: code.into(),
+            constants: constants_by_id,
+            module: module.clone(),
+        });
+        Ok(module)
+    }
+
+    pub fn len(&self) -> usize {
+        self.modules.values().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.modules.clear();
+    }
+}
+
+#[test]
+fn spec_constants_are_replaced() {
+    #[rustfmt::skip]
+    let code = [
+        reflect::MAGIC, 0x0001_0000, 0, 10, 0,
+        4 << 16 | op::DECORATE, 3, decoration::SPEC_ID, 7,
+        4 << 16 | op::DECORATE, 5, decoration::SPEC_ID, 8,
+        4 << 16 | op::DECORATE, 6, decoration::SPEC_ID, 9,
+        4 << 16 | op::TYPE_INT, 1, 32, 0,
+        3 << 16 | op::TYPE_FLOAT, 2, 32,
+        2 << 16 | op::TYPE_BOOL, 4,
+        4 << 16 | op::SPEC_CONSTANT, 1, 3, 5,
+        3 << 16 | op::SPEC_CONSTANT_TRUE, 4, 5,
+        4 << 16 | op::SPEC_CONSTANT, 2, 6, 1.0f32.to_bits(),
+    ];
+    let mut constants = HashMap::new();
+    constants.insert(7, SpecValue::Int(-2));
+    constants.insert(8, SpecValue::Bool(false));
+
+    #[rustfmt::skip]
+    let expected = vec![
+        reflect::MAGIC, 0x0001_0000, 0, 10, 0,
+        4 << 16 | op::DECORATE, 6, decoration::SPEC_ID, 9,
+        4 << 16 | op::TYPE_INT, 1, 32, 0,
+        3 << 16 | op::TYPE_FLOAT, 2, 32,
+        2 << 16 | op::TYPE_BOOL, 4,
+        4 << 16 | op::CONSTANT, 1, 3, -2i32 as u32,
+        3 << 16 | op::CONSTANT_FALSE, 4, 5,
+        4 << 16 | op::SPEC_CONSTANT, 2, 6, 1.0f32.to_bits(),
+    ];
+    assert_eq!(Ok(expected), specialize(&code, &constants));
+
+    constants.insert(9, SpecValue::Bool(true));
+    assert_eq!(
+        Err(SpecializationError::TypeMismatch {
+            spec_id: 9,
+            value: SpecValue::Bool(true),
+        }),
+        specialize(&code, &constants)
+    );
+    constants.remove(&9);
+    constants.insert(10, SpecValue::UInt(1));
+    assert_eq!(
+        Err(SpecializationError::UnknownSpecId(10)),
+        specialize(&code, &constants)
+    );
+    constants.remove(&10);
+
+    let mut cache = SpecializationCache::new();
+    let first = cache.specialize(&code, &constants).unwrap();
+    let second = cache.specialize(&code, &constants).unwrap();
+    assert!(Arc::ptr_eq(&first, &second));
+    constants.insert(9, SpecValue::Float(0.5));
+    cache.specialize(&code, &constants).unwrap();
+    assert_eq!(2, cache.len());
+
+    // An entry for other code under the same hash isn't returned.
+    let mut cache = SpecializationCache::new();
+    let key = cache_key(&code, &[]);
+    let other: Arc<[u32]> = code[..reflect::HEADER_LEN].into();
+    cache.modules.insert(
+        key,
+        vec![CacheEntry {
+            code: other.clone(),
+            constants: Vec::new(),
+            module: other,
+        }],
+    );
+    let module = cache.specialize(&code, &HashMap::new()).unwrap();
+    assert_eq!(&code[..], &module[..]);
+    assert_eq!(2, cache.len());
+}
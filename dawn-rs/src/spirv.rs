@@ -0,0 +1,211 @@
+//! Loading SPIR-V.
+//!
+//! `ShaderSource` checks that bytes read at runtime are a SPIR-V module before they are passed
+//! to `Device::create_shader_module`, and converts modules written in the other byte order.
+//! `include_spirv!` does the same checks when the crate is compiled and yields correctly
+//! aligned words.
+//!
+//! ```no_run
+//! # let device: dawn::Device = unimplemented!();
+//! use dawn::spirv::ShaderSource;
+//!
+//! let vertex_shader = device.create_shader_module_with_code(dawn::include_spirv!(
+//!     "../examples/triangle.vert.spv"
+//! ));
+//! let fragment_source = ShaderSource::load("examples/triangle.frag.spv").unwrap();
+//! let fragment_shader = device.create_shader_module_with_code(&fragment_source);
+//! ```
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::ops::Deref;
+use std::path::Path;
+
+/// The first word of every SPIR-V module.
+pub const MAGIC: u32 = 0x0723_0203;
+
+// The magic number, version, generator, bound and schema words.
+const HEADER_WORDS: usize = 5;
+
+#[derive(Debug)]
+pub enum SpirvError {
+    Io(io::Error),
+    /// The length in bytes isn't a multiple of 4 or is too short for the header.
+    InvalidLength(usize),
+    /// The first word isn't the SPIR-V magic number in either byte order.
+    InvalidMagic(u32),
+}
+
+impl fmt::Display for SpirvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SpirvError::Io(err) => write!(f, "failed to read SPIR-V: {}", err),
+            SpirvError::InvalidLength(len) => write!(
+                f,
+                "SPIR-V must be a multiple of 4 bytes and at least {} bytes long, got {} bytes",
+                HEADER_WORDS * 4,
+                len
+            ),
+            SpirvError::InvalidMagic(magic) => {
+                write!(f, "not SPIR-V, the magic number is {:#010x}", magic)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SpirvError {}
+
+impl From<io::Error> for SpirvError {
+    fn from(err: io::Error) -> SpirvError {
+        SpirvError::Io(err)
+    }
+}
+
+/// A SPIR-V module in native byte order. Derefs to the words to pass to
+/// `Device::create_shader_module`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShaderSource {
+    words: Vec<u32>,
+}
+
+impl ShaderSource {
+    /// Reads a `.spv` file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<ShaderSource, SpirvError> {
+        ShaderSource::from_bytes(&fs::read(path)?)
+    }
+
+    /// Checks the header of `bytes` and converts them to words. Modules in either byte order
+    /// are accepted.
+    pub fn from_bytes(bytes: &[u8]) -> Result<ShaderSource, SpirvError> {
+        if bytes.len() % 4 != 0 || bytes.len() < HEADER_WORDS * 4 {
+            return Err(SpirvError::InvalidLength(bytes.len()));
+        }
+        let word = |bytes: &[u8]| [bytes[0], bytes[1], bytes[2], bytes[3]];
+        let magic = u32::from_le_bytes(word(bytes));
+        let from_bytes = if magic == MAGIC {
+            u32::from_le_bytes
+        } else if magic.swap_bytes() == MAGIC {
+            u32::from_be_bytes
+        } else {
+            return Err(SpirvError::InvalidMagic(magic));
+        };
+        let words = bytes
+            .chunks_exact(4)
+            .map(|bytes| from_bytes(word(bytes)))
+            .collect();
+        Ok(ShaderSource { words })
+    }
+
+    /// Checks the header of `words`, byte swapping them if they are in the other byte order.
+    pub fn from_words(mut words: Vec<u32>) -> Result<ShaderSource, SpirvError> {
+        if words.len() < HEADER_WORDS {
+            return Err(SpirvError::InvalidLength(words.len() * 4));
+        }
+        if words[0].swap_bytes() == MAGIC {
+            for word in &mut words {
+                *word = word.swap_bytes();
+            }
+        } else if words[0] != MAGIC {
+            return Err(SpirvError::InvalidMagic(words[0]));
+        }
+        Ok(ShaderSource { words })
+    }
+
+    pub fn words(&self) -> &[u32] {
+        &self.words
+    }
+
+    pub fn into_words(self) -> Vec<u32> {
+        self.words
+    }
+}
+
+impl Deref for ShaderSource {
+    type Target = [u32];
+
+    fn deref(&self) -> &[u32] {
+        &self.words
+    }
+}
+
+#[doc(hidden)]
+#[repr(C, align(4))]
+pub struct Align4<T: ?Sized>(pub T);
+
+/// Includes a SPIR-V file as a `&'static [u32]`.
+///
+/// The path is resolved like `include_bytes!`. Compilation fails if the file isn't a multiple
+/// of 4 bytes, is too short for the header, or doesn't start with the SPIR-V magic number in
+/// the byte order of the target.
+#[macro_export]
+macro_rules! include_spirv {
+    ($path:expr) => {{
+        const BYTES: &[u8] = include_bytes!($path);
+        const _: () = assert!(
+            BYTES.len() % 4 == 0 && BYTES.len() >= 20,
+            concat!(
+                $path,
+                " isn't SPIR-V: the length must be a multiple of 4 and at least 20"
+            )
+        );
+        const _: () = assert!(
+            u32::from_ne_bytes([BYTES[0], BYTES[1], BYTES[2], BYTES[3]]) == $crate::spirv::MAGIC,
+            concat!(
+                $path,
+                " isn't SPIR-V in the byte order of the target: wrong magic number"
+            )
+        );
+        static ALIGNED: &$crate::spirv::Align4<[u8]> =
+            &$crate::spirv::Align4(*include_bytes!($path));
+        let words: &'static [u32] = unsafe {
+            ::std::slice::from_raw_parts(ALIGNED.0.as_ptr() as *const u32, BYTES.len() / 4)
+        };
+        words
+    }};
+}
+
+#[test]
+fn source_checks_header_and_byte_order() {
+    // A module with only a header is the shortest one accepted.
+    let header = [MAGIC, 0x0001_0000, 0, 1, 0];
+    let header_bytes: Vec<u8> = header
+        .iter()
+        .flat_map(|w| w.to_ne_bytes().to_vec())
+        .collect();
+    assert_eq!(
+        &header,
+        ShaderSource::from_bytes(&header_bytes).unwrap().words()
+    );
+
+    let words = crate::include_spirv!("../examples/triangle.vert.spv");
+    assert_eq!(MAGIC, words[0]);
+
+    let little_endian: Vec<u8> = words
+        .iter()
+        .flat_map(|w| w.to_le_bytes().to_vec())
+        .collect();
+    let big_endian: Vec<u8> = words
+        .iter()
+        .flat_map(|w| w.to_be_bytes().to_vec())
+        .collect();
+    assert_eq!(
+        words,
+        ShaderSource::from_bytes(&little_endian).unwrap().words()
+    );
+    assert_eq!(
+        words,
+        ShaderSource::from_bytes(&big_endian).unwrap().words()
+    );
+    let swapped = words.iter().map(|w| w.swap_bytes()).collect();
+    assert_eq!(words, &*ShaderSource::from_words(swapped).unwrap());
+
+    match ShaderSource::from_bytes(&little_endian[..little_endian.len() - 2]) {
+        Err(SpirvError::InvalidLength(len)) => assert_eq!(little_endian.len() - 2, len),
+        other => panic!("{:?}", other),
+    }
+    match ShaderSource::from_bytes(b"#version 450\nvoid main() {}\n") {
+        Err(SpirvError::InvalidMagic(_)) => {}
+        other => panic!("{:?}", other),
+    }
+}
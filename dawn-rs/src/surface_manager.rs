@@ -0,0 +1,394 @@
+//! Owns a swap chain together with the attachments that have to match its size.
+//!
+//! `SurfaceManager::resize` only records the new size. The swap chain is reconfigured and the
+//! depth and multisampled color textures are recreated by the next `next_frame`, so resize
+//! events that arrive in bursts while a window is dragged cost a single reconfiguration.
+//!
+//! ```no_run
+//! # fn run(device: &dawn::Device, swap_chain: dawn::SwapChain) {
+//! use dawn::surface_manager::{SurfaceManager, SurfaceManagerDescriptor};
+//!
+//! let mut surface = SurfaceManager::new(
+//!     device,
+//!     swap_chain,
+//!     &SurfaceManagerDescriptor {
+//!         depth_format: Some(dawn::TextureFormat::Depth24PlusStencil8),
+//!         sample_count: 4,
+//!         ..SurfaceManagerDescriptor::new(640, 480)
+//!     },
+//! );
+//! // On a window resize event:
+//! surface.resize(800, 600);
+//! // Each frame:
+//! let frame = surface.next_frame();
+//! let (attachment, resolve_target) = frame.color_attachment();
+//! // ... record a render pass into `attachment` and `frame.depth_view` ...
+//! surface.present();
+//! # }
+//! ```
+
+use std::sync::atomic::Ordering;
+
+use crate::{
+    Device, Extent3d, PresentMode, Surface, SwapChain, SwapChainDescriptor, Texture, TextureAspect,
+    TextureDescriptor, TextureDimension, TextureFormat, TextureUsage, TextureView,
+    TextureViewDescriptor, TextureViewDimension,
+};
+
+#[derive(Debug, Copy, Clone)]
+pub struct SurfaceManagerDescriptor {
+    /// The swap chain format. Swap chains that report a preferred format can only be
+    /// configured with that format, and swap chains that present to a surface only support
+    /// `BGRA8Unorm`, so other formats fall back to those. `None` uses the format preferred by
+    /// the swap chain, or `BGRA8Unorm` if it doesn't report one. `SurfaceManager::format`
+    /// returns the format that was configured.
+    pub format: Option<TextureFormat>,
+    pub usage: TextureUsage,
+    /// Only used by `SurfaceManager::with_surface`. Other swap chains keep the way they were
+    /// created to present.
+    pub present_mode: PresentMode,
+    pub width: u32,
+    pub height: u32,
+    /// Creates a depth texture of this format that matches the swap chain size.
+    pub depth_format: Option<TextureFormat>,
+    /// Creates a multisampled color texture that resolves into the swap chain if greater
+    /// than 1. The depth texture uses the same sample count.
+    pub sample_count: u32,
+}
+
+impl SurfaceManagerDescriptor {
+    pub fn new(width: u32, height: u32) -> SurfaceManagerDescriptor {
+        SurfaceManagerDescriptor {
+            format: None,
+            usage: TextureUsage::OUTPUT_ATTACHMENT | TextureUsage::PRESENT,
+            present_mode: PresentMode::Fifo,
+            width,
+            height,
+            depth_format: None,
+            sample_count: 1,
+        }
+    }
+}
+
+/// The views to render the current frame into.
+pub struct SurfaceFrame<'a> {
+    /// The current swap chain texture.
+    pub view: TextureView,
+    /// The multisampled color texture, if the sample count is greater than 1.
+    pub msaa_view: Option<&'a TextureView>,
+    pub depth_view: Option<&'a TextureView>,
+}
+
+impl<'a> SurfaceFrame<'a> {
+    /// Returns the color attachment and resolve target of a render pass that ends up in the
+    /// swap chain.
+    pub fn color_attachment(&self) -> (&TextureView, Option<&TextureView>) {
+        match self.msaa_view {
+            Some(msaa_view) => (msaa_view, Some(&self.view)),
+            None => (&self.view, None),
+        }
+    }
+}
+
+struct Attachment {
+    texture: Texture,
+    view: TextureView,
+}
+
+/// The only format of swap chains that present to a surface.
+const SURFACE_FORMAT: TextureFormat = TextureFormat::BGRA8Unorm;
+
+pub struct SurfaceManager {
+    device: Device,
+    // Surface based swap chains can't be configured, so they're recreated on resize instead.
+    surface: Option<Surface>,
+    swap_chain: SwapChain,
+    has_frame: bool,
+    format: TextureFormat,
+    usage: TextureUsage,
+    present_mode: PresentMode,
+    width: u32,
+    height: u32,
+    pending_size: Option<(u32, u32)>,
+    depth_format: Option<TextureFormat>,
+    sample_count: u32,
+    depth: Option<Attachment>,
+    msaa: Option<Attachment>,
+}
+
+impl SurfaceManager {
+    /// Takes ownership of `swap_chain` and configures it with the negotiated format. The
+    /// present mode can't be changed after the swap chain is created, so `present_mode` of
+    /// the descriptor is ignored.
+    pub fn new(
+        device: &Device,
+        swap_chain: SwapChain,
+        descriptor: &SurfaceManagerDescriptor,
+    ) -> SurfaceManager {
+        assert!(descriptor.sample_count > 0, "sample_count must not be 0");
+        let format = swap_chain
+            .preferred_format()
+            .or(descriptor.format)
+            .unwrap_or(TextureFormat::BGRA8Unorm);
+        let mut manager = SurfaceManager {
+            device: device.clone(),
+            surface: None,
+            swap_chain,
+            has_frame: false,
+            format,
+            usage: descriptor.usage,
+            present_mode: descriptor.present_mode,
+            width: descriptor.width,
+            height: descriptor.height,
+            pending_size: None,
+            depth_format: descriptor.depth_format,
+            sample_count: descriptor.sample_count,
+            depth: None,
+            msaa: None,
+        };
+        manager.reconfigure();
+        manager
+    }
+
+    /// Creates a swap chain that presents to `surface` with `BGRA8Unorm`, the only format
+    /// supported by surfaces.
+    pub fn with_surface(
+        device: &Device,
+        surface: &Surface,
+        descriptor: &SurfaceManagerDescriptor,
+    ) -> SurfaceManager {
+        assert!(descriptor.sample_count > 0, "sample_count must not be 0");
+        let format = SURFACE_FORMAT;
+        let swap_chain = surface_swap_chain(
+            device,
+            surface,
+            format,
+            descriptor.usage,
+            descriptor.present_mode,
+            descriptor.width,
+            descriptor.height,
+        );
+        let mut manager = SurfaceManager {
+            device: device.clone(),
+            surface: Some(surface.clone()),
+            swap_chain,
+            has_frame: false,
+            format,
+            usage: descriptor.usage,
+            present_mode: descriptor.present_mode,
+            width: descriptor.width,
+            height: descriptor.height,
+            pending_size: None,
+            depth_format: descriptor.depth_format,
+            sample_count: descriptor.sample_count,
+            depth: None,
+            msaa: None,
+        };
+        manager.create_attachments();
+        manager
+    }
+
+    pub fn swap_chain(&self) -> &SwapChain {
+        &self.swap_chain
+    }
+
+    pub fn surface(&self) -> Option<&Surface> {
+        self.surface.as_ref()
+    }
+
+    /// The negotiated swap chain format.
+    pub fn format(&self) -> TextureFormat {
+        self.format
+    }
+
+    /// The present mode of the swap chain. `None` if its implementation decides how frames
+    /// are presented, see `SwapChain::present_mode`.
+    pub fn present_mode(&self) -> Option<PresentMode> {
+        self.swap_chain.present_mode()
+    }
+
+    pub fn depth_format(&self) -> Option<TextureFormat> {
+        self.depth_format
+    }
+
+    pub fn sample_count(&self) -> u32 {
+        self.sample_count
+    }
+
+    pub fn depth_texture(&self) -> Option<&Texture> {
+        self.depth.as_ref().map(|depth| &depth.texture)
+    }
+
+    /// The size the swap chain is configured with. A pending resize is applied by the next
+    /// `next_frame`.
+    pub fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Records a new size. Zero sized surfaces, e.g. of minimized windows, keep the previous
+    /// configuration.
+    pub fn resize(&mut self, width: u32, height: u32) {
+        if width == 0 || height == 0 {
+            return;
+        }
+        if (width, height) == (self.width, self.height) {
+            self.pending_size = None;
+        } else {
+            self.pending_size = Some((width, height));
+        }
+    }
+
+    /// Applies a pending resize and returns the views for the next frame.
+    pub fn next_frame(&mut self) -> SurfaceFrame<'_> {
+        if let Some((width, height)) = self.pending_size.take() {
+            self.width = width;
+            self.height = height;
+            self.reconfigure();
+        }
+        self.has_frame = true;
+        SurfaceFrame {
+            view: self.swap_chain.get_current_texture_view(),
+            msaa_view: self.msaa.as_ref().map(|msaa| &msaa.view),
+            depth_view: self.depth.as_ref().map(|depth| &depth.view),
+        }
+    }
+
+    /// Returns `true` if a frame was acquired with `next_frame` and hasn't been presented yet.
+    pub fn has_frame(&self) -> bool {
+        self.has_frame
+    }
+
+    pub fn present(&mut self) {
+        self.has_frame = false;
+        self.swap_chain.present();
+    }
+
+    fn reconfigure(&mut self) {
+        match &self.surface {
+            Some(surface) => {
+                // The new swap chain replaces the old one as the surface's current swap chain.
+                self.swap_chain = surface_swap_chain(
+                    &self.device,
+                    surface,
+                    self.format,
+                    self.usage,
+                    self.present_mode,
+                    self.width,
+                    self.height,
+                );
+            }
+            None => self
+                .swap_chain
+                .configure(self.format, self.usage, self.width, self.height),
+        }
+        self.create_attachments();
+    }
+
+    fn create_attachments(&mut self) {
+        self.depth = None;
+        self.msaa = None;
+        if let Some(depth_format) = self.depth_format {
+            self.depth = Some(self.create_attachment("depth", depth_format));
+        }
+        if self.sample_count > 1 {
+            self.msaa = Some(self.create_attachment("msaa color", self.format));
+        }
+    }
+
+    fn create_attachment(&self, label: &str, format: TextureFormat) -> Attachment {
+        let texture = self.device.create_texture(&TextureDescriptor {
+            label: Some(label),
+            usage: TextureUsage::OUTPUT_ATTACHMENT,
+            dimension: TextureDimension::D2,
+            size: Extent3d {
+                width: self.width,
+                height: self.height,
+                depth: 1,
+            },
+            array_layer_count: 1,
+            format,
+            mip_level_count: 1,
+            sample_count: self.sample_count,
+        });
+        let view = texture.create_view(&TextureViewDescriptor {
+            label: Some(label),
+            format,
+            dimension: TextureViewDimension::D2,
+            base_mip_level: 0,
+            mip_level_count: 1,
+            base_array_layer: 0,
+            array_layer_count: 1,
+            aspect: TextureAspect::All,
+        });
+        Attachment { texture, view }
+    }
+}
+
+fn surface_swap_chain(
+    device: &Device,
+    surface: &Surface,
+    format: TextureFormat,
+    usage: TextureUsage,
+    present_mode: PresentMode,
+    width: u32,
+    height: u32,
+) -> SwapChain {
+    let swap_chain = device.create_swap_chain(
+        Some(surface),
+        &SwapChainDescriptor {
+            label: None,
+            // `PRESENT` is implied for surfaces and isn't accepted in the descriptor.
+            usage: usage - TextureUsage::PRESENT,
+            format,
+            width,
+            height,
+            present_mode,
+            implementation: 0,
+        },
+    );
+    swap_chain.configured.store(true, Ordering::Relaxed);
+    swap_chain
+}
+
+#[cfg(feature = "mock")]
+#[test]
+fn surface_manager_resizes_lazily() {
+    use crate::offscreen::{self, OffscreenSwapChainDescriptor};
+
+    let _guard = crate::mock::install();
+    let device = crate::mock::create_device();
+    let swap_chain = offscreen::create_swap_chain(
+        &device,
+        &OffscreenSwapChainDescriptor {
+            label: None,
+            usage: TextureUsage::OUTPUT_ATTACHMENT,
+            format: TextureFormat::RGBA8Unorm,
+            width: 64,
+            height: 64,
+            image_count: 2,
+        },
+    );
+    let mut surface = SurfaceManager::new(
+        &device,
+        swap_chain,
+        &SurfaceManagerDescriptor {
+            format: Some(TextureFormat::BGRA8Unorm),
+            depth_format: Some(TextureFormat::Depth32Float),
+            sample_count: 4,
+            ..SurfaceManagerDescriptor::new(64, 64)
+        },
+    );
+    // The offscreen swap chain prefers the format it was created with.
+    assert_eq!(TextureFormat::RGBA8Unorm, surface.format());
+    assert_eq!(None, surface.present_mode());
+
+    surface.resize(128, 32);
+    surface.resize(0, 0);
+    assert_eq!((64, 64), surface.size());
+
+    let frame = surface.next_frame();
+    let (_, resolve_target) = frame.color_attachment();
+    assert!(resolve_target.is_some());
+    assert!(frame.depth_view.is_some());
+    assert_eq!((128, 32), surface.size());
+}
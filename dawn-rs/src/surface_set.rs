@@ -0,0 +1,203 @@
+//! Several windows rendered by one device.
+//!
+//! A `SurfaceSet` keeps a `SurfaceManager` per window. Every window has its own size, format,
+//! depth and multisample configuration, but all of them share the device and its queue. The
+//! command buffers of a frame are submitted once by `present_all`, which then presents the
+//! windows in the order they were added.
+//!
+//! ```no_run
+//! # use raw_window_handle::HasRawWindowHandle;
+//! # fn run<W: HasRawWindowHandle>(instance: &dawn::Instance, device: &dawn::Device, windows: &[W]) {
+//! use dawn::surface_manager::SurfaceManagerDescriptor;
+//! use dawn::surface_set::SurfaceSet;
+//!
+//! let mut queue = device.default_queue();
+//! let mut surfaces = SurfaceSet::new(device);
+//! for window in windows {
+//!     let surface = instance.create_surface(window).unwrap();
+//!     surfaces.create(&surface, &SurfaceManagerDescriptor::new(640, 480));
+//! }
+//! let mut encoder = device.create_command_encoder(&Default::default());
+//! for (_, surface) in surfaces.iter_mut() {
+//!     let frame = surface.next_frame();
+//!     // ... record a render pass into `frame.view` with `encoder` ...
+//! }
+//! surfaces.present_all(&mut queue, &[encoder.finish()]);
+//! # }
+//! ```
+
+use crate::surface_manager::{SurfaceManager, SurfaceManagerDescriptor};
+use crate::{CommandBuffer, Device, Queue, Surface};
+
+/// Identifies a window in a `SurfaceSet`. Ids aren't reused after a window is removed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SurfaceId(u64);
+
+pub struct SurfaceSet {
+    device: Device,
+    next_id: u64,
+    surfaces: Vec<(SurfaceId, SurfaceManager)>,
+}
+
+impl SurfaceSet {
+    pub fn new(device: &Device) -> SurfaceSet {
+        SurfaceSet {
+            device: device.clone(),
+            next_id: 0,
+            surfaces: Vec::new(),
+        }
+    }
+
+    pub fn device(&self) -> &Device {
+        &self.device
+    }
+
+    /// Creates a swap chain for `surface` and adds it to the set.
+    pub fn create(
+        &mut self,
+        surface: &Surface,
+        descriptor: &SurfaceManagerDescriptor,
+    ) -> SurfaceId {
+        let manager = SurfaceManager::with_surface(&self.device, surface, descriptor);
+        self.insert(manager)
+    }
+
+    /// Adds a surface manager created from another swap chain, e.g. a native one. It must
+    /// belong to the device of the set.
+    pub fn insert(&mut self, manager: SurfaceManager) -> SurfaceId {
+        let id = SurfaceId(self.next_id);
+        self.next_id += 1;
+        self.surfaces.push((id, manager));
+        id
+    }
+
+    /// Removes a window, e.g. after it has been closed.
+    pub fn remove(&mut self, id: SurfaceId) -> Option<SurfaceManager> {
+        let index = self.surfaces.iter().position(|(other, _)| *other == id)?;
+        Some(self.surfaces.remove(index).1)
+    }
+
+    pub fn get(&self, id: SurfaceId) -> Option<&SurfaceManager> {
+        self.surfaces
+            .iter()
+            .find(|(other, _)| *other == id)
+            .map(|(_, manager)| manager)
+    }
+
+    pub fn get_mut(&mut self, id: SurfaceId) -> Option<&mut SurfaceManager> {
+        self.surfaces
+            .iter_mut()
+            .find(|(other, _)| *other == id)
+            .map(|(_, manager)| manager)
+    }
+
+    /// Records the new size of a window. See `SurfaceManager::resize`.
+    pub fn resize(&mut self, id: SurfaceId, width: u32, height: u32) {
+        if let Some(manager) = self.get_mut(id) {
+            manager.resize(width, height);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.surfaces.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.surfaces.is_empty()
+    }
+
+    /// Iterates the windows in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = (SurfaceId, &SurfaceManager)> {
+        self.surfaces.iter().map(|(id, manager)| (*id, manager))
+    }
+
+    /// Iterates the windows in the order they were added.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (SurfaceId, &mut SurfaceManager)> {
+        self.surfaces.iter_mut().map(|(id, manager)| (*id, manager))
+    }
+
+    /// Submits `commands` and then presents every window that acquired a frame since it was
+    /// last presented, in the order the windows were added. Windows that weren't rendered
+    /// this frame, e.g. minimized ones, are skipped.
+    pub fn present_all(&mut self, queue: &mut Queue, commands: &[CommandBuffer]) {
+        queue.submit(commands);
+        for (_, manager) in &mut self.surfaces {
+            if manager.has_frame() {
+                manager.present();
+            }
+        }
+    }
+}
+
+#[cfg(feature = "mock")]
+#[test]
+fn present_all_submits_once_in_order() {
+    use crate::custom_swap_chain::{NextTexture, SwapChainImplementation};
+    use crate::mock::{self, AsHandle};
+    use crate::{TextureFormat, TextureUsage};
+
+    struct Unused;
+
+    impl SwapChainImplementation for Unused {
+        fn configure(
+            &mut self,
+            _: TextureFormat,
+            _: TextureUsage,
+            _: u32,
+            _: u32,
+        ) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn next_texture(&mut self) -> Result<NextTexture, String> {
+            Ok(NextTexture::U64(0))
+        }
+
+        fn present(&mut self) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    let _guard = mock::install();
+    let device = mock::create_device();
+    let mut queue = device.default_queue();
+    let mut surfaces = SurfaceSet::new(&device);
+    let ids: Vec<_> = (0..3)
+        .map(|_| {
+            let swap_chain = device.create_custom_swap_chain(Unused);
+            surfaces.insert(SurfaceManager::new(
+                &device,
+                swap_chain,
+                &SurfaceManagerDescriptor::new(64, 64),
+            ))
+        })
+        .collect();
+    surfaces.remove(ids[1]);
+    assert!(surfaces.get(ids[1]).is_none());
+
+    for (_, manager) in surfaces.iter_mut() {
+        manager.next_frame();
+    }
+    let expected = vec![
+        ("submit", queue.handle()),
+        (
+            "present",
+            surfaces.get(ids[0]).unwrap().swap_chain().handle(),
+        ),
+        (
+            "present",
+            surfaces.get(ids[2]).unwrap().swap_chain().handle(),
+        ),
+    ];
+    mock::take_calls();
+    surfaces.present_all(&mut queue, &[]);
+    let calls: Vec<_> = mock::take_calls()
+        .into_iter()
+        .map(|call| (call.name, call.receiver))
+        .collect();
+    assert_eq!(expected, calls);
+
+    // Nothing was rendered since the last present.
+    surfaces.present_all(&mut queue, &[]);
+    assert_eq!(1, mock::take_calls().len());
+}
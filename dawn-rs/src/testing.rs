@@ -0,0 +1,530 @@
+//! Helpers for testing rendering code with `cargo test`.
+//!
+//! `TestDevice` creates a device on a headless adapter and collects uncaptured errors, which
+//! fail the test when the device is dropped. `assert_no_errors` and `expect_validation_error`
+//! check the errors raised by a block of calls using error scopes.
+//!
+//! ```no_run
+//! use dawn::testing::TestDevice;
+//! use dawn::BufferUsage;
+//!
+//! let device = TestDevice::new();
+//! let buffer = device.assert_no_errors(|| {
+//!     device.create_buffer_with_data(&[1, 2, 3, 4], BufferUsage::COPY_SRC)
+//! });
+//! assert_eq!(vec![1u8, 2, 3, 4], device.read_buffer::<u8>(&buffer, 0, 4));
+//! ```
+//!
+//! Golden image tests render into an offscreen texture, read it back and compare it with a
+//! PNG checked into the repository. A comparison passes if at most `max_differing_pixels`
+//! pixels differ by more than `tolerance` in any channel. On failure, the actual and expected
+//! images and a diff are written next to the golden file as `<name>.actual.png`,
+//! `<name>.expected.png` and `<name>.diff.png`. Set `DAWN_UPDATE_GOLDEN=1` to write the
+//! rendered images as the new golden files instead.
+//!
+//! ```no_run
+//! use dawn::testing::{self, GoldenOptions};
+//!
+//! #[test]
+//! fn clear_to_red() {
+//!     let device = match testing::headless_device() {
+//!         Some(device) => device,
+//!         None => return,
+//!     };
+//!     let image = testing::render_to_image(&device, 64, 64, |encoder, view| {
+//!         // ... begin a render pass on `view` ...
+//!     });
+//!     testing::assert_golden(&image, "tests/golden/clear_to_red.png", &GoldenOptions::default());
+//! }
+//! ```
+
+use std::convert::TryFrom;
+use std::env;
+use std::mem;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::ptr;
+use std::sync::Arc;
+use std::thread;
+
+use parking_lot::Mutex;
+
+use dawn_sys as sys;
+
+use crate::capture::{self, Image};
+use crate::{
+    AdapterType, BackendType, Buffer, BufferUsage, CommandEncoder, CommandEncoderDescriptor,
+    Device, ErrorFilter, ErrorType, Extent3d, Instance, TextureAspect, TextureDescriptor,
+    TextureDimension, TextureFormat, TextureUsage, TextureView, TextureViewDescriptor,
+    TextureViewDimension,
+};
+
+/// Creates a device on an adapter that doesn't need a GPU: a CPU adapter such as SwiftShader
+/// if one is available, the null backend otherwise. Returns `None` if neither exists.
+///
+/// The null backend doesn't execute commands, so golden image tests should only compare
+/// images rendered on a CPU adapter. See `is_null_backend`.
+pub fn headless_device() -> Option<Device> {
+    let instance = Instance::new();
+    let adapters = instance.enumerate_adapters();
+    let adapter = adapters
+        .iter()
+        .find(|adapter| adapter.properties().adapter_type == AdapterType::CPU)
+        .or_else(|| {
+            adapters
+                .iter()
+                .find(|adapter| adapter.properties().backend_type == BackendType::Null)
+        })?;
+    Some(adapter.create_device(&Default::default()))
+}
+
+/// Returns `true` if `device` uses the null backend, which doesn't produce pixels.
+pub fn is_null_backend(device: &Device) -> bool {
+    device.inner.lock().backend_type == BackendType::Null
+}
+
+/// Renders into a `width` x `height` `RGBA8Unorm` texture and reads it back. `render` records
+/// commands into the encoder, which is submitted afterwards.
+pub fn render_to_image<F>(device: &Device, width: u32, height: u32, render: F) -> Image
+where
+    F: FnOnce(&mut CommandEncoder, &TextureView),
+{
+    let texture = device.create_texture(&TextureDescriptor {
+        label: Some("render_to_image"),
+        usage: TextureUsage::OUTPUT_ATTACHMENT | TextureUsage::COPY_SRC,
+        dimension: TextureDimension::D2,
+        size: Extent3d {
+            width,
+            height,
+            depth: 1,
+        },
+        array_layer_count: 1,
+        format: TextureFormat::RGBA8Unorm,
+        mip_level_count: 1,
+        sample_count: 1,
+    });
+    let view = texture.create_view(&TextureViewDescriptor {
+        label: None,
+        format: TextureFormat::RGBA8Unorm,
+        dimension: TextureViewDimension::D2,
+        base_mip_level: 0,
+        mip_level_count: 1,
+        base_array_layer: 0,
+        array_layer_count: 1,
+        aspect: TextureAspect::All,
+    });
+    let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor { label: None });
+    render(&mut encoder, &view);
+    device.default_queue().submit(&[encoder.finish()]);
+    texture
+        .read_to_image(0, 0)
+        .unwrap_or_else(|err| panic!("failed to read back rendered image: {}", err))
+}
+
+/// An error reported on a device.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceError {
+    pub error_type: ErrorType,
+    pub message: String,
+}
+
+type ErrorLog = Mutex<Vec<DeviceError>>;
+
+/// A device for tests. Uncaptured errors are collected instead of being printed, and dropping
+/// the device panics if any were reported and not taken with `take_errors`. Derefs to `Device`.
+pub struct TestDevice {
+    device: Device,
+    // Boxed so that the address passed to the error callback stays valid.
+    errors: Box<ErrorLog>,
+}
+
+impl TestDevice {
+    /// Creates a device on the adapter chosen by `headless_device`. Panics if there is none.
+    pub fn new() -> TestDevice {
+        TestDevice::try_new().expect("no CPU or null adapter available")
+    }
+
+    /// Like `new`, but returns `None` instead of panicking so that tests can be skipped on
+    /// machines without a headless adapter.
+    pub fn try_new() -> Option<TestDevice> {
+        headless_device().map(TestDevice::from_device)
+    }
+
+    /// Wraps an existing device and replaces its uncaptured error callback.
+    pub fn from_device(device: Device) -> TestDevice {
+        let errors: Box<ErrorLog> = Box::new(Mutex::new(Vec::new()));
+        let guard = device.inner.lock();
+        unsafe {
+            sys::wgpuDeviceSetUncapturedErrorCallback(
+                guard.raw,
+                Some(uncaptured_error_callback),
+                &*errors as *const ErrorLog as _,
+            );
+        }
+        drop(guard);
+        TestDevice { device, errors }
+    }
+
+    pub fn device(&self) -> &Device {
+        &self.device
+    }
+
+    /// Returns the uncaptured errors reported so far and clears them.
+    pub fn take_errors(&self) -> Vec<DeviceError> {
+        mem::take(&mut *self.errors.lock())
+    }
+
+    /// Runs `f` and panics if it raised any validation or out of memory error, or if there
+    /// are uncaptured errors from earlier calls.
+    pub fn assert_no_errors<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        self.device.push_error_scope(ErrorFilter::OutOfMemory);
+        self.device.push_error_scope(ErrorFilter::Validation);
+        let result = f();
+        let validation_error = self.pop_error_scope();
+        let out_of_memory_error = self.pop_error_scope();
+        if let Some(error) = validation_error.or(out_of_memory_error) {
+            panic!("unexpected {:?} error: {}", error.error_type, error.message);
+        }
+        self.assert_no_uncaptured_errors();
+        result
+    }
+
+    /// Runs `f` and panics unless it raised a validation error. Returns the result of `f` and
+    /// the error message.
+    pub fn expect_validation_error<F, R>(&self, f: F) -> (R, String)
+    where
+        F: FnOnce() -> R,
+    {
+        self.device.push_error_scope(ErrorFilter::Validation);
+        let result = f();
+        match self.pop_error_scope() {
+            Some(error) => (result, error.message),
+            None => panic!("expected a validation error"),
+        }
+    }
+
+    /// Copies `count` values of type `T` starting at byte `offset` out of `buffer`, which must
+    /// have been created with `BufferUsage::COPY_SRC`.
+    ///
+    /// Buffer copies must be 4 byte aligned, so the copied range is widened to multiples of 4
+    /// bytes, which `buffer` must contain.
+    pub fn read_buffer<T: Plain>(&self, buffer: &Buffer, offset: usize, count: usize) -> Vec<T> {
+        let size = count * mem::size_of::<T>();
+        let skip = offset % 4;
+        let copy_size = (skip + size + 3) & !3;
+        let staging =
+            self.create_buffer_with_size(copy_size, BufferUsage::MAP_READ | BufferUsage::COPY_DST);
+        let mut encoder = self.create_command_encoder(&CommandEncoderDescriptor { label: None });
+        encoder.copy_buffer_to_buffer(buffer, offset - skip, &staging, 0, copy_size);
+        self.default_queue().submit(&[encoder.finish()]);
+        let data = capture::map_read(&self.device, &staging).expect("failed to map buffer");
+        data[skip..skip + size]
+            .chunks_exact(mem::size_of::<T>())
+            .map(|bytes| unsafe { ptr::read_unaligned(bytes.as_ptr() as *const T) })
+            .collect()
+    }
+
+    /// The number of times Dawn cleared a texture because it was used before being
+    /// initialized. Compare the counts before and after a block of calls to check that it
+    /// didn't rely on, or did rely on, lazy clears.
+    pub fn lazy_clear_count(&self) -> usize {
+        let guard = self.device.inner.lock();
+        unsafe { sys::dawn_native__GetLazyClearCountForTesting(guard.raw) }
+    }
+
+    /// Panics if any uncaptured errors have been reported.
+    pub fn assert_no_uncaptured_errors(&self) {
+        let errors = self.take_errors();
+        if !errors.is_empty() {
+            panic!("uncaptured device errors: {:#?}", errors);
+        }
+    }
+
+    fn pop_error_scope(&self) -> Option<DeviceError> {
+        let result: Arc<Mutex<Option<Option<DeviceError>>>> = Arc::new(Mutex::new(None));
+        let callback_result = result.clone();
+        let popped = self.device.pop_error_scope(move |error_type, message| {
+            let error = match error_type {
+                ErrorType::NoError => None,
+                _ => Some(DeviceError {
+                    error_type,
+                    message: message.to_string(),
+                }),
+            };
+            *callback_result.lock() = Some(error);
+        });
+        assert!(popped, "error scope stack is empty");
+        loop {
+            if let Some(error) = result.lock().take() {
+                return error;
+            }
+            self.device.tick();
+            thread::yield_now();
+        }
+    }
+}
+
+impl Default for TestDevice {
+    fn default() -> TestDevice {
+        TestDevice::new()
+    }
+}
+
+impl Deref for TestDevice {
+    type Target = Device;
+
+    fn deref(&self) -> &Device {
+        &self.device
+    }
+}
+
+impl Drop for TestDevice {
+    fn drop(&mut self) {
+        let guard = self.device.inner.lock();
+        unsafe {
+            sys::wgpuDeviceSetUncapturedErrorCallback(guard.raw, None, ptr::null_mut());
+        }
+        drop(guard);
+        if !thread::panicking() {
+            self.assert_no_uncaptured_errors();
+        }
+    }
+}
+
+unsafe extern "C" fn uncaptured_error_callback(
+    error_type: sys::WGPUErrorType,
+    message: *const libc::c_char,
+    userdata: *mut libc::c_void,
+) {
+    let errors = &*(userdata as *const ErrorLog);
+    let message = if message.is_null() {
+        String::new()
+    } else {
+        std::ffi::CStr::from_ptr(message)
+            .to_string_lossy()
+            .into_owned()
+    };
+    errors.lock().push(DeviceError {
+        error_type: ErrorType::try_from(error_type).unwrap_or(ErrorType::Unknown),
+        message,
+    });
+}
+
+/// Types that can be read from any bytes of the right size.
+///
+/// # Safety
+///
+/// Every bit pattern must be a valid value of the type.
+pub unsafe trait Plain: Copy {}
+
+macro_rules! impl_plain {
+    ($($Type:ty),*) => {
+        $(
+            unsafe impl Plain for $Type {}
+            unsafe impl Plain for [$Type; 2] {}
+            unsafe impl Plain for [$Type; 3] {}
+            unsafe impl Plain for [$Type; 4] {}
+        )*
+    };
+}
+
+impl_plain!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+#[derive(Debug, Copy, Clone)]
+pub struct GoldenOptions {
+    /// The largest difference in any channel for which two pixels are still equal.
+    pub tolerance: u8,
+    /// The number of pixels that may differ by more than `tolerance`.
+    pub max_differing_pixels: usize,
+}
+
+impl Default for GoldenOptions {
+    fn default() -> GoldenOptions {
+        GoldenOptions {
+            tolerance: 2,
+            max_differing_pixels: 0,
+        }
+    }
+}
+
+/// The result of `compare`.
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    /// The number of pixels that differ by more than the tolerance.
+    pub differing_pixels: usize,
+    /// The largest difference in any channel.
+    pub max_difference: u8,
+    /// Differing pixels in red on a dimmed grayscale copy of the actual image.
+    pub diff: Image,
+}
+
+impl Comparison {
+    pub fn passed(&self, options: &GoldenOptions) -> bool {
+        self.differing_pixels <= options.max_differing_pixels
+    }
+}
+
+/// Compares two images of the same size pixel by pixel.
+pub fn compare(actual: &Image, expected: &Image, tolerance: u8) -> Comparison {
+    assert_eq!(
+        (actual.width, actual.height),
+        (expected.width, expected.height),
+        "image sizes differ"
+    );
+    let mut differing_pixels = 0;
+    let mut max_difference = 0;
+    let mut diff = Vec::with_capacity(actual.data.len());
+    for (a, e) in actual.data.chunks(4).zip(expected.data.chunks(4)) {
+        let difference = a
+            .iter()
+            .zip(e)
+            .map(|(&a, &e)| a.max(e) - a.min(e))
+            .max()
+            .unwrap_or(0);
+        max_difference = max_difference.max(difference);
+        if difference > tolerance {
+            differing_pixels += 1;
+            diff.extend_from_slice(&[255, 0, 0, 255]);
+        } else {
+            let luma = (u32::from(a[0]) * 77 + u32::from(a[1]) * 150 + u32::from(a[2]) * 29) >> 8;
+            let dimmed = (luma / 4) as u8;
+            diff.extend_from_slice(&[dimmed, dimmed, dimmed, 255]);
+        }
+    }
+    Comparison {
+        differing_pixels,
+        max_difference,
+        diff: Image {
+            width: actual.width,
+            height: actual.height,
+            data: diff,
+        },
+    }
+}
+
+/// Compares `actual` with the PNG at `golden_path` and panics if they differ. See the module
+/// documentation for the files written on failure.
+pub fn assert_golden<P: AsRef<Path>>(actual: &Image, golden_path: P, options: &GoldenOptions) {
+    let golden_path = golden_path.as_ref();
+    if env::var("DAWN_UPDATE_GOLDEN").ok().as_deref() == Some("1") {
+        if let Some(parent) = golden_path.parent() {
+            std::fs::create_dir_all(parent).unwrap();
+        }
+        actual
+            .save_png(golden_path)
+            .unwrap_or_else(|err| panic!("failed to write {}: {}", golden_path.display(), err));
+        return;
+    }
+
+    let expected = match std::fs::read(golden_path) {
+        Ok(png_data) => Image::decode_png(&png_data)
+            .unwrap_or_else(|err| panic!("failed to decode {}: {}", golden_path.display(), err)),
+        Err(err) => {
+            let actual_path = output_path(golden_path, "actual");
+            let _ = actual.save_png(&actual_path);
+            panic!(
+                "failed to read golden image {}: {}. The rendered image was written to {}, \
+                 run with DAWN_UPDATE_GOLDEN=1 to accept it.",
+                golden_path.display(),
+                err,
+                actual_path.display()
+            );
+        }
+    };
+
+    if (actual.width, actual.height) != (expected.width, expected.height) {
+        let _ = actual.save_png(output_path(golden_path, "actual"));
+        panic!(
+            "{}: rendered {}x{}, expected {}x{}",
+            golden_path.display(),
+            actual.width,
+            actual.height,
+            expected.width,
+            expected.height
+        );
+    }
+
+    let comparison = compare(actual, &expected, options.tolerance);
+    if !comparison.passed(options) {
+        let actual_path = output_path(golden_path, "actual");
+        let _ = actual.save_png(&actual_path);
+        let _ = expected.save_png(output_path(golden_path, "expected"));
+        let _ = comparison.diff.save_png(output_path(golden_path, "diff"));
+        panic!(
+            "{}: {} pixels differ by up to {} (tolerance {}, {} differing pixels allowed), \
+             see {}",
+            golden_path.display(),
+            comparison.differing_pixels,
+            comparison.max_difference,
+            options.tolerance,
+            options.max_differing_pixels,
+            actual_path.display()
+        );
+    }
+}
+
+/// `tests/golden/name.png` -> `tests/golden/name.<suffix>.png`
+fn output_path(golden_path: &Path, suffix: &str) -> PathBuf {
+    let stem = golden_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    golden_path.with_file_name(format!("{}.{}.png", stem, suffix))
+}
+
+#[test]
+fn compare_counts_pixels_outside_tolerance() {
+    let expected = Image {
+        width: 3,
+        height: 1,
+        data: vec![10, 10, 10, 255, 10, 10, 10, 255, 10, 10, 10, 255],
+    };
+    let actual = Image {
+        width: 3,
+        height: 1,
+        data: vec![10, 10, 10, 255, 12, 10, 10, 255, 10, 10, 60, 255],
+    };
+    let comparison = compare(&actual, &expected, 2);
+    assert_eq!(1, comparison.differing_pixels);
+    assert_eq!(50, comparison.max_difference);
+    assert_eq!([255, 0, 0, 255], comparison.diff.pixel(2, 0));
+    assert!(!comparison.passed(&GoldenOptions::default()));
+    assert!(comparison.passed(&GoldenOptions {
+        tolerance: 2,
+        max_differing_pixels: 1,
+    }));
+    assert_eq!(
+        Path::new("golden/a.diff.png"),
+        output_path(Path::new("golden/a.png"), "diff")
+    );
+}
+
+#[cfg(feature = "mock")]
+#[test]
+fn test_device_error_scopes() {
+    let _mock = crate::mock::install();
+    let device = TestDevice::from_device(crate::mock::create_device());
+
+    let (value, message) = device.expect_validation_error(|| {
+        device.inject_error("bad binding", ErrorType::Validation);
+        7
+    });
+    assert_eq!((7, "bad binding"), (value, message.as_str()));
+    device.assert_no_errors(|| ());
+
+    device.inject_error("uncaptured", ErrorType::Validation);
+    assert_eq!(
+        vec![DeviceError {
+            error_type: ErrorType::Validation,
+            message: "uncaptured".to_string(),
+        }],
+        device.take_errors()
+    );
+
+    let buffer = device.create_buffer_with_data(&[1, 0, 2, 0, 3, 0, 4, 0], BufferUsage::COPY_SRC);
+    assert_eq!(vec![2u16, 3], device.read_buffer::<u16>(&buffer, 2, 2));
+    assert_eq!(vec![1u8, 0, 2], device.read_buffer::<u8>(&buffer, 0, 3));
+}
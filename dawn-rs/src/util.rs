@@ -1,5 +1,7 @@
 use std::{mem, ptr};
 
+/// Copies `code` into words, padding it with zeros to a multiple of 4 bytes. The code isn't
+/// checked; see `spirv::ShaderSource` and `include_spirv!`.
 pub fn spirv(code: &[u8]) -> Vec<u32> {
     let byte_count = code.len();
     let word_extra = if byte_count % 4 > 0 { 1 } else { 0 };
@@ -0,0 +1,1637 @@
+//! Run on another `webgpu.h` implementation, such as wgpu-native, loaded at runtime.
+//!
+//! The rest of the crate is written against Dawn's `webgpu.h`, which differs from the official
+//! one from webgpu-headers in struct layouts, enum values and entry points. Installing a library
+//! resolves its entry points with `dawn_sys::webgpu_native::load_procs` and installs a proc table
+//! of adapters in front of them:
+//!
+//! * Descriptors are translated to the official structs, e.g. `rowPitch` moves into the
+//!   `WGPUTextureDataLayout` of a `WGPUBufferCopyView`, texture array layers become the depth of
+//!   the texture size and colors are widened to `double`.
+//! * Enum values are translated where they differ: texture formats, compare functions, index
+//!   formats, binding types and map statuses. The others have the same values in both headers.
+//! * Dawn-only entry points are emulated. `bufferSetSubData` writes through the device's default
+//!   queue, `bufferMapReadAsync` and `bufferMapWriteAsync` map the whole buffer with
+//!   `wgpuBufferMapAsync`, `deviceCreateBufferMapped` creates the buffer mapped,
+//!   `deviceInjectError` calls the uncaptured error callback (error scopes don't see it) and
+//!   `deviceTick` does nothing.
+//! * Entry points without an equivalent (`getProcAddress`, `deviceCreateBufferMappedAsync`,
+//!   `deviceLoseForTesting` and `swapChainConfigure`) are left `None` and must not be used.
+//!   So are entry points whose `webgpu.h` counterparts the library doesn't export. Missing
+//!   `*Reference` and `*Release` functions are replaced with no-ops.
+//!
+//! The `dawn_native` adapter discovery isn't available either. `Instance::enumerate_adapters`
+//! requests an adapter with `wgpuInstanceRequestAdapter` and devices are created with
+//! `wgpuAdapterRequestDevice`. Both must invoke their callback before returning. Libraries that
+//! don't export them can name their own device creation function in `create_device_symbol`.
+//!
+//! ```no_run
+//! use dawn::webgpu_native::{self, WebGpuNativeDescriptor};
+//! use dawn::BackendType;
+//!
+//! unsafe {
+//!     webgpu_native::install(&WebGpuNativeDescriptor {
+//!         path: "libwgpu_native.so".as_ref(),
+//!         create_device_symbol: None,
+//!         backend_type: BackendType::Vulkan,
+//!     })
+//!     .unwrap();
+//! }
+//! let instance = dawn::Instance::new();
+//! let adapter = &instance.enumerate_adapters()[0];
+//! let device = adapter.create_device(&Default::default());
+//! ```
+
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::path::Path;
+use std::sync::atomic::{AtomicPtr, Ordering};
+use std::{fmt, mem, ptr, slice};
+
+use libc::c_void;
+use parking_lot::Mutex;
+
+use dawn_sys as sys;
+use dawn_sys::webgpu_native::{webgpu as w, WebGpuProcTable};
+
+use crate::{convert, set_dawn_proc_table, AdapterProperties, AdapterType, BackendType};
+
+/// Creates a device on a `webgpu.h` implementation without `wgpuAdapterRequestDevice`.
+/// `descriptor` carries the required extensions and toggles from `DeviceDescriptor`;
+/// implementations may ignore the toggles. The returned device must have the official
+/// `webgpu.h` ABI.
+pub type CreateDeviceProc = unsafe extern "C" fn(
+    instance: sys::WGPUInstance,
+    descriptor: *const sys::DeviceDescriptor,
+) -> sys::WGPUDevice;
+
+#[derive(Debug, Clone)]
+pub struct WebGpuNativeDescriptor<'a> {
+    /// Path to the shared library.
+    pub path: &'a Path,
+    /// Exported symbol with the `CreateDeviceProc` signature, used instead of
+    /// `wgpuInstanceRequestAdapter` and `wgpuAdapterRequestDevice`.
+    pub create_device_symbol: Option<&'a str>,
+    /// The backend reported in the adapter properties if the library doesn't export
+    /// `wgpuAdapterGetProperties`.
+    pub backend_type: BackendType,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// The library couldn't be loaded.
+    Load(libloading::Error),
+    /// A required entry point isn't exported by the library.
+    MissingEntryPoint(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Load(err) => write!(f, "failed to load webgpu library: {}", err),
+            Error::MissingEntryPoint(name) => write!(f, "missing entry point: {}", name),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+struct WebGpuNative {
+    // Never unloaded; handles and the proc table point into it for the rest of the process.
+    _library: libloading::Library,
+    create_device: Option<CreateDeviceProc>,
+    adapter: w::WGPUAdapter,
+    name: String,
+    backend_type: BackendType,
+}
+
+unsafe impl Send for WebGpuNative {}
+
+static STATE: Mutex<Option<WebGpuNative>> = parking_lot::const_mutex(None);
+
+/// The library's entry points. Set once by `install` and never freed, since the installed proc
+/// table calls through it.
+static PROCS: AtomicPtr<WebGpuProcTable> = AtomicPtr::new(ptr::null_mut());
+
+fn procs() -> &'static WebGpuProcTable {
+    let procs = PROCS.load(Ordering::Acquire);
+    assert!(!procs.is_null(), "no webgpu.h implementation is installed");
+    unsafe { &*procs }
+}
+
+/// Loads a `webgpu.h` implementation and installs it, behind the adapters described in the
+/// module documentation, as the global proc table.
+///
+/// # Safety
+///
+/// The library must implement the official `webgpu.h` ABI of the bindings in
+/// `dawn_sys::webgpu_native::webgpu`. This must be called at most once, before
+/// `Instance::new()` and before any object is created with another proc table.
+pub unsafe fn install(descriptor: &WebGpuNativeDescriptor) -> Result<(), Error> {
+    let library = libloading::Library::new(descriptor.path).map_err(Error::Load)?;
+
+    let procs = sys::webgpu_native::load_procs(|name| {
+        library
+            .get::<*const c_void>(name.to_bytes_with_nul())
+            .map(|symbol| *symbol)
+            .unwrap_or(ptr::null())
+    });
+    if procs.createInstance.is_none() {
+        return Err(Error::MissingEntryPoint("wgpuCreateInstance".to_string()));
+    }
+    if procs.deviceGetDefaultQueue.is_none() {
+        return Err(Error::MissingEntryPoint(
+            "wgpuDeviceGetDefaultQueue".to_string(),
+        ));
+    }
+
+    let create_device = match descriptor.create_device_symbol {
+        Some(symbol) => {
+            let symbol_name = CString::new(symbol).unwrap();
+            let create_device = *library
+                .get::<CreateDeviceProc>(symbol_name.as_bytes_with_nul())
+                .map_err(|_| Error::MissingEntryPoint(symbol.to_string()))?;
+            Some(create_device)
+        }
+        None => None,
+    };
+
+    let name = descriptor
+        .path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    *STATE.lock() = Some(WebGpuNative {
+        _library: library,
+        create_device,
+        adapter: ptr::null_mut(),
+        name,
+        backend_type: descriptor.backend_type,
+    });
+    set_dawn_proc_table(proc_table(procs));
+
+    Ok(())
+}
+
+/// Returns `true` if a `webgpu.h` implementation has been installed.
+pub fn is_installed() -> bool {
+    STATE.lock().is_some()
+}
+
+/// Requests the adapter that `Adapter`s with index `0` refer to, returning the number of
+/// adapters: `0` or `1`.
+pub(crate) unsafe fn request_adapter(instance: sys::WGPUInstance) -> usize {
+    unsafe extern "C" fn callback(adapter: w::WGPUAdapter, userdata: *mut c_void) {
+        *(userdata as *mut w::WGPUAdapter) = adapter;
+    }
+
+    let mut guard = STATE.lock();
+    let state = match guard.as_mut() {
+        Some(state) => state,
+        None => return 0,
+    };
+    if state.adapter.is_null() {
+        if let Some(request_adapter) = procs().instanceRequestAdapter {
+            let options = w::WGPURequestAdapterOptions {
+                nextInChain: ptr::null(),
+                compatibleSurface: ptr::null_mut(),
+            };
+            request_adapter(
+                instance as _,
+                &options,
+                Some(callback),
+                &mut state.adapter as *mut _ as _,
+            );
+        }
+    }
+    if !state.adapter.is_null() || state.create_device.is_some() {
+        1
+    } else {
+        0
+    }
+}
+
+pub(crate) fn adapter_properties() -> Option<AdapterProperties> {
+    let guard = STATE.lock();
+    let state = guard.as_ref()?;
+    let get_properties = procs().adapterGetProperties;
+    if let (false, Some(get_properties)) = (state.adapter.is_null(), get_properties) {
+        unsafe {
+            let mut raw: w::WGPUAdapterProperties = mem::zeroed();
+            get_properties(state.adapter, &mut raw);
+            let name = if raw.name.is_null() {
+                state.name.clone()
+            } else {
+                CStr::from_ptr(raw.name).to_string_lossy().to_string()
+            };
+            // The adapter and backend types have the same values in both headers.
+            return Some(AdapterProperties {
+                name,
+                vendor_id: raw.vendorID,
+                device_id: raw.deviceID,
+                adapter_type: convert::adapter_type(raw.adapterType),
+                backend_type: convert::backend_type(raw.backendType),
+            });
+        }
+    }
+    Some(AdapterProperties {
+        name: state.name.clone(),
+        adapter_type: AdapterType::Unknown,
+        backend_type: state.backend_type,
+        vendor_id: 0,
+        device_id: 0,
+    })
+}
+
+/// Creates a device with the installed library, or returns `None` if none is installed. The
+/// device is null if creation failed.
+pub(crate) unsafe fn create_device(
+    instance: sys::WGPUInstance,
+    descriptor: &sys::DeviceDescriptor,
+) -> Option<sys::WGPUDevice> {
+    unsafe extern "C" fn callback(device: w::WGPUDevice, userdata: *mut c_void) {
+        *(userdata as *mut w::WGPUDevice) = device;
+    }
+
+    let (create_device, adapter) = {
+        let guard = STATE.lock();
+        let state = guard.as_ref()?;
+        (state.create_device, state.adapter)
+    };
+    let device = match (create_device, procs().adapterRequestDevice) {
+        (Some(create_device), _) => create_device(instance, descriptor),
+        (None, Some(request_device)) if !adapter.is_null() => {
+            let raw_descriptor = w::WGPUDeviceDescriptor {
+                nextInChain: ptr::null(),
+            };
+            let mut device: w::WGPUDevice = ptr::null_mut();
+            request_device(
+                adapter,
+                &raw_descriptor,
+                Some(callback),
+                &mut device as *mut _ as _,
+            );
+            device as sys::WGPUDevice
+        }
+        _ => ptr::null_mut(),
+    };
+    if !device.is_null() {
+        with_tracking(|tracking| {
+            tracking.devices.insert(
+                device as usize,
+                Tracked::new(DeviceInfo {
+                    queue: ptr::null_mut(),
+                    error_callback: None,
+                    error_userdata: ptr::null_mut(),
+                }),
+            );
+        });
+    }
+    Some(device)
+}
+
+/// The state that Dawn-only entry points are emulated with.
+#[derive(Default)]
+struct Tracking {
+    buffers: HashMap<usize, Tracked<BufferInfo>>,
+    devices: HashMap<usize, Tracked<DeviceInfo>>,
+}
+
+unsafe impl Send for Tracking {}
+
+struct Tracked<T> {
+    refs: usize,
+    info: T,
+}
+
+impl<T> Tracked<T> {
+    fn new(info: T) -> Tracked<T> {
+        Tracked { refs: 1, info }
+    }
+}
+
+struct BufferInfo {
+    device: sys::WGPUDevice,
+    size: u64,
+}
+
+struct DeviceInfo {
+    /// The default queue used by `bufferSetSubData`, requested on first use.
+    queue: w::WGPUQueue,
+    error_callback: sys::WGPUErrorCallback,
+    error_userdata: *mut c_void,
+}
+
+static TRACKING: Mutex<Option<Tracking>> = parking_lot::const_mutex(None);
+
+fn with_tracking<R>(f: impl FnOnce(&mut Tracking) -> R) -> R {
+    f(TRACKING.lock().get_or_insert_with(Tracking::default))
+}
+
+fn reference<T>(objects: &mut HashMap<usize, Tracked<T>>, handle: usize) {
+    if let Some(object) = objects.get_mut(&handle) {
+        object.refs += 1;
+    }
+}
+
+/// Drops a reference, returning the object once the last one is gone.
+fn release<T>(objects: &mut HashMap<usize, Tracked<T>>, handle: usize) -> Option<T> {
+    let object = objects.get_mut(&handle)?;
+    object.refs -= 1;
+    if object.refs == 0 {
+        objects.remove(&handle).map(|object| object.info)
+    } else {
+        None
+    }
+}
+
+/// Builds the adapting proc table and makes `procs` the target of its entries.
+unsafe fn proc_table(procs: WebGpuProcTable) -> sys::DawnProcTable {
+    let procs = Box::into_raw(Box::new(procs));
+    PROCS.store(procs, Ordering::Release);
+    let procs = &*procs;
+
+    let mut table: sys::DawnProcTable = mem::zeroed();
+
+    // These entry points take the same handles and scalars in both headers, so the library's
+    // functions are installed directly. A `None` stays `None`.
+    macro_rules! forward {
+        ($($field:ident => $proc:ident,)*) => {
+            $(
+                table.$field = cast_proc(procs.$proc);
+            )*
+        };
+    }
+
+    // `wgpu-native` and others don't reference count through `webgpu.h`; without the library's
+    // functions, references are ignored.
+    macro_rules! forward_or_ignore {
+        ($($field:ident => $proc:ident,)*) => {
+            $(
+                table.$field = match procs.$proc {
+                    Some(_) => cast_proc(procs.$proc),
+                    None => cast_proc(Some(ignore as unsafe extern "C" fn(*mut c_void))),
+                };
+            )*
+        };
+    }
+
+    // These entry points are translated. Each is installed only if the library exports every
+    // function that its translation calls.
+    macro_rules! translate {
+        ($($field:ident => $shim:ident if $($proc:ident)&&*,)*) => {
+            $(
+                if $(procs.$proc.is_some())&&* {
+                    table.$field = Some($shim);
+                }
+            )*
+        };
+    }
+
+    #[rustfmt::skip]
+    forward! {
+        bufferDestroy => bufferDestroy,
+        bufferUnmap => bufferUnmap,
+        commandEncoderCopyBufferToBuffer => commandEncoderCopyBufferToBuffer,
+        commandEncoderInsertDebugMarker => commandEncoderInsertDebugMarker,
+        commandEncoderPopDebugGroup => commandEncoderPopDebugGroup,
+        commandEncoderPushDebugGroup => commandEncoderPushDebugGroup,
+        computePassEncoderDispatch => computePassEncoderDispatch,
+        computePassEncoderDispatchIndirect => computePassEncoderDispatchIndirect,
+        computePassEncoderEndPass => computePassEncoderEndPass,
+        computePassEncoderInsertDebugMarker => computePassEncoderInsertDebugMarker,
+        computePassEncoderPopDebugGroup => computePassEncoderPopDebugGroup,
+        computePassEncoderPushDebugGroup => computePassEncoderPushDebugGroup,
+        computePassEncoderSetBindGroup => computePassEncoderSetBindGroup,
+        computePassEncoderSetPipeline => computePassEncoderSetPipeline,
+        computePipelineGetBindGroupLayout => computePipelineGetBindGroupLayout,
+        deviceCreateQueue => deviceGetDefaultQueue,
+        devicePopErrorScope => devicePopErrorScope,
+        devicePushErrorScope => devicePushErrorScope,
+        deviceSetDeviceLostCallback => deviceSetDeviceLostCallback,
+        fenceGetCompletedValue => fenceGetCompletedValue,
+        fenceOnCompletion => fenceOnCompletion,
+        queueSignal => queueSignal,
+        queueSubmit => queueSubmit,
+        renderBundleEncoderDraw => renderBundleEncoderDraw,
+        renderBundleEncoderDrawIndexed => renderBundleEncoderDrawIndexed,
+        renderBundleEncoderDrawIndexedIndirect => renderBundleEncoderDrawIndexedIndirect,
+        renderBundleEncoderDrawIndirect => renderBundleEncoderDrawIndirect,
+        renderBundleEncoderInsertDebugMarker => renderBundleEncoderInsertDebugMarker,
+        renderBundleEncoderPopDebugGroup => renderBundleEncoderPopDebugGroup,
+        renderBundleEncoderPushDebugGroup => renderBundleEncoderPushDebugGroup,
+        renderBundleEncoderSetBindGroup => renderBundleEncoderSetBindGroup,
+        renderBundleEncoderSetPipeline => renderBundleEncoderSetPipeline,
+        renderPassEncoderDraw => renderPassEncoderDraw,
+        renderPassEncoderDrawIndexed => renderPassEncoderDrawIndexed,
+        renderPassEncoderDrawIndexedIndirect => renderPassEncoderDrawIndexedIndirect,
+        renderPassEncoderDrawIndirect => renderPassEncoderDrawIndirect,
+        renderPassEncoderEndPass => renderPassEncoderEndPass,
+        renderPassEncoderExecuteBundles => renderPassEncoderExecuteBundles,
+        renderPassEncoderInsertDebugMarker => renderPassEncoderInsertDebugMarker,
+        renderPassEncoderPopDebugGroup => renderPassEncoderPopDebugGroup,
+        renderPassEncoderPushDebugGroup => renderPassEncoderPushDebugGroup,
+        renderPassEncoderSetBindGroup => renderPassEncoderSetBindGroup,
+        renderPassEncoderSetPipeline => renderPassEncoderSetPipeline,
+        renderPassEncoderSetScissorRect => renderPassEncoderSetScissorRect,
+        renderPassEncoderSetStencilReference => renderPassEncoderSetStencilReference,
+        renderPassEncoderSetViewport => renderPassEncoderSetViewport,
+        renderPipelineGetBindGroupLayout => renderPipelineGetBindGroupLayout,
+        swapChainGetCurrentTextureView => swapChainGetCurrentTextureView,
+        swapChainPresent => swapChainPresent,
+        textureDestroy => textureDestroy,
+    }
+
+    #[rustfmt::skip]
+    forward_or_ignore! {
+        bindGroupReference => bindGroupReference,
+        bindGroupRelease => bindGroupRelease,
+        bindGroupLayoutReference => bindGroupLayoutReference,
+        bindGroupLayoutRelease => bindGroupLayoutRelease,
+        commandBufferReference => commandBufferReference,
+        commandBufferRelease => commandBufferRelease,
+        commandEncoderReference => commandEncoderReference,
+        commandEncoderRelease => commandEncoderRelease,
+        computePassEncoderReference => computePassEncoderReference,
+        computePassEncoderRelease => computePassEncoderRelease,
+        computePipelineReference => computePipelineReference,
+        computePipelineRelease => computePipelineRelease,
+        fenceReference => fenceReference,
+        fenceRelease => fenceRelease,
+        instanceReference => instanceReference,
+        instanceRelease => instanceRelease,
+        pipelineLayoutReference => pipelineLayoutReference,
+        pipelineLayoutRelease => pipelineLayoutRelease,
+        queueReference => queueReference,
+        queueRelease => queueRelease,
+        renderBundleReference => renderBundleReference,
+        renderBundleRelease => renderBundleRelease,
+        renderBundleEncoderReference => renderBundleEncoderReference,
+        renderBundleEncoderRelease => renderBundleEncoderRelease,
+        renderPassEncoderReference => renderPassEncoderReference,
+        renderPassEncoderRelease => renderPassEncoderRelease,
+        renderPipelineReference => renderPipelineReference,
+        renderPipelineRelease => renderPipelineRelease,
+        samplerReference => samplerReference,
+        samplerRelease => samplerRelease,
+        shaderModuleReference => shaderModuleReference,
+        shaderModuleRelease => shaderModuleRelease,
+        surfaceReference => surfaceReference,
+        surfaceRelease => surfaceRelease,
+        swapChainReference => swapChainReference,
+        swapChainRelease => swapChainRelease,
+        textureReference => textureReference,
+        textureRelease => textureRelease,
+        textureViewReference => textureViewReference,
+        textureViewRelease => textureViewRelease,
+    }
+
+    #[rustfmt::skip]
+    translate! {
+        createInstance => create_instance if createInstance,
+        bufferMapReadAsync => buffer_map_read_async if bufferMapAsync && bufferGetConstMappedRange,
+        bufferMapWriteAsync => buffer_map_write_async if bufferMapAsync && bufferGetMappedRange,
+        bufferSetSubData => buffer_set_sub_data if queueWriteBuffer,
+        bufferReference => buffer_reference if createInstance,
+        bufferRelease => buffer_release if createInstance,
+        commandEncoderBeginComputePass => command_encoder_begin_compute_pass if commandEncoderBeginComputePass,
+        commandEncoderBeginRenderPass => command_encoder_begin_render_pass if commandEncoderBeginRenderPass,
+        commandEncoderCopyBufferToTexture => command_encoder_copy_buffer_to_texture if commandEncoderCopyBufferToTexture,
+        commandEncoderCopyTextureToBuffer => command_encoder_copy_texture_to_buffer if commandEncoderCopyTextureToBuffer,
+        commandEncoderCopyTextureToTexture => command_encoder_copy_texture_to_texture if commandEncoderCopyTextureToTexture,
+        commandEncoderFinish => command_encoder_finish if commandEncoderFinish,
+        deviceCreateBindGroup => device_create_bind_group if deviceCreateBindGroup,
+        deviceCreateBindGroupLayout => device_create_bind_group_layout if deviceCreateBindGroupLayout,
+        deviceCreateBuffer => device_create_buffer if deviceCreateBuffer,
+        deviceCreateBufferMapped => device_create_buffer_mapped if deviceCreateBuffer && bufferGetMappedRange,
+        deviceCreateCommandEncoder => device_create_command_encoder if deviceCreateCommandEncoder,
+        deviceCreateComputePipeline => device_create_compute_pipeline if deviceCreateComputePipeline,
+        deviceCreatePipelineLayout => device_create_pipeline_layout if deviceCreatePipelineLayout,
+        deviceCreateRenderBundleEncoder => device_create_render_bundle_encoder if deviceCreateRenderBundleEncoder,
+        deviceCreateRenderPipeline => device_create_render_pipeline if deviceCreateRenderPipeline,
+        deviceCreateSampler => device_create_sampler if deviceCreateSampler,
+        deviceCreateShaderModule => device_create_shader_module if deviceCreateShaderModule,
+        deviceCreateSwapChain => device_create_swap_chain if deviceCreateSwapChain,
+        deviceCreateTexture => device_create_texture if deviceCreateTexture,
+        deviceInjectError => device_inject_error if createInstance,
+        deviceSetUncapturedErrorCallback => device_set_uncaptured_error_callback if createInstance,
+        deviceTick => device_tick if createInstance,
+        deviceReference => device_reference if createInstance,
+        deviceRelease => device_release if createInstance,
+        instanceCreateSurface => instance_create_surface if instanceCreateSurface,
+        queueCreateFence => queue_create_fence if queueCreateFence,
+        renderBundleEncoderFinish => render_bundle_encoder_finish if renderBundleEncoderFinish,
+        renderBundleEncoderSetIndexBuffer => render_bundle_encoder_set_index_buffer if renderBundleEncoderSetIndexBuffer,
+        renderBundleEncoderSetVertexBuffer => render_bundle_encoder_set_vertex_buffer if renderBundleEncoderSetVertexBuffer,
+        renderPassEncoderSetBlendColor => render_pass_encoder_set_blend_color if renderPassEncoderSetBlendColor,
+        renderPassEncoderSetIndexBuffer => render_pass_encoder_set_index_buffer if renderPassEncoderSetIndexBuffer,
+        renderPassEncoderSetVertexBuffer => render_pass_encoder_set_vertex_buffer if renderPassEncoderSetVertexBuffer,
+        textureCreateView => texture_create_view if textureCreateView,
+    }
+
+    table
+}
+
+unsafe extern "C" fn ignore(_object: *mut c_void) {}
+
+/// Reinterprets an entry point as one with the equivalent Dawn signature.
+unsafe fn cast_proc<T, U>(proc: T) -> U {
+    assert_eq!(mem::size_of::<T>(), mem::size_of::<U>());
+    mem::transmute_copy::<T, U>(&proc)
+}
+
+unsafe fn slice<'a, T>(data: *const T, count: u32) -> &'a [T] {
+    if count == 0 || data.is_null() {
+        &[]
+    } else {
+        slice::from_raw_parts(data, count as usize)
+    }
+}
+
+fn opt_ptr<T>(value: &Option<T>) -> *const T {
+    value.as_ref().map_or(ptr::null(), |value| value)
+}
+
+fn texture_format(format: sys::WGPUTextureFormat) -> w::WGPUTextureFormat {
+    // `webgpu.h` inserts `RGB9E5Ufloat` after `RG11B10Ufloat`, Dawn's `RG11B10Float`.
+    match format {
+        sys::WGPUTextureFormat_Undefined..=sys::WGPUTextureFormat_RG11B10Float => format,
+        sys::WGPUTextureFormat_RG32Float..=sys::WGPUTextureFormat_BC7RGBAUnormSrgb => format + 1,
+        _ => w::WGPUTextureFormat_Force32,
+    }
+}
+
+fn compare_function(function: sys::WGPUCompareFunction) -> w::WGPUCompareFunction {
+    // `webgpu.h` starts with `Undefined`.
+    match function {
+        sys::WGPUCompareFunction_Never..=sys::WGPUCompareFunction_Always => function + 1,
+        _ => w::WGPUCompareFunction_Force32,
+    }
+}
+
+fn index_format(format: sys::WGPUIndexFormat) -> w::WGPUIndexFormat {
+    match format {
+        sys::WGPUIndexFormat_Uint16 => w::WGPUIndexFormat_Uint16,
+        sys::WGPUIndexFormat_Uint32 => w::WGPUIndexFormat_Uint32,
+        _ => w::WGPUIndexFormat_Undefined,
+    }
+}
+
+fn binding_type(ty: sys::WGPUBindingType) -> w::WGPUBindingType {
+    match ty {
+        sys::WGPUBindingType_UniformBuffer => w::WGPUBindingType_UniformBuffer,
+        sys::WGPUBindingType_StorageBuffer => w::WGPUBindingType_StorageBuffer,
+        sys::WGPUBindingType_ReadonlyStorageBuffer => w::WGPUBindingType_ReadonlyStorageBuffer,
+        sys::WGPUBindingType_Sampler => w::WGPUBindingType_Sampler,
+        sys::WGPUBindingType_SampledTexture => w::WGPUBindingType_SampledTexture,
+        sys::WGPUBindingType_ReadonlyStorageTexture => w::WGPUBindingType_ReadonlyStorageTexture,
+        sys::WGPUBindingType_WriteonlyStorageTexture => w::WGPUBindingType_WriteonlyStorageTexture,
+        // Read-write storage textures aren't in `webgpu.h`; an invalid type makes the
+        // implementation report a validation error.
+        _ => w::WGPUBindingType_Force32,
+    }
+}
+
+fn map_async_status(status: w::WGPUBufferMapAsyncStatus) -> sys::WGPUBufferMapAsyncStatus {
+    match status {
+        w::WGPUBufferMapAsyncStatus_Success => sys::WGPUBufferMapAsyncStatus_Success,
+        w::WGPUBufferMapAsyncStatus_Error => sys::WGPUBufferMapAsyncStatus_Error,
+        w::WGPUBufferMapAsyncStatus_DeviceLost => sys::WGPUBufferMapAsyncStatus_DeviceLost,
+        _ => sys::WGPUBufferMapAsyncStatus_Unknown,
+    }
+}
+
+fn texture_usage(usage: sys::WGPUTextureUsageFlags) -> w::WGPUTextureUsageFlags {
+    // Dawn's `Present` usage isn't in `webgpu.h`; swap chains imply it.
+    usage & !(sys::WGPUTextureUsage_Present as sys::WGPUFlags)
+}
+
+fn color(color: &sys::WGPUColor) -> w::WGPUColor {
+    w::WGPUColor {
+        r: color.r as f64,
+        g: color.g as f64,
+        b: color.b as f64,
+        a: color.a as f64,
+    }
+}
+
+fn extent(extent: &sys::WGPUExtent3D) -> w::WGPUExtent3D {
+    w::WGPUExtent3D {
+        width: extent.width,
+        height: extent.height,
+        depth: extent.depth,
+    }
+}
+
+fn programmable_stage(
+    stage: &sys::WGPUProgrammableStageDescriptor,
+) -> w::WGPUProgrammableStageDescriptor {
+    w::WGPUProgrammableStageDescriptor {
+        nextInChain: ptr::null(),
+        module: stage.module as _,
+        entryPoint: stage.entryPoint,
+    }
+}
+
+fn blend(blend: &sys::WGPUBlendDescriptor) -> w::WGPUBlendDescriptor {
+    w::WGPUBlendDescriptor {
+        operation: blend.operation,
+        srcFactor: blend.srcFactor,
+        dstFactor: blend.dstFactor,
+    }
+}
+
+fn stencil_face(face: &sys::WGPUStencilStateFaceDescriptor) -> w::WGPUStencilStateFaceDescriptor {
+    w::WGPUStencilStateFaceDescriptor {
+        compare: compare_function(face.compare),
+        failOp: face.failOp,
+        depthFailOp: face.depthFailOp,
+        passOp: face.passOp,
+    }
+}
+
+fn buffer_copy_view(view: &sys::WGPUBufferCopyView) -> w::WGPUBufferCopyView {
+    w::WGPUBufferCopyView {
+        nextInChain: ptr::null(),
+        layout: w::WGPUTextureDataLayout {
+            nextInChain: ptr::null(),
+            offset: view.offset,
+            bytesPerRow: view.rowPitch,
+            rowsPerImage: view.imageHeight,
+        },
+        buffer: view.buffer as _,
+    }
+}
+
+fn texture_copy_view(view: &sys::WGPUTextureCopyView) -> w::WGPUTextureCopyView {
+    // Array layers are addressed through the depth of the origin.
+    w::WGPUTextureCopyView {
+        nextInChain: ptr::null(),
+        texture: view.texture as _,
+        mipLevel: view.mipLevel,
+        origin: w::WGPUOrigin3D {
+            x: view.origin.x,
+            y: view.origin.y,
+            z: view.origin.z + view.arrayLayer,
+        },
+    }
+}
+
+unsafe extern "C" fn create_instance(
+    _descriptor: *const sys::WGPUInstanceDescriptor,
+) -> sys::WGPUInstance {
+    let raw_descriptor = w::WGPUInstanceDescriptor {
+        nextInChain: ptr::null(),
+    };
+    procs().createInstance.unwrap()(&raw_descriptor) as _
+}
+
+enum MapCallback {
+    Read(sys::WGPUBufferMapReadCallback),
+    Write(sys::WGPUBufferMapWriteCallback),
+}
+
+struct MapRequest {
+    buffer: sys::WGPUBuffer,
+    size: u64,
+    callback: MapCallback,
+    userdata: *mut c_void,
+}
+
+fn buffer_size(buffer: sys::WGPUBuffer) -> Option<u64> {
+    with_tracking(|tracking| {
+        tracking
+            .buffers
+            .get(&(buffer as usize))
+            .map(|buffer| buffer.info.size)
+    })
+}
+
+unsafe fn buffer_map_async(buffer: sys::WGPUBuffer, mode: w::WGPUMapMode, request: MapRequest) {
+    unsafe extern "C" fn callback(status: w::WGPUBufferMapAsyncStatus, userdata: *mut c_void) {
+        let request = Box::from_raw(userdata as *mut MapRequest);
+        let status = map_async_status(status);
+        let mapped = status == sys::WGPUBufferMapAsyncStatus_Success;
+        let size = if mapped { request.size } else { 0 };
+        match request.callback {
+            MapCallback::Read(Some(callback)) => {
+                let data = if mapped {
+                    procs().bufferGetConstMappedRange.unwrap()(
+                        request.buffer as _,
+                        0,
+                        request.size as usize,
+                    )
+                } else {
+                    ptr::null()
+                };
+                callback(status, data, size, request.userdata);
+            }
+            MapCallback::Write(Some(callback)) => {
+                let data = if mapped {
+                    procs().bufferGetMappedRange.unwrap()(
+                        request.buffer as _,
+                        0,
+                        request.size as usize,
+                    )
+                } else {
+                    ptr::null_mut()
+                };
+                callback(status, data, size, request.userdata);
+            }
+            MapCallback::Read(None) | MapCallback::Write(None) => {}
+        }
+    }
+
+    let size = request.size as usize;
+    procs().bufferMapAsync.unwrap()(
+        buffer as _,
+        mode as _,
+        0,
+        size,
+        Some(callback),
+        Box::into_raw(Box::new(request)) as _,
+    );
+}
+
+unsafe extern "C" fn buffer_map_read_async(
+    buffer: sys::WGPUBuffer,
+    callback: sys::WGPUBufferMapReadCallback,
+    userdata: *mut c_void,
+) {
+    match buffer_size(buffer) {
+        Some(size) => buffer_map_async(
+            buffer,
+            w::WGPUMapMode_Read,
+            MapRequest {
+                buffer,
+                size,
+                callback: MapCallback::Read(callback),
+                userdata,
+            },
+        ),
+        None => {
+            if let Some(callback) = callback {
+                callback(
+                    sys::WGPUBufferMapAsyncStatus_Error,
+                    ptr::null(),
+                    0,
+                    userdata,
+                );
+            }
+        }
+    }
+}
+
+unsafe extern "C" fn buffer_map_write_async(
+    buffer: sys::WGPUBuffer,
+    callback: sys::WGPUBufferMapWriteCallback,
+    userdata: *mut c_void,
+) {
+    match buffer_size(buffer) {
+        Some(size) => buffer_map_async(
+            buffer,
+            w::WGPUMapMode_Write,
+            MapRequest {
+                buffer,
+                size,
+                callback: MapCallback::Write(callback),
+                userdata,
+            },
+        ),
+        None => {
+            if let Some(callback) = callback {
+                callback(
+                    sys::WGPUBufferMapAsyncStatus_Error,
+                    ptr::null_mut(),
+                    0,
+                    userdata,
+                );
+            }
+        }
+    }
+}
+
+/// The device's default queue, requested on first use and released with the device.
+fn default_queue(device: sys::WGPUDevice) -> Option<w::WGPUQueue> {
+    with_tracking(|tracking| {
+        let device_info = &mut tracking.devices.get_mut(&(device as usize))?.info;
+        if device_info.queue.is_null() {
+            device_info.queue = unsafe { procs().deviceGetDefaultQueue.unwrap()(device as _) };
+        }
+        Some(device_info.queue)
+    })
+}
+
+unsafe extern "C" fn buffer_set_sub_data(
+    buffer: sys::WGPUBuffer,
+    start: u64,
+    count: u64,
+    data: *const c_void,
+) {
+    let device = with_tracking(|tracking| {
+        tracking
+            .buffers
+            .get(&(buffer as usize))
+            .map(|buffer| buffer.info.device)
+    });
+    if let Some(queue) = device.and_then(default_queue) {
+        procs().queueWriteBuffer.unwrap()(queue, buffer as _, start, data, count as usize);
+    }
+}
+
+unsafe extern "C" fn buffer_reference(buffer: sys::WGPUBuffer) {
+    with_tracking(|tracking| reference(&mut tracking.buffers, buffer as usize));
+    if let Some(buffer_reference) = procs().bufferReference {
+        buffer_reference(buffer as _);
+    }
+}
+
+unsafe extern "C" fn buffer_release(buffer: sys::WGPUBuffer) {
+    with_tracking(|tracking| release(&mut tracking.buffers, buffer as usize));
+    if let Some(buffer_release) = procs().bufferRelease {
+        buffer_release(buffer as _);
+    }
+}
+
+unsafe extern "C" fn command_encoder_begin_compute_pass(
+    encoder: sys::WGPUCommandEncoder,
+    descriptor: *const sys::WGPUComputePassDescriptor,
+) -> sys::WGPUComputePassEncoder {
+    let raw_descriptor = w::WGPUComputePassDescriptor {
+        nextInChain: ptr::null(),
+        label: descriptor.as_ref().map_or(ptr::null(), |d| d.label),
+    };
+    procs().commandEncoderBeginComputePass.unwrap()(encoder as _, &raw_descriptor) as _
+}
+
+unsafe extern "C" fn command_encoder_begin_render_pass(
+    encoder: sys::WGPUCommandEncoder,
+    descriptor: *const sys::WGPURenderPassDescriptor,
+) -> sys::WGPURenderPassEncoder {
+    let descriptor = &*descriptor;
+    let color_attachments: Vec<_> =
+        slice(descriptor.colorAttachments, descriptor.colorAttachmentCount)
+            .iter()
+            .map(|attachment| w::WGPURenderPassColorAttachmentDescriptor {
+                attachment: attachment.attachment as _,
+                resolveTarget: attachment.resolveTarget as _,
+                loadOp: attachment.loadOp,
+                storeOp: attachment.storeOp,
+                clearColor: color(&attachment.clearColor),
+            })
+            .collect();
+    let depth_stencil_attachment = descriptor
+        .depthStencilAttachment
+        .as_ref()
+        .map(
+            |attachment| w::WGPURenderPassDepthStencilAttachmentDescriptor {
+                attachment: attachment.attachment as _,
+                depthLoadOp: attachment.depthLoadOp,
+                depthStoreOp: attachment.depthStoreOp,
+                clearDepth: attachment.clearDepth,
+                depthReadOnly: false,
+                stencilLoadOp: attachment.stencilLoadOp,
+                stencilStoreOp: attachment.stencilStoreOp,
+                clearStencil: attachment.clearStencil,
+                stencilReadOnly: false,
+            },
+        );
+    let raw_descriptor = w::WGPURenderPassDescriptor {
+        nextInChain: ptr::null(),
+        label: descriptor.label,
+        colorAttachmentCount: color_attachments.len() as u32,
+        colorAttachments: color_attachments.as_ptr(),
+        depthStencilAttachment: opt_ptr(&depth_stencil_attachment),
+        occlusionQuerySet: ptr::null_mut(),
+    };
+    procs().commandEncoderBeginRenderPass.unwrap()(encoder as _, &raw_descriptor) as _
+}
+
+unsafe extern "C" fn command_encoder_copy_buffer_to_texture(
+    encoder: sys::WGPUCommandEncoder,
+    source: *const sys::WGPUBufferCopyView,
+    destination: *const sys::WGPUTextureCopyView,
+    copy_size: *const sys::WGPUExtent3D,
+) {
+    procs().commandEncoderCopyBufferToTexture.unwrap()(
+        encoder as _,
+        &buffer_copy_view(&*source),
+        &texture_copy_view(&*destination),
+        &extent(&*copy_size),
+    );
+}
+
+unsafe extern "C" fn command_encoder_copy_texture_to_buffer(
+    encoder: sys::WGPUCommandEncoder,
+    source: *const sys::WGPUTextureCopyView,
+    destination: *const sys::WGPUBufferCopyView,
+    copy_size: *const sys::WGPUExtent3D,
+) {
+    procs().commandEncoderCopyTextureToBuffer.unwrap()(
+        encoder as _,
+        &texture_copy_view(&*source),
+        &buffer_copy_view(&*destination),
+        &extent(&*copy_size),
+    );
+}
+
+unsafe extern "C" fn command_encoder_copy_texture_to_texture(
+    encoder: sys::WGPUCommandEncoder,
+    source: *const sys::WGPUTextureCopyView,
+    destination: *const sys::WGPUTextureCopyView,
+    copy_size: *const sys::WGPUExtent3D,
+) {
+    procs().commandEncoderCopyTextureToTexture.unwrap()(
+        encoder as _,
+        &texture_copy_view(&*source),
+        &texture_copy_view(&*destination),
+        &extent(&*copy_size),
+    );
+}
+
+unsafe extern "C" fn command_encoder_finish(
+    encoder: sys::WGPUCommandEncoder,
+    descriptor: *const sys::WGPUCommandBufferDescriptor,
+) -> sys::WGPUCommandBuffer {
+    let raw_descriptor = w::WGPUCommandBufferDescriptor {
+        nextInChain: ptr::null(),
+        label: descriptor.as_ref().map_or(ptr::null(), |d| d.label),
+    };
+    procs().commandEncoderFinish.unwrap()(encoder as _, &raw_descriptor) as _
+}
+
+unsafe extern "C" fn device_create_bind_group(
+    device: sys::WGPUDevice,
+    descriptor: *const sys::WGPUBindGroupDescriptor,
+) -> sys::WGPUBindGroup {
+    let descriptor = &*descriptor;
+    let entries: Vec<_> = slice(descriptor.bindings, descriptor.bindingCount)
+        .iter()
+        .map(|entry| w::WGPUBindGroupEntry {
+            binding: entry.binding,
+            buffer: entry.buffer as _,
+            offset: entry.offset,
+            size: entry.size,
+            sampler: entry.sampler as _,
+            textureView: entry.textureView as _,
+        })
+        .collect();
+    let raw_descriptor = w::WGPUBindGroupDescriptor {
+        nextInChain: ptr::null(),
+        label: descriptor.label,
+        layout: descriptor.layout as _,
+        entryCount: entries.len() as u32,
+        entries: entries.as_ptr(),
+    };
+    procs().deviceCreateBindGroup.unwrap()(device as _, &raw_descriptor) as _
+}
+
+unsafe extern "C" fn device_create_bind_group_layout(
+    device: sys::WGPUDevice,
+    descriptor: *const sys::WGPUBindGroupLayoutDescriptor,
+) -> sys::WGPUBindGroupLayout {
+    let descriptor = &*descriptor;
+    let entries: Vec<_> = slice(descriptor.bindings, descriptor.bindingCount)
+        .iter()
+        .map(|entry| w::WGPUBindGroupLayoutEntry {
+            binding: entry.binding,
+            visibility: entry.visibility,
+            type_: binding_type(entry.type_),
+            hasDynamicOffset: entry.hasDynamicOffset,
+            minBufferBindingSize: 0,
+            multisampled: entry.multisampled,
+            viewDimension: entry.textureDimension,
+            textureComponentType: entry.textureComponentType,
+            storageTextureFormat: texture_format(entry.storageTextureFormat),
+        })
+        .collect();
+    let raw_descriptor = w::WGPUBindGroupLayoutDescriptor {
+        nextInChain: ptr::null(),
+        label: descriptor.label,
+        entryCount: entries.len() as u32,
+        entries: entries.as_ptr(),
+    };
+    procs().deviceCreateBindGroupLayout.unwrap()(device as _, &raw_descriptor) as _
+}
+
+unsafe fn create_buffer(
+    device: sys::WGPUDevice,
+    descriptor: &sys::WGPUBufferDescriptor,
+    mapped_at_creation: bool,
+) -> w::WGPUBuffer {
+    let raw_descriptor = w::WGPUBufferDescriptor {
+        nextInChain: ptr::null(),
+        label: descriptor.label,
+        usage: descriptor.usage,
+        size: descriptor.size,
+        mappedAtCreation: mapped_at_creation,
+    };
+    let buffer = procs().deviceCreateBuffer.unwrap()(device as _, &raw_descriptor);
+    with_tracking(|tracking| {
+        tracking.buffers.insert(
+            buffer as usize,
+            Tracked::new(BufferInfo {
+                device,
+                size: descriptor.size,
+            }),
+        );
+    });
+    buffer
+}
+
+unsafe extern "C" fn device_create_buffer(
+    device: sys::WGPUDevice,
+    descriptor: *const sys::WGPUBufferDescriptor,
+) -> sys::WGPUBuffer {
+    create_buffer(device, &*descriptor, false) as _
+}
+
+unsafe extern "C" fn device_create_buffer_mapped(
+    device: sys::WGPUDevice,
+    descriptor: *const sys::WGPUBufferDescriptor,
+) -> sys::WGPUCreateBufferMappedResult {
+    let descriptor = &*descriptor;
+    let buffer = create_buffer(device, descriptor, true);
+    let data = procs().bufferGetMappedRange.unwrap()(buffer, 0, descriptor.size as usize);
+    sys::WGPUCreateBufferMappedResult {
+        buffer: buffer as _,
+        dataLength: descriptor.size,
+        data,
+    }
+}
+
+unsafe extern "C" fn device_create_command_encoder(
+    device: sys::WGPUDevice,
+    descriptor: *const sys::WGPUCommandEncoderDescriptor,
+) -> sys::WGPUCommandEncoder {
+    let raw_descriptor = w::WGPUCommandEncoderDescriptor {
+        nextInChain: ptr::null(),
+        label: descriptor.as_ref().map_or(ptr::null(), |d| d.label),
+    };
+    procs().deviceCreateCommandEncoder.unwrap()(device as _, &raw_descriptor) as _
+}
+
+unsafe extern "C" fn device_create_compute_pipeline(
+    device: sys::WGPUDevice,
+    descriptor: *const sys::WGPUComputePipelineDescriptor,
+) -> sys::WGPUComputePipeline {
+    let descriptor = &*descriptor;
+    let raw_descriptor = w::WGPUComputePipelineDescriptor {
+        nextInChain: ptr::null(),
+        label: descriptor.label,
+        layout: descriptor.layout as _,
+        computeStage: programmable_stage(&descriptor.computeStage),
+    };
+    procs().deviceCreateComputePipeline.unwrap()(device as _, &raw_descriptor) as _
+}
+
+unsafe extern "C" fn device_create_pipeline_layout(
+    device: sys::WGPUDevice,
+    descriptor: *const sys::WGPUPipelineLayoutDescriptor,
+) -> sys::WGPUPipelineLayout {
+    let descriptor = &*descriptor;
+    let raw_descriptor = w::WGPUPipelineLayoutDescriptor {
+        nextInChain: ptr::null(),
+        label: descriptor.label,
+        bindGroupLayoutCount: descriptor.bindGroupLayoutCount,
+        bindGroupLayouts: descriptor.bindGroupLayouts as _,
+    };
+    procs().deviceCreatePipelineLayout.unwrap()(device as _, &raw_descriptor) as _
+}
+
+unsafe extern "C" fn device_create_render_bundle_encoder(
+    device: sys::WGPUDevice,
+    descriptor: *const sys::WGPURenderBundleEncoderDescriptor,
+) -> sys::WGPURenderBundleEncoder {
+    let descriptor = &*descriptor;
+    let color_formats: Vec<_> = slice(descriptor.colorFormats, descriptor.colorFormatsCount)
+        .iter()
+        .map(|format| texture_format(*format))
+        .collect();
+    let raw_descriptor = w::WGPURenderBundleEncoderDescriptor {
+        nextInChain: ptr::null(),
+        label: descriptor.label,
+        colorFormatsCount: color_formats.len() as u32,
+        colorFormats: color_formats.as_ptr(),
+        depthStencilFormat: texture_format(descriptor.depthStencilFormat),
+        sampleCount: descriptor.sampleCount,
+    };
+    procs().deviceCreateRenderBundleEncoder.unwrap()(device as _, &raw_descriptor) as _
+}
+
+unsafe extern "C" fn device_create_render_pipeline(
+    device: sys::WGPUDevice,
+    descriptor: *const sys::WGPURenderPipelineDescriptor,
+) -> sys::WGPURenderPipeline {
+    let descriptor = &*descriptor;
+    let fragment_stage = descriptor.fragmentStage.as_ref().map(programmable_stage);
+
+    let vertex_state = descriptor.vertexState.as_ref();
+    let layouts = vertex_state.map_or(&[][..], |state| {
+        slice(state.vertexBuffers, state.vertexBufferCount)
+    });
+    let attributes: Vec<Vec<_>> = layouts
+        .iter()
+        .map(|layout| {
+            slice(layout.attributes, layout.attributeCount)
+                .iter()
+                .map(|attribute| w::WGPUVertexAttributeDescriptor {
+                    // The vertex formats have the same values in both headers.
+                    format: attribute.format,
+                    offset: attribute.offset,
+                    shaderLocation: attribute.shaderLocation,
+                })
+                .collect()
+        })
+        .collect();
+    let vertex_buffers: Vec<_> = layouts
+        .iter()
+        .zip(&attributes)
+        .map(|(layout, attributes)| w::WGPUVertexBufferLayoutDescriptor {
+            arrayStride: layout.arrayStride,
+            stepMode: layout.stepMode,
+            attributeCount: attributes.len() as u32,
+            attributes: attributes.as_ptr(),
+        })
+        .collect();
+    let vertex_state = vertex_state.map(|state| w::WGPUVertexStateDescriptor {
+        nextInChain: ptr::null(),
+        indexFormat: index_format(state.indexFormat),
+        vertexBufferCount: vertex_buffers.len() as u32,
+        vertexBuffers: vertex_buffers.as_ptr(),
+    });
+
+    let rasterization_state =
+        descriptor
+            .rasterizationState
+            .as_ref()
+            .map(|state| w::WGPURasterizationStateDescriptor {
+                nextInChain: ptr::null(),
+                frontFace: state.frontFace,
+                cullMode: state.cullMode,
+                depthBias: state.depthBias,
+                depthBiasSlopeScale: state.depthBiasSlopeScale,
+                depthBiasClamp: state.depthBiasClamp,
+            });
+    let depth_stencil_state =
+        descriptor
+            .depthStencilState
+            .as_ref()
+            .map(|state| w::WGPUDepthStencilStateDescriptor {
+                nextInChain: ptr::null(),
+                format: texture_format(state.format),
+                depthWriteEnabled: state.depthWriteEnabled,
+                depthCompare: compare_function(state.depthCompare),
+                stencilFront: stencil_face(&state.stencilFront),
+                stencilBack: stencil_face(&state.stencilBack),
+                stencilReadMask: state.stencilReadMask,
+                stencilWriteMask: state.stencilWriteMask,
+            });
+    let color_states: Vec<_> = slice(descriptor.colorStates, descriptor.colorStateCount)
+        .iter()
+        .map(|state| w::WGPUColorStateDescriptor {
+            nextInChain: ptr::null(),
+            format: texture_format(state.format),
+            alphaBlend: blend(&state.alphaBlend),
+            colorBlend: blend(&state.colorBlend),
+            writeMask: state.writeMask,
+        })
+        .collect();
+
+    let raw_descriptor = w::WGPURenderPipelineDescriptor {
+        nextInChain: ptr::null(),
+        label: descriptor.label,
+        layout: descriptor.layout as _,
+        vertexStage: programmable_stage(&descriptor.vertexStage),
+        fragmentStage: opt_ptr(&fragment_stage),
+        vertexState: opt_ptr(&vertex_state),
+        primitiveTopology: descriptor.primitiveTopology,
+        rasterizationState: opt_ptr(&rasterization_state),
+        sampleCount: descriptor.sampleCount,
+        depthStencilState: opt_ptr(&depth_stencil_state),
+        colorStateCount: color_states.len() as u32,
+        colorStates: color_states.as_ptr(),
+        sampleMask: descriptor.sampleMask,
+        alphaToCoverageEnabled: descriptor.alphaToCoverageEnabled,
+    };
+    procs().deviceCreateRenderPipeline.unwrap()(device as _, &raw_descriptor) as _
+}
+
+unsafe extern "C" fn device_create_sampler(
+    device: sys::WGPUDevice,
+    descriptor: *const sys::WGPUSamplerDescriptor,
+) -> sys::WGPUSampler {
+    let descriptor = &*descriptor;
+    // Dawn has no comparison samplers at this revision and defaults `compare` to `Never`.
+    // `Undefined` makes a non-comparison sampler in `webgpu.h`.
+    let compare = match descriptor.compare {
+        sys::WGPUCompareFunction_Never => w::WGPUCompareFunction_Undefined,
+        compare => compare_function(compare),
+    };
+    let raw_descriptor = w::WGPUSamplerDescriptor {
+        nextInChain: ptr::null(),
+        label: descriptor.label,
+        addressModeU: descriptor.addressModeU,
+        addressModeV: descriptor.addressModeV,
+        addressModeW: descriptor.addressModeW,
+        magFilter: descriptor.magFilter,
+        minFilter: descriptor.minFilter,
+        mipmapFilter: descriptor.mipmapFilter,
+        lodMinClamp: descriptor.lodMinClamp,
+        lodMaxClamp: descriptor.lodMaxClamp,
+        compare,
+    };
+    procs().deviceCreateSampler.unwrap()(device as _, &raw_descriptor) as _
+}
+
+unsafe extern "C" fn device_create_shader_module(
+    device: sys::WGPUDevice,
+    descriptor: *const sys::WGPUShaderModuleDescriptor,
+) -> sys::WGPUShaderModule {
+    let descriptor = &*descriptor;
+    let spirv = w::WGPUShaderModuleSPIRVDescriptor {
+        chain: w::WGPUChainedStruct {
+            next: ptr::null(),
+            sType: w::WGPUSType_ShaderModuleSPIRVDescriptor,
+        },
+        codeSize: descriptor.codeSize,
+        code: descriptor.code,
+    };
+    let raw_descriptor = w::WGPUShaderModuleDescriptor {
+        nextInChain: &spirv.chain,
+        label: descriptor.label,
+    };
+    procs().deviceCreateShaderModule.unwrap()(device as _, &raw_descriptor) as _
+}
+
+unsafe extern "C" fn device_create_swap_chain(
+    device: sys::WGPUDevice,
+    surface: sys::WGPUSurface,
+    descriptor: *const sys::WGPUSwapChainDescriptor,
+) -> sys::WGPUSwapChain {
+    let descriptor = &*descriptor;
+    let raw_descriptor = w::WGPUSwapChainDescriptor {
+        nextInChain: ptr::null(),
+        label: descriptor.label,
+        usage: texture_usage(descriptor.usage),
+        format: texture_format(descriptor.format),
+        width: descriptor.width,
+        height: descriptor.height,
+        presentMode: descriptor.presentMode,
+        implementation: descriptor.implementation,
+    };
+    procs().deviceCreateSwapChain.unwrap()(device as _, surface as _, &raw_descriptor) as _
+}
+
+unsafe extern "C" fn device_create_texture(
+    device: sys::WGPUDevice,
+    descriptor: *const sys::WGPUTextureDescriptor,
+) -> sys::WGPUTexture {
+    let descriptor = &*descriptor;
+    // Array layers are the depth of the size in `webgpu.h`.
+    let mut size = extent(&descriptor.size);
+    size.depth *= descriptor.arrayLayerCount.max(1);
+    let raw_descriptor = w::WGPUTextureDescriptor {
+        nextInChain: ptr::null(),
+        label: descriptor.label,
+        usage: texture_usage(descriptor.usage),
+        dimension: descriptor.dimension,
+        size,
+        format: texture_format(descriptor.format),
+        mipLevelCount: descriptor.mipLevelCount,
+        sampleCount: descriptor.sampleCount,
+    };
+    procs().deviceCreateTexture.unwrap()(device as _, &raw_descriptor) as _
+}
+
+unsafe extern "C" fn device_inject_error(
+    device: sys::WGPUDevice,
+    error_type: sys::WGPUErrorType,
+    message: *const libc::c_char,
+) {
+    let callback = with_tracking(|tracking| {
+        let device_info = &tracking.devices.get(&(device as usize))?.info;
+        Some((device_info.error_callback?, device_info.error_userdata))
+    });
+    if let Some((callback, userdata)) = callback {
+        callback(error_type, message, userdata);
+    }
+}
+
+unsafe extern "C" fn device_set_uncaptured_error_callback(
+    device: sys::WGPUDevice,
+    callback: sys::WGPUErrorCallback,
+    userdata: *mut c_void,
+) {
+    with_tracking(|tracking| {
+        if let Some(device) = tracking.devices.get_mut(&(device as usize)) {
+            device.info.error_callback = callback;
+            device.info.error_userdata = userdata;
+        }
+    });
+    if let Some(set_callback) = procs().deviceSetUncapturedErrorCallback {
+        set_callback(device as _, callback, userdata);
+    }
+}
+
+unsafe extern "C" fn device_tick(_device: sys::WGPUDevice) {}
+
+unsafe extern "C" fn device_reference(device: sys::WGPUDevice) {
+    with_tracking(|tracking| reference(&mut tracking.devices, device as usize));
+    if let Some(device_reference) = procs().deviceReference {
+        device_reference(device as _);
+    }
+}
+
+unsafe extern "C" fn device_release(device: sys::WGPUDevice) {
+    let device_info = with_tracking(|tracking| release(&mut tracking.devices, device as usize));
+    let procs = procs();
+    if let (Some(device_info), Some(queue_release)) = (device_info, procs.queueRelease) {
+        if !device_info.queue.is_null() {
+            queue_release(device_info.queue);
+        }
+    }
+    if let Some(device_release) = procs.deviceRelease {
+        device_release(device as _);
+    }
+}
+
+unsafe extern "C" fn instance_create_surface(
+    instance: sys::WGPUInstance,
+    descriptor: *const sys::WGPUSurfaceDescriptor,
+) -> sys::WGPUSurface {
+    let descriptor = &*descriptor;
+    let mut metal_layer: w::WGPUSurfaceDescriptorFromMetalLayer = mem::zeroed();
+    let mut windows_hwnd: w::WGPUSurfaceDescriptorFromWindowsHWND = mem::zeroed();
+    let mut xlib: w::WGPUSurfaceDescriptorFromXlib = mem::zeroed();
+    let mut html_canvas_id: w::WGPUSurfaceDescriptorFromHTMLCanvasId = mem::zeroed();
+    let mut next: *const w::WGPUChainedStruct = ptr::null();
+
+    let mut chain = descriptor.nextInChain;
+    while let Some(link) = chain.as_ref() {
+        match link.sType {
+            sys::WGPUSType_SurfaceDescriptorFromMetalLayer => {
+                let source = &*(chain as *const sys::WGPUSurfaceDescriptorFromMetalLayer);
+                metal_layer.chain.sType = w::WGPUSType_SurfaceDescriptorFromMetalLayer;
+                metal_layer.chain.next = next;
+                metal_layer.layer = source.layer;
+                next = &metal_layer.chain;
+            }
+            sys::WGPUSType_SurfaceDescriptorFromWindowsHWND => {
+                let source = &*(chain as *const sys::WGPUSurfaceDescriptorFromWindowsHWND);
+                windows_hwnd.chain.sType = w::WGPUSType_SurfaceDescriptorFromWindowsHWND;
+                windows_hwnd.chain.next = next;
+                windows_hwnd.hinstance = source.hinstance;
+                windows_hwnd.hwnd = source.hwnd;
+                next = &windows_hwnd.chain;
+            }
+            sys::WGPUSType_SurfaceDescriptorFromXlib => {
+                let source = &*(chain as *const sys::WGPUSurfaceDescriptorFromXlib);
+                xlib.chain.sType = w::WGPUSType_SurfaceDescriptorFromXlib;
+                xlib.chain.next = next;
+                xlib.display = source.display;
+                xlib.window = source.window;
+                next = &xlib.chain;
+            }
+            sys::WGPUSType_SurfaceDescriptorFromHTMLCanvasId => {
+                let source = &*(chain as *const sys::WGPUSurfaceDescriptorFromHTMLCanvasId);
+                html_canvas_id.chain.sType = w::WGPUSType_SurfaceDescriptorFromHTMLCanvasId;
+                html_canvas_id.chain.next = next;
+                html_canvas_id.id = source.id;
+                next = &html_canvas_id.chain;
+            }
+            _ => {}
+        }
+        chain = link.next;
+    }
+
+    let raw_descriptor = w::WGPUSurfaceDescriptor {
+        nextInChain: next,
+        label: descriptor.label,
+    };
+    procs().instanceCreateSurface.unwrap()(instance as _, &raw_descriptor) as _
+}
+
+unsafe extern "C" fn queue_create_fence(
+    queue: sys::WGPUQueue,
+    descriptor: *const sys::WGPUFenceDescriptor,
+) -> sys::WGPUFence {
+    let descriptor = &*descriptor;
+    let raw_descriptor = w::WGPUFenceDescriptor {
+        nextInChain: ptr::null(),
+        label: descriptor.label,
+        initialValue: descriptor.initialValue,
+    };
+    procs().queueCreateFence.unwrap()(queue as _, &raw_descriptor) as _
+}
+
+unsafe extern "C" fn render_bundle_encoder_finish(
+    encoder: sys::WGPURenderBundleEncoder,
+    descriptor: *const sys::WGPURenderBundleDescriptor,
+) -> sys::WGPURenderBundle {
+    let raw_descriptor = w::WGPURenderBundleDescriptor {
+        nextInChain: ptr::null(),
+        label: descriptor.as_ref().map_or(ptr::null(), |d| d.label),
+    };
+    procs().renderBundleEncoderFinish.unwrap()(encoder as _, &raw_descriptor) as _
+}
+
+unsafe extern "C" fn render_bundle_encoder_set_index_buffer(
+    encoder: sys::WGPURenderBundleEncoder,
+    buffer: sys::WGPUBuffer,
+    offset: u64,
+) {
+    procs().renderBundleEncoderSetIndexBuffer.unwrap()(
+        encoder as _,
+        buffer as _,
+        offset,
+        w::WGPU_WHOLE_SIZE,
+    );
+}
+
+unsafe extern "C" fn render_bundle_encoder_set_vertex_buffer(
+    encoder: sys::WGPURenderBundleEncoder,
+    slot: u32,
+    buffer: sys::WGPUBuffer,
+    offset: u64,
+) {
+    procs().renderBundleEncoderSetVertexBuffer.unwrap()(
+        encoder as _,
+        slot,
+        buffer as _,
+        offset,
+        w::WGPU_WHOLE_SIZE,
+    );
+}
+
+unsafe extern "C" fn render_pass_encoder_set_blend_color(
+    encoder: sys::WGPURenderPassEncoder,
+    blend_color: *const sys::WGPUColor,
+) {
+    procs().renderPassEncoderSetBlendColor.unwrap()(encoder as _, &color(&*blend_color));
+}
+
+unsafe extern "C" fn render_pass_encoder_set_index_buffer(
+    encoder: sys::WGPURenderPassEncoder,
+    buffer: sys::WGPUBuffer,
+    offset: u64,
+) {
+    procs().renderPassEncoderSetIndexBuffer.unwrap()(
+        encoder as _,
+        buffer as _,
+        offset,
+        w::WGPU_WHOLE_SIZE,
+    );
+}
+
+unsafe extern "C" fn render_pass_encoder_set_vertex_buffer(
+    encoder: sys::WGPURenderPassEncoder,
+    slot: u32,
+    buffer: sys::WGPUBuffer,
+    offset: u64,
+) {
+    procs().renderPassEncoderSetVertexBuffer.unwrap()(
+        encoder as _,
+        slot,
+        buffer as _,
+        offset,
+        w::WGPU_WHOLE_SIZE,
+    );
+}
+
+unsafe extern "C" fn texture_create_view(
+    texture: sys::WGPUTexture,
+    descriptor: *const sys::WGPUTextureViewDescriptor,
+) -> sys::WGPUTextureView {
+    let raw_descriptor = descriptor
+        .as_ref()
+        .map(|descriptor| w::WGPUTextureViewDescriptor {
+            nextInChain: ptr::null(),
+            label: descriptor.label,
+            format: texture_format(descriptor.format),
+            dimension: descriptor.dimension,
+            baseMipLevel: descriptor.baseMipLevel,
+            mipLevelCount: descriptor.mipLevelCount,
+            baseArrayLayer: descriptor.baseArrayLayer,
+            arrayLayerCount: descriptor.arrayLayerCount,
+            aspect: descriptor.aspect,
+        });
+    procs().textureCreateView.unwrap()(texture as _, opt_ptr(&raw_descriptor)) as _
+}
+
+#[test]
+fn translates_descriptors_and_emulates_set_sub_data() {
+    static CALLS: Mutex<Vec<String>> = parking_lot::const_mutex(Vec::new());
+
+    fn record(call: String) {
+        CALLS.lock().push(call);
+    }
+
+    const DEVICE: usize = 0x10;
+    const QUEUE: usize = 0x20;
+    const BUFFER: usize = 0x30;
+
+    unsafe extern "C" fn create_bind_group_layout(
+        _device: w::WGPUDevice,
+        descriptor: *const w::WGPUBindGroupLayoutDescriptor,
+    ) -> w::WGPUBindGroupLayout {
+        let descriptor = &*descriptor;
+        for entry in slice(descriptor.entries, descriptor.entryCount) {
+            record(format!(
+                "layout {} {} {}",
+                entry.binding, entry.type_, entry.storageTextureFormat
+            ));
+        }
+        ptr::null_mut()
+    }
+
+    unsafe extern "C" fn create_buffer(
+        _device: w::WGPUDevice,
+        descriptor: *const w::WGPUBufferDescriptor,
+    ) -> w::WGPUBuffer {
+        record(format!("buffer {}", (*descriptor).size));
+        BUFFER as _
+    }
+
+    unsafe extern "C" fn get_default_queue(device: w::WGPUDevice) -> w::WGPUQueue {
+        record(format!("queue {:#x}", device as usize));
+        QUEUE as _
+    }
+
+    unsafe extern "C" fn write_buffer(
+        queue: w::WGPUQueue,
+        buffer: w::WGPUBuffer,
+        offset: u64,
+        _data: *const c_void,
+        size: usize,
+    ) {
+        record(format!(
+            "write {:#x} {:#x} {} {}",
+            queue as usize, buffer as usize, offset, size
+        ));
+    }
+
+    let procs = unsafe {
+        sys::webgpu_native::load_procs(|name| match name.to_str().unwrap() {
+            "wgpuDeviceCreateBindGroupLayout" => create_bind_group_layout as _,
+            "wgpuDeviceCreateBuffer" => create_buffer as _,
+            "wgpuDeviceGetDefaultQueue" => get_default_queue as _,
+            "wgpuQueueWriteBuffer" => write_buffer as _,
+            _ => ptr::null(),
+        })
+    };
+    let table = unsafe { proc_table(procs) };
+    assert!(table.deviceCreateRenderPipeline.is_none());
+    assert!(table.bufferSetSubData.is_some());
+
+    let device = DEVICE as sys::WGPUDevice;
+    with_tracking(|tracking| {
+        tracking.devices.insert(
+            DEVICE,
+            Tracked::new(DeviceInfo {
+                queue: ptr::null_mut(),
+                error_callback: None,
+                error_userdata: ptr::null_mut(),
+            }),
+        );
+    });
+
+    unsafe {
+        let mut entry: sys::WGPUBindGroupLayoutEntry = mem::zeroed();
+        entry.binding = 3;
+        entry.type_ = sys::WGPUBindingType_ReadonlyStorageTexture;
+        entry.storageTextureFormat = sys::WGPUTextureFormat_RGBA32Float;
+        let descriptor = sys::WGPUBindGroupLayoutDescriptor {
+            nextInChain: ptr::null(),
+            label: ptr::null(),
+            bindingCount: 1,
+            bindings: &entry,
+        };
+        table.deviceCreateBindGroupLayout.unwrap()(device, &descriptor);
+
+        let descriptor = sys::WGPUBufferDescriptor {
+            nextInChain: ptr::null(),
+            label: ptr::null(),
+            usage: sys::WGPUBufferUsage_CopyDst as sys::WGPUFlags,
+            size: 16,
+        };
+        let buffer = table.deviceCreateBuffer.unwrap()(device, &descriptor);
+        let data = [0u8; 8];
+        table.bufferSetSubData.unwrap()(buffer, 4, 8, data.as_ptr() as _);
+        table.bufferSetSubData.unwrap()(buffer, 0, 4, data.as_ptr() as _);
+    }
+
+    let expected = vec![
+        format!(
+            "layout 3 {} {}",
+            w::WGPUBindingType_ReadonlyStorageTexture,
+            w::WGPUTextureFormat_RGBA32Float
+        ),
+        "buffer 16".to_string(),
+        "queue 0x10".to_string(),
+        "write 0x20 0x30 4 8".to_string(),
+        "write 0x20 0x30 0 4".to_string(),
+    ];
+    assert_eq!(*CALLS.lock(), expected);
+}
@@ -0,0 +1,190 @@
+//! Translating WGSL to SPIR-V.
+//!
+//! The Dawn revision used by this crate only accepts SPIR-V. With the `wgsl` feature,
+//! `ShaderModuleSource::Wgsl` is parsed, validated and translated to SPIR-V with naga before
+//! the module is created. Call `translate` directly to get the errors with their source spans.
+//!
+//! ```no_run
+//! # let device: dawn::Device = unimplemented!();
+//! use dawn::{ShaderModuleDescriptor, ShaderModuleSource};
+//!
+//! let source = "@compute @workgroup_size(1) fn main() {}";
+//! if let Err(err) = dawn::wgsl::translate(source) {
+//!     for label in err.labels() {
+//!         eprintln!("{}:{}: {}", label.span.line, label.span.column, label.message);
+//!     }
+//! }
+//! let shader = device.create_shader_module(&ShaderModuleDescriptor {
+//!     label: Some("main"),
+//!     source: ShaderModuleSource::Wgsl(source),
+//! });
+//! ```
+
+use std::fmt;
+use std::ops::Range;
+
+/// A range of the WGSL source.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SourceSpan {
+    /// The byte offset of the first character.
+    pub start: usize,
+    /// The byte offset after the last character.
+    pub end: usize,
+    /// The 1-based line of `start`.
+    pub line: u32,
+    /// The 1-based column of `start`, in characters.
+    pub column: u32,
+}
+
+impl SourceSpan {
+    fn new(source: &str, range: Range<usize>) -> SourceSpan {
+        let start = range.start.min(source.len());
+        let before = &source[..start];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        SourceSpan {
+            start,
+            end: range.end.min(source.len()).max(start),
+            line: before.matches('\n').count() as u32 + 1,
+            column: before[line_start..].chars().count() as u32 + 1,
+        }
+    }
+}
+
+/// A message about a span of the source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Label {
+    pub span: SourceSpan,
+    pub message: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum WgslError {
+    Parse {
+        message: String,
+        labels: Vec<Label>,
+    },
+    Validation {
+        message: String,
+        labels: Vec<Label>,
+    },
+    /// The module is valid but naga couldn't write it as SPIR-V.
+    Translation(String),
+}
+
+impl WgslError {
+    /// The spans of the source the error refers to, most relevant first.
+    pub fn labels(&self) -> &[Label] {
+        match self {
+            WgslError::Parse { labels, .. } | WgslError::Validation { labels, .. } => labels,
+            WgslError::Translation(_) => &[],
+        }
+    }
+}
+
+impl fmt::Display for WgslError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (kind, message) = match self {
+            WgslError::Parse { message, .. } => ("WGSL parse error", message),
+            WgslError::Validation { message, .. } => ("WGSL validation error", message),
+            WgslError::Translation(message) => ("WGSL translation error", message),
+        };
+        match self.labels().first() {
+            Some(label) => write!(
+                f,
+                "{}:{}: {}: {}",
+                label.span.line, label.span.column, kind, message
+            )?,
+            None => write!(f, "{}: {}", kind, message)?,
+        }
+        for label in self
+            .labels()
+            .iter()
+            .filter(|label| !label.message.is_empty())
+        {
+            write!(
+                f,
+                "\n  {}:{}: {}",
+                label.span.line, label.span.column, label.message
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for WgslError {}
+
+/// Parses and validates `source` and translates it to SPIR-V.
+pub fn translate(source: &str) -> Result<Vec<u32>, WgslError> {
+    let module = naga::front::wgsl::parse_str(source).map_err(|err| WgslError::Parse {
+        message: err.message().to_owned(),
+        labels: labels(source, err.labels()),
+    })?;
+    let info = naga::valid::Validator::new(
+        naga::valid::ValidationFlags::all(),
+        naga::valid::Capabilities::empty(),
+    )
+    .validate(&module)
+    .map_err(|err| WgslError::Validation {
+        message: err.as_inner().to_string(),
+        labels: labels(
+            source,
+            err.spans()
+                .filter_map(|(span, message)| Some((span.to_range()?, &message[..]))),
+        ),
+    })?;
+    naga::back::spv::write_vec(&module, &info, &naga::back::spv::Options::default(), None)
+        .map_err(|err| WgslError::Translation(err.to_string()))
+}
+
+fn labels<'a>(source: &str, spans: impl Iterator<Item = (Range<usize>, &'a str)>) -> Vec<Label> {
+    spans
+        .map(|(range, message)| Label {
+            span: SourceSpan::new(source, range),
+            message: message.to_owned(),
+        })
+        .collect()
+}
+
+#[test]
+fn spans_have_line_and_column() {
+    let source = "fn main() {\n    let x = 1;\n    lét y = 2;\n}\n";
+    let start = source.find("y =").unwrap();
+    let span = SourceSpan::new(source, start..start + 1);
+    assert_eq!(
+        SourceSpan {
+            start,
+            end: start + 1,
+            line: 3,
+            column: 9,
+        },
+        span
+    );
+
+    let err = WgslError::Parse {
+        message: "expected ';'".to_owned(),
+        labels: vec![Label {
+            span,
+            message: "unknown identifier".to_owned(),
+        }],
+    };
+    assert_eq!(
+        "3:9: WGSL parse error: expected ';'\n  3:9: unknown identifier",
+        err.to_string()
+    );
+}
+
+#[test]
+fn invalid_source_is_reported_with_spans() {
+    let source =
+        "@fragment\nfn main() -> @location(0) vec4<f32> {\n    return vec4<f32>(1.0) +;\n}\n";
+    let err = translate(source).unwrap_err();
+    assert!(matches!(err, WgslError::Parse { .. }), "{}", err);
+    assert_eq!(3, err.labels()[0].span.line);
+
+    let source = "fn main() -> f32 {\n    return undefined;\n}\n";
+    let err = translate(source).unwrap_err();
+    assert_eq!(2, err.labels()[0].span.line, "{}", err);
+
+    let source = "@compute @workgroup_size(1)\nfn main() {}\n";
+    assert!(translate(source).is_ok());
+}
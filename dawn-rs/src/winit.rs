@@ -0,0 +1,280 @@
+//! Swap chains for winit windows.
+//!
+//! `WinitSurface` picks the way to present that the device backend supports: a native D3D12
+//! swap chain for Windows windows, a native Vulkan swap chain for Xlib, Xcb and Wayland
+//! windows and a surface based swap chain otherwise. It forwards `Resized` and
+//! `ScaleFactorChanged` to its `SurfaceManager`, and `tick` drives `Device::tick` from the
+//! event loop.
+//!
+//! ```no_run
+//! use dawn::winit::WinitSurface;
+//! use winit::event::{Event, WindowEvent};
+//! use winit::event_loop::{ControlFlow, EventLoop};
+//!
+//! let event_loop = EventLoop::new();
+//! let window = winit::window::Window::new(&event_loop).unwrap();
+//! let instance = dawn::Instance::new();
+//! let device = instance.enumerate_adapters()[0].create_device(&Default::default());
+//! let mut surface = WinitSurface::new(&instance, &device, &window, &Default::default()).unwrap();
+//!
+//! event_loop.run(move |event, _, control_flow| {
+//!     *control_flow = ControlFlow::Poll;
+//!     surface.handle_event(&event);
+//!     dawn::winit::tick(&device, &event);
+//!     match event {
+//!         Event::WindowEvent { event: WindowEvent::CloseRequested, .. } => {
+//!             *control_flow = ControlFlow::Exit;
+//!         }
+//!         Event::MainEventsCleared => {
+//!             let frame = surface.next_frame();
+//!             // ... render into `frame.view` and submit ...
+//!             surface.present();
+//!         }
+//!         _ => {}
+//!     }
+//! });
+//! ```
+
+use std::ops::{Deref, DerefMut};
+
+use ::winit::dpi::PhysicalSize;
+use ::winit::event::{Event, WindowEvent};
+use ::winit::window::{Window, WindowId};
+use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
+
+use crate::surface_manager::{SurfaceManager, SurfaceManagerDescriptor};
+use crate::{
+    native_swap_chain, BackendType, Device, Instance, PresentMode, SurfaceError, TextureFormat,
+};
+
+#[derive(Debug, Copy, Clone)]
+pub struct WinitSurfaceDescriptor {
+    /// `None` uses the format preferred by the swap chain.
+    pub format: Option<TextureFormat>,
+    pub present_mode: WinitPresentMode,
+    pub depth_format: Option<TextureFormat>,
+    pub sample_count: u32,
+}
+
+impl Default for WinitSurfaceDescriptor {
+    fn default() -> WinitSurfaceDescriptor {
+        WinitSurfaceDescriptor {
+            format: None,
+            present_mode: WinitPresentMode::VSync,
+            depth_format: None,
+            sample_count: 1,
+        }
+    }
+}
+
+/// How frames are synchronized with the display.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum WinitPresentMode {
+    /// Waits for the vertical blank. Supported everywhere.
+    VSync,
+    /// Replaces the queued frame with a newer one without tearing.
+    LowLatency,
+    /// Presents immediately and may tear.
+    NoVSync,
+}
+
+impl From<WinitPresentMode> for PresentMode {
+    fn from(present_mode: WinitPresentMode) -> PresentMode {
+        match present_mode {
+            WinitPresentMode::VSync => PresentMode::Fifo,
+            WinitPresentMode::LowLatency => PresentMode::Mailbox,
+            WinitPresentMode::NoVSync => PresentMode::Immediate,
+        }
+    }
+}
+
+/// A swap chain that presents to a winit window. Derefs to its `SurfaceManager`.
+pub struct WinitSurface {
+    window_id: WindowId,
+    scale_factor: f64,
+    manager: SurfaceManager,
+}
+
+impl WinitSurface {
+    /// Creates a swap chain for `window` with the window's current inner size.
+    pub fn new(
+        instance: &Instance,
+        device: &Device,
+        window: &Window,
+        descriptor: &WinitSurfaceDescriptor,
+    ) -> Result<WinitSurface, SurfaceError> {
+        let size = window.inner_size();
+        let manager_descriptor = SurfaceManagerDescriptor {
+            format: descriptor.format,
+            present_mode: descriptor.present_mode.into(),
+            depth_format: descriptor.depth_format,
+            sample_count: descriptor.sample_count,
+            ..SurfaceManagerDescriptor::new(size.width.max(1), size.height.max(1))
+        };
+        Ok(WinitSurface {
+            window_id: window.id(),
+            scale_factor: window.scale_factor(),
+            manager: create_surface_manager(instance, device, window, &manager_descriptor)?,
+        })
+    }
+
+    pub fn window_id(&self) -> WindowId {
+        self.window_id
+    }
+
+    /// The scale factor of the window from the last `ScaleFactorChanged` event.
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
+    /// Resizes the swap chain on `Resized` and `ScaleFactorChanged` events of the window.
+    /// Returns `true` if the event was a resize.
+    pub fn handle_event<T>(&mut self, event: &Event<T>) -> bool {
+        match resize_event(self.window_id, event) {
+            Some((size, scale_factor)) => {
+                if let Some(scale_factor) = scale_factor {
+                    self.scale_factor = scale_factor;
+                }
+                self.manager.resize(size.width, size.height);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn into_surface_manager(self) -> SurfaceManager {
+        self.manager
+    }
+}
+
+impl Deref for WinitSurface {
+    type Target = SurfaceManager;
+
+    fn deref(&self) -> &SurfaceManager {
+        &self.manager
+    }
+}
+
+impl DerefMut for WinitSurface {
+    fn deref_mut(&mut self) -> &mut SurfaceManager {
+        &mut self.manager
+    }
+}
+
+/// Ticks `device` once per iteration of the event loop, after the events of the iteration
+/// have been handled. This processes callbacks such as buffer mapping and errors.
+pub fn tick<T>(device: &Device, event: &Event<T>) {
+    if let Event::MainEventsCleared = event {
+        device.tick();
+    }
+}
+
+/// Returns the new inner size, and the new scale factor if it changed, for the resize events
+/// of the window `window_id`.
+fn resize_event<T>(
+    window_id: WindowId,
+    event: &Event<T>,
+) -> Option<(PhysicalSize<u32>, Option<f64>)> {
+    match event {
+        Event::WindowEvent {
+            window_id: event_window_id,
+            event,
+        } if *event_window_id == window_id => match event {
+            WindowEvent::Resized(size) => Some((*size, None)),
+            WindowEvent::ScaleFactorChanged {
+                scale_factor,
+                new_inner_size,
+            } => Some((**new_inner_size, Some(*scale_factor))),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn create_surface_manager(
+    instance: &Instance,
+    device: &Device,
+    window: &Window,
+    descriptor: &SurfaceManagerDescriptor,
+) -> Result<SurfaceManager, SurfaceError> {
+    let backend_type = device.inner.lock().backend_type;
+    let swap_chain = match (backend_type, window.raw_window_handle()) {
+        #[cfg(windows)]
+        (BackendType::D3D12, RawWindowHandle::Windows(handle)) => {
+            native_swap_chain::create_swap_chain(
+                device,
+                native_swap_chain::NativeSwapChainDescriptor {
+                    params: native_swap_chain::NativeSwapChainSurfaceParams::D3D12 {
+                        hwnd: handle.hwnd as _,
+                    },
+                    width: descriptor.width,
+                    height: descriptor.height,
+                    present_mode: descriptor.present_mode,
+                },
+            )
+        }
+        #[cfg(any(
+            target_os = "linux",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        ))]
+        (BackendType::Vulkan, RawWindowHandle::Xlib(_))
+        | (BackendType::Vulkan, RawWindowHandle::Xcb(_))
+        | (BackendType::Vulkan, RawWindowHandle::Wayland(_)) => {
+            let surface = native_swap_chain::create_vulkan_surface(device, window)
+                .map_err(SurfaceError::Vulkan)?;
+            native_swap_chain::create_swap_chain(
+                device,
+                native_swap_chain::NativeSwapChainDescriptor {
+                    params: native_swap_chain::NativeSwapChainSurfaceParams::Vulkan { surface },
+                    width: descriptor.width,
+                    height: descriptor.height,
+                    present_mode: descriptor.present_mode,
+                },
+            )
+        }
+        _ => {
+            let surface = instance.create_surface(window)?;
+            return Ok(SurfaceManager::with_surface(device, &surface, descriptor));
+        }
+    };
+    Ok(SurfaceManager::new(device, swap_chain, descriptor))
+}
+
+#[test]
+fn resize_events_are_mapped_for_the_window() {
+    let window_id = unsafe { WindowId::dummy() };
+    let resized: Event<()> = Event::WindowEvent {
+        window_id,
+        event: WindowEvent::Resized(PhysicalSize::new(640, 480)),
+    };
+    assert_eq!(
+        Some((PhysicalSize::new(640, 480), None)),
+        resize_event(window_id, &resized)
+    );
+
+    let mut new_inner_size = PhysicalSize::new(1280, 960);
+    let scale_factor_changed: Event<()> = Event::WindowEvent {
+        window_id,
+        event: WindowEvent::ScaleFactorChanged {
+            scale_factor: 2.0,
+            new_inner_size: &mut new_inner_size,
+        },
+    };
+    assert_eq!(
+        Some((PhysicalSize::new(1280, 960), Some(2.0))),
+        resize_event(window_id, &scale_factor_changed)
+    );
+
+    let focused: Event<()> = Event::WindowEvent {
+        window_id,
+        event: WindowEvent::Focused(true),
+    };
+    assert_eq!(None, resize_event(window_id, &focused));
+    assert_eq!(
+        None,
+        resize_event::<()>(window_id, &Event::MainEventsCleared)
+    );
+}
@@ -21,6 +21,15 @@ fn main() {
     println!("cargo:rerun-if-changed=dawnc/dawnc.h");
     println!("cargo:rerun-if-changed=dawn");
 
+    // The `wgpu*` functions are dispatched in Rust, so there's nothing to build or link.
+    if cfg!(feature = "dispatch") {
+        assert!(
+            !cfg!(feature = "bindgen"),
+            "the `dispatch` and `bindgen` features can't be combined"
+        );
+        return;
+    }
+
     println!("cargo:rustc-link-lib=dawn_native.dll");
     println!("cargo:rustc-link-lib=libdawn_proc.dll");
     println!("cargo:rustc-link-lib=libc++.dll");
@@ -581,4 +590,35 @@ fn bindgen(out_dir: &PathBuf, out_dir_dawn_src: &PathBuf, out_dir_dawn_out: &Pat
     output
         .write_to_file(&out_dir.join("dawn_wsi.rs"))
         .expect("failed to write dawn_wsi.rs");
+
+    // The official `webgpu.h` from webgpu-headers, for `webgpu_native`. It isn't part of Dawn,
+    // so the baked bindings are used unless `DAWN_SYS_WEBGPU_HEADER` points at a copy.
+    println!("cargo:rerun-if-env-changed=DAWN_SYS_WEBGPU_HEADER");
+    let out_file = out_dir.join("webgpu_native.rs");
+    let header = match env::var("DAWN_SYS_WEBGPU_HEADER") {
+        Ok(header) => header,
+        Err(_) => {
+            std::fs::copy("src/webgpu_headers.rs", &out_file)
+                .expect("failed to copy webgpu_headers.rs");
+            return;
+        }
+    };
+    // Implementations are loaded at runtime, so only the types and `WGPUProc*` pointers are
+    // generated. The function declarations would clash with Dawn's.
+    let output = bindgen::builder()
+        .header(header)
+        .whitelist_type("WGPU.*")
+        .whitelist_var("WGPU.*")
+        .blacklist_function(".*")
+        .ctypes_prefix("libc")
+        .use_core()
+        .impl_debug(true)
+        .impl_partialeq(true)
+        .prepend_enum_name(false)
+        .generate()
+        .expect("bindgen failed");
+
+    output
+        .write_to_file(&out_file)
+        .expect("failed to write webgpu_native.rs");
 }
@@ -84,6 +84,10 @@ void dawn_native__Adapter__GetProperties(WGPUInstance instance, size_t adapterIn
     properties->backendType = ConvertBackendType(adapterProperties.backendType);
 }
 
+size_t dawn_native__GetLazyClearCountForTesting(WGPUDevice device) {
+    return dawn_native::GetLazyClearCountForTesting(device);
+}
+
 VkInstance dawn_native__vulkan__GetInstance(WGPUDevice device) {
     return dawn_native::vulkan::GetInstance(device);
 }
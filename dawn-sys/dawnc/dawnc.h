@@ -2,6 +2,7 @@
 
 #define DAWNC_EXPORT WGPU_EXPORT extern "C"
 
+#include <dawn_native/DawnNative.h>
 #include <dawn_native/Instance.h>
 #include <dawn_native/Adapter.h>
 #include <dawn_native/Device.h>
@@ -33,6 +34,7 @@ DAWNC_EXPORT void dawn_native__Instance__DiscoverDefaultAdapters(const WGPUInsta
 DAWNC_EXPORT size_t dawn_native__Instance__GetAdaptersCount(const WGPUInstance instance);
 DAWNC_EXPORT WGPUDeviceProperties dawn_native__Adapter__GetAdapterProperties(WGPUInstance instance, size_t adapterIndex);
 DAWNC_EXPORT void dawn_native__Adapter__GetProperties(WGPUInstance instance, size_t adapterIndex, WGPUAdapterProperties* properties);
+DAWNC_EXPORT size_t dawn_native__GetLazyClearCountForTesting(WGPUDevice device);
 DAWNC_EXPORT VkInstance dawn_native__vulkan__GetInstance(WGPUDevice device);
 DAWNC_EXPORT WGPUDevice dawn_native__Adapter__CreateDevice(WGPUInstance instance, size_t adapterIndex, const DeviceDescriptor* descriptor);
 DAWNC_EXPORT WGPUTextureFormat dawn_native__vulkan__GetNativeSwapChainPreferredFormat(const DawnSwapChainImplementation* swapChainImpl);
@@ -0,0 +1,278 @@
+//! A Rust stand-in for `libdawn_proc` and the `dawn_native` shims, used instead of building and
+//! linking Dawn when the `dispatch` feature is enabled.
+//!
+//! Every `wgpu*` function forwards to the proc table installed with `dawnProcSetProcs`, so only
+//! a table implemented in Rust, such as the dawn-rs mock, can be used. There is no Dawn to
+//! discover adapters with: `dawn_native__GetProcs` returns an empty table, no adapters are found
+//! and the remaining shims abort.
+
+#![allow(non_snake_case)]
+
+use crate::*;
+
+use std::sync::atomic::{AtomicPtr, Ordering};
+use std::{process, ptr};
+
+/// The installed table. Replaced tables are leaked, since other threads may still be reading
+/// them.
+static PROCS: AtomicPtr<DawnProcTable> = AtomicPtr::new(ptr::null_mut());
+
+fn procs() -> &'static DawnProcTable {
+    let proc_table = PROCS.load(Ordering::Acquire);
+    if proc_table.is_null() {
+        eprintln!("dawn-sys: a wgpu function was called before dawnProcSetProcs");
+        process::abort()
+    }
+    unsafe { &*proc_table }
+}
+
+fn unavailable(name: &str) -> ! {
+    eprintln!(
+        "dawn-sys: {} isn't available, Dawn isn't linked with the `dispatch` feature",
+        name
+    );
+    process::abort()
+}
+
+macro_rules! dispatch {
+    ($($field:ident => fn $name:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)?;)*) => {
+        $(
+            #[no_mangle]
+            pub unsafe extern "C" fn $name($($arg: $ty),*) $(-> $ret)? {
+                match procs().$field {
+                    Some(f) => f($($arg),*),
+                    None => unavailable(stringify!($name)),
+                }
+            }
+        )*
+    };
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dawnProcSetProcs(proc_table: *const DawnProcTable) {
+    let proc_table = if proc_table.is_null() {
+        ptr::null_mut()
+    } else {
+        Box::into_raw(Box::new(ptr::read(proc_table)))
+    };
+    PROCS.store(proc_table, Ordering::Release);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dawn_native__GetProcs(proc_table: *mut DawnProcTable) {
+    ptr::write_bytes(proc_table, 0, 1);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dawn_native__Instance__DiscoverDefaultAdapters(_instance: WGPUInstance) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn dawn_native__Instance__GetAdaptersCount(_instance: WGPUInstance) -> usize {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dawn_native__Adapter__GetAdapterProperties(
+    _instance: WGPUInstance,
+    _adapter_index: usize,
+) -> WGPUDeviceProperties {
+    unavailable("dawn_native::Adapter::GetAdapterProperties")
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dawn_native__Adapter__GetProperties(
+    _instance: WGPUInstance,
+    _adapter_index: usize,
+    _properties: *mut WGPUAdapterProperties,
+) {
+    unavailable("dawn_native::Adapter::GetProperties")
+}
+
+/// Nothing is ever lazily cleared without Dawn.
+#[no_mangle]
+pub unsafe extern "C" fn dawn_native__GetLazyClearCountForTesting(_device: WGPUDevice) -> usize {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dawn_native__vulkan__GetInstance(_device: WGPUDevice) -> VkInstance {
+    unavailable("dawn_native::vulkan::GetInstance")
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dawn_native__Adapter__CreateDevice(
+    _instance: WGPUInstance,
+    _adapter_index: usize,
+    _descriptor: *const DeviceDescriptor,
+) -> WGPUDevice {
+    unavailable("dawn_native::Adapter::CreateDevice")
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dawn_native__vulkan__GetNativeSwapChainPreferredFormat(
+    _swap_chain_impl: *const DawnSwapChainImplementation,
+) -> WGPUTextureFormat {
+    unavailable("dawn_native::vulkan::GetNativeSwapChainPreferredFormat")
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dawn_native__vulkan__CreateNativeSwapChainImpl(
+    _device: WGPUDevice,
+    _surface: VkSurfaceKHR,
+) -> DawnSwapChainImplementation {
+    unavailable("dawn_native::vulkan::CreateNativeSwapChainImpl")
+}
+
+#[cfg(windows)]
+#[no_mangle]
+pub unsafe extern "C" fn dawn_native__d3d12__GetNativeSwapChainPreferredFormat(
+    _swap_chain_impl: *const DawnSwapChainImplementation,
+) -> WGPUTextureFormat {
+    unavailable("dawn_native::d3d12::GetNativeSwapChainPreferredFormat")
+}
+
+#[cfg(windows)]
+#[no_mangle]
+pub unsafe extern "C" fn dawn_native__d3d12__CreateNativeSwapChainImpl(
+    _device: WGPUDevice,
+    _hwnd: HWND,
+) -> DawnSwapChainImplementation {
+    unavailable("dawn_native::d3d12::CreateNativeSwapChainImpl")
+}
+
+#[rustfmt::skip]
+dispatch! {
+    createInstance => fn wgpuCreateInstance(descriptor: *const WGPUInstanceDescriptor) -> WGPUInstance;
+    getProcAddress => fn wgpuGetProcAddress(device: WGPUDevice, procName: *const libc::c_char) -> WGPUProc;
+    bindGroupReference => fn wgpuBindGroupReference(bindGroup: WGPUBindGroup);
+    bindGroupRelease => fn wgpuBindGroupRelease(bindGroup: WGPUBindGroup);
+    bindGroupLayoutReference => fn wgpuBindGroupLayoutReference(bindGroupLayout: WGPUBindGroupLayout);
+    bindGroupLayoutRelease => fn wgpuBindGroupLayoutRelease(bindGroupLayout: WGPUBindGroupLayout);
+    bufferDestroy => fn wgpuBufferDestroy(buffer: WGPUBuffer);
+    bufferMapReadAsync => fn wgpuBufferMapReadAsync(buffer: WGPUBuffer, callback: WGPUBufferMapReadCallback, userdata: *mut libc::c_void);
+    bufferMapWriteAsync => fn wgpuBufferMapWriteAsync(buffer: WGPUBuffer, callback: WGPUBufferMapWriteCallback, userdata: *mut libc::c_void);
+    bufferSetSubData => fn wgpuBufferSetSubData(buffer: WGPUBuffer, start: u64, count: u64, data: *const libc::c_void);
+    bufferUnmap => fn wgpuBufferUnmap(buffer: WGPUBuffer);
+    bufferReference => fn wgpuBufferReference(buffer: WGPUBuffer);
+    bufferRelease => fn wgpuBufferRelease(buffer: WGPUBuffer);
+    commandBufferReference => fn wgpuCommandBufferReference(commandBuffer: WGPUCommandBuffer);
+    commandBufferRelease => fn wgpuCommandBufferRelease(commandBuffer: WGPUCommandBuffer);
+    commandEncoderBeginComputePass => fn wgpuCommandEncoderBeginComputePass(commandEncoder: WGPUCommandEncoder, descriptor: *const WGPUComputePassDescriptor) -> WGPUComputePassEncoder;
+    commandEncoderBeginRenderPass => fn wgpuCommandEncoderBeginRenderPass(commandEncoder: WGPUCommandEncoder, descriptor: *const WGPURenderPassDescriptor) -> WGPURenderPassEncoder;
+    commandEncoderCopyBufferToBuffer => fn wgpuCommandEncoderCopyBufferToBuffer(commandEncoder: WGPUCommandEncoder, source: WGPUBuffer, sourceOffset: u64, destination: WGPUBuffer, destinationOffset: u64, size: u64);
+    commandEncoderCopyBufferToTexture => fn wgpuCommandEncoderCopyBufferToTexture(commandEncoder: WGPUCommandEncoder, source: *const WGPUBufferCopyView, destination: *const WGPUTextureCopyView, copySize: *const WGPUExtent3D);
+    commandEncoderCopyTextureToBuffer => fn wgpuCommandEncoderCopyTextureToBuffer(commandEncoder: WGPUCommandEncoder, source: *const WGPUTextureCopyView, destination: *const WGPUBufferCopyView, copySize: *const WGPUExtent3D);
+    commandEncoderCopyTextureToTexture => fn wgpuCommandEncoderCopyTextureToTexture(commandEncoder: WGPUCommandEncoder, source: *const WGPUTextureCopyView, destination: *const WGPUTextureCopyView, copySize: *const WGPUExtent3D);
+    commandEncoderFinish => fn wgpuCommandEncoderFinish(commandEncoder: WGPUCommandEncoder, descriptor: *const WGPUCommandBufferDescriptor) -> WGPUCommandBuffer;
+    commandEncoderInsertDebugMarker => fn wgpuCommandEncoderInsertDebugMarker(commandEncoder: WGPUCommandEncoder, groupLabel: *const libc::c_char);
+    commandEncoderPopDebugGroup => fn wgpuCommandEncoderPopDebugGroup(commandEncoder: WGPUCommandEncoder);
+    commandEncoderPushDebugGroup => fn wgpuCommandEncoderPushDebugGroup(commandEncoder: WGPUCommandEncoder, groupLabel: *const libc::c_char);
+    commandEncoderReference => fn wgpuCommandEncoderReference(commandEncoder: WGPUCommandEncoder);
+    commandEncoderRelease => fn wgpuCommandEncoderRelease(commandEncoder: WGPUCommandEncoder);
+    computePassEncoderDispatch => fn wgpuComputePassEncoderDispatch(computePassEncoder: WGPUComputePassEncoder, x: u32, y: u32, z: u32);
+    computePassEncoderDispatchIndirect => fn wgpuComputePassEncoderDispatchIndirect(computePassEncoder: WGPUComputePassEncoder, indirectBuffer: WGPUBuffer, indirectOffset: u64);
+    computePassEncoderEndPass => fn wgpuComputePassEncoderEndPass(computePassEncoder: WGPUComputePassEncoder);
+    computePassEncoderInsertDebugMarker => fn wgpuComputePassEncoderInsertDebugMarker(computePassEncoder: WGPUComputePassEncoder, groupLabel: *const libc::c_char);
+    computePassEncoderPopDebugGroup => fn wgpuComputePassEncoderPopDebugGroup(computePassEncoder: WGPUComputePassEncoder);
+    computePassEncoderPushDebugGroup => fn wgpuComputePassEncoderPushDebugGroup(computePassEncoder: WGPUComputePassEncoder, groupLabel: *const libc::c_char);
+    computePassEncoderSetBindGroup => fn wgpuComputePassEncoderSetBindGroup(computePassEncoder: WGPUComputePassEncoder, groupIndex: u32, group: WGPUBindGroup, dynamicOffsetCount: u32, dynamicOffsets: *const u32);
+    computePassEncoderSetPipeline => fn wgpuComputePassEncoderSetPipeline(computePassEncoder: WGPUComputePassEncoder, pipeline: WGPUComputePipeline);
+    computePassEncoderReference => fn wgpuComputePassEncoderReference(computePassEncoder: WGPUComputePassEncoder);
+    computePassEncoderRelease => fn wgpuComputePassEncoderRelease(computePassEncoder: WGPUComputePassEncoder);
+    computePipelineGetBindGroupLayout => fn wgpuComputePipelineGetBindGroupLayout(computePipeline: WGPUComputePipeline, groupIndex: u32) -> WGPUBindGroupLayout;
+    computePipelineReference => fn wgpuComputePipelineReference(computePipeline: WGPUComputePipeline);
+    computePipelineRelease => fn wgpuComputePipelineRelease(computePipeline: WGPUComputePipeline);
+    deviceCreateBindGroup => fn wgpuDeviceCreateBindGroup(device: WGPUDevice, descriptor: *const WGPUBindGroupDescriptor) -> WGPUBindGroup;
+    deviceCreateBindGroupLayout => fn wgpuDeviceCreateBindGroupLayout(device: WGPUDevice, descriptor: *const WGPUBindGroupLayoutDescriptor) -> WGPUBindGroupLayout;
+    deviceCreateBuffer => fn wgpuDeviceCreateBuffer(device: WGPUDevice, descriptor: *const WGPUBufferDescriptor) -> WGPUBuffer;
+    deviceCreateBufferMapped => fn wgpuDeviceCreateBufferMapped(device: WGPUDevice, descriptor: *const WGPUBufferDescriptor) -> WGPUCreateBufferMappedResult;
+    deviceCreateBufferMappedAsync => fn wgpuDeviceCreateBufferMappedAsync(device: WGPUDevice, descriptor: *const WGPUBufferDescriptor, callback: WGPUBufferCreateMappedCallback, userdata: *mut libc::c_void);
+    deviceCreateCommandEncoder => fn wgpuDeviceCreateCommandEncoder(device: WGPUDevice, descriptor: *const WGPUCommandEncoderDescriptor) -> WGPUCommandEncoder;
+    deviceCreateComputePipeline => fn wgpuDeviceCreateComputePipeline(device: WGPUDevice, descriptor: *const WGPUComputePipelineDescriptor) -> WGPUComputePipeline;
+    deviceCreatePipelineLayout => fn wgpuDeviceCreatePipelineLayout(device: WGPUDevice, descriptor: *const WGPUPipelineLayoutDescriptor) -> WGPUPipelineLayout;
+    deviceCreateQueue => fn wgpuDeviceCreateQueue(device: WGPUDevice) -> WGPUQueue;
+    deviceCreateRenderBundleEncoder => fn wgpuDeviceCreateRenderBundleEncoder(device: WGPUDevice, descriptor: *const WGPURenderBundleEncoderDescriptor) -> WGPURenderBundleEncoder;
+    deviceCreateRenderPipeline => fn wgpuDeviceCreateRenderPipeline(device: WGPUDevice, descriptor: *const WGPURenderPipelineDescriptor) -> WGPURenderPipeline;
+    deviceCreateSampler => fn wgpuDeviceCreateSampler(device: WGPUDevice, descriptor: *const WGPUSamplerDescriptor) -> WGPUSampler;
+    deviceCreateShaderModule => fn wgpuDeviceCreateShaderModule(device: WGPUDevice, descriptor: *const WGPUShaderModuleDescriptor) -> WGPUShaderModule;
+    deviceCreateSwapChain => fn wgpuDeviceCreateSwapChain(device: WGPUDevice, surface: WGPUSurface, descriptor: *const WGPUSwapChainDescriptor) -> WGPUSwapChain;
+    deviceCreateTexture => fn wgpuDeviceCreateTexture(device: WGPUDevice, descriptor: *const WGPUTextureDescriptor) -> WGPUTexture;
+    deviceInjectError => fn wgpuDeviceInjectError(device: WGPUDevice, type_: WGPUErrorType, message: *const libc::c_char);
+    deviceLoseForTesting => fn wgpuDeviceLoseForTesting(device: WGPUDevice);
+    devicePopErrorScope => fn wgpuDevicePopErrorScope(device: WGPUDevice, callback: WGPUErrorCallback, userdata: *mut libc::c_void) -> bool;
+    devicePushErrorScope => fn wgpuDevicePushErrorScope(device: WGPUDevice, filter: WGPUErrorFilter);
+    deviceSetDeviceLostCallback => fn wgpuDeviceSetDeviceLostCallback(device: WGPUDevice, callback: WGPUDeviceLostCallback, userdata: *mut libc::c_void);
+    deviceSetUncapturedErrorCallback => fn wgpuDeviceSetUncapturedErrorCallback(device: WGPUDevice, callback: WGPUErrorCallback, userdata: *mut libc::c_void);
+    deviceTick => fn wgpuDeviceTick(device: WGPUDevice);
+    deviceReference => fn wgpuDeviceReference(device: WGPUDevice);
+    deviceRelease => fn wgpuDeviceRelease(device: WGPUDevice);
+    fenceGetCompletedValue => fn wgpuFenceGetCompletedValue(fence: WGPUFence) -> u64;
+    fenceOnCompletion => fn wgpuFenceOnCompletion(fence: WGPUFence, value: u64, callback: WGPUFenceOnCompletionCallback, userdata: *mut libc::c_void);
+    fenceReference => fn wgpuFenceReference(fence: WGPUFence);
+    fenceRelease => fn wgpuFenceRelease(fence: WGPUFence);
+    instanceCreateSurface => fn wgpuInstanceCreateSurface(instance: WGPUInstance, descriptor: *const WGPUSurfaceDescriptor) -> WGPUSurface;
+    instanceReference => fn wgpuInstanceReference(instance: WGPUInstance);
+    instanceRelease => fn wgpuInstanceRelease(instance: WGPUInstance);
+    pipelineLayoutReference => fn wgpuPipelineLayoutReference(pipelineLayout: WGPUPipelineLayout);
+    pipelineLayoutRelease => fn wgpuPipelineLayoutRelease(pipelineLayout: WGPUPipelineLayout);
+    queueCreateFence => fn wgpuQueueCreateFence(queue: WGPUQueue, descriptor: *const WGPUFenceDescriptor) -> WGPUFence;
+    queueSignal => fn wgpuQueueSignal(queue: WGPUQueue, fence: WGPUFence, signalValue: u64);
+    queueSubmit => fn wgpuQueueSubmit(queue: WGPUQueue, commandCount: u32, commands: *const WGPUCommandBuffer);
+    queueReference => fn wgpuQueueReference(queue: WGPUQueue);
+    queueRelease => fn wgpuQueueRelease(queue: WGPUQueue);
+    renderBundleReference => fn wgpuRenderBundleReference(renderBundle: WGPURenderBundle);
+    renderBundleRelease => fn wgpuRenderBundleRelease(renderBundle: WGPURenderBundle);
+    renderBundleEncoderDraw => fn wgpuRenderBundleEncoderDraw(renderBundleEncoder: WGPURenderBundleEncoder, vertexCount: u32, instanceCount: u32, firstVertex: u32, firstInstance: u32);
+    renderBundleEncoderDrawIndexed => fn wgpuRenderBundleEncoderDrawIndexed(renderBundleEncoder: WGPURenderBundleEncoder, indexCount: u32, instanceCount: u32, firstIndex: u32, baseVertex: i32, firstInstance: u32);
+    renderBundleEncoderDrawIndexedIndirect => fn wgpuRenderBundleEncoderDrawIndexedIndirect(renderBundleEncoder: WGPURenderBundleEncoder, indirectBuffer: WGPUBuffer, indirectOffset: u64);
+    renderBundleEncoderDrawIndirect => fn wgpuRenderBundleEncoderDrawIndirect(renderBundleEncoder: WGPURenderBundleEncoder, indirectBuffer: WGPUBuffer, indirectOffset: u64);
+    renderBundleEncoderFinish => fn wgpuRenderBundleEncoderFinish(renderBundleEncoder: WGPURenderBundleEncoder, descriptor: *const WGPURenderBundleDescriptor) -> WGPURenderBundle;
+    renderBundleEncoderInsertDebugMarker => fn wgpuRenderBundleEncoderInsertDebugMarker(renderBundleEncoder: WGPURenderBundleEncoder, groupLabel: *const libc::c_char);
+    renderBundleEncoderPopDebugGroup => fn wgpuRenderBundleEncoderPopDebugGroup(renderBundleEncoder: WGPURenderBundleEncoder);
+    renderBundleEncoderPushDebugGroup => fn wgpuRenderBundleEncoderPushDebugGroup(renderBundleEncoder: WGPURenderBundleEncoder, groupLabel: *const libc::c_char);
+    renderBundleEncoderSetBindGroup => fn wgpuRenderBundleEncoderSetBindGroup(renderBundleEncoder: WGPURenderBundleEncoder, groupIndex: u32, group: WGPUBindGroup, dynamicOffsetCount: u32, dynamicOffsets: *const u32);
+    renderBundleEncoderSetIndexBuffer => fn wgpuRenderBundleEncoderSetIndexBuffer(renderBundleEncoder: WGPURenderBundleEncoder, buffer: WGPUBuffer, offset: u64);
+    renderBundleEncoderSetPipeline => fn wgpuRenderBundleEncoderSetPipeline(renderBundleEncoder: WGPURenderBundleEncoder, pipeline: WGPURenderPipeline);
+    renderBundleEncoderSetVertexBuffer => fn wgpuRenderBundleEncoderSetVertexBuffer(renderBundleEncoder: WGPURenderBundleEncoder, slot: u32, buffer: WGPUBuffer, offset: u64);
+    renderBundleEncoderReference => fn wgpuRenderBundleEncoderReference(renderBundleEncoder: WGPURenderBundleEncoder);
+    renderBundleEncoderRelease => fn wgpuRenderBundleEncoderRelease(renderBundleEncoder: WGPURenderBundleEncoder);
+    renderPassEncoderDraw => fn wgpuRenderPassEncoderDraw(renderPassEncoder: WGPURenderPassEncoder, vertexCount: u32, instanceCount: u32, firstVertex: u32, firstInstance: u32);
+    renderPassEncoderDrawIndexed => fn wgpuRenderPassEncoderDrawIndexed(renderPassEncoder: WGPURenderPassEncoder, indexCount: u32, instanceCount: u32, firstIndex: u32, baseVertex: i32, firstInstance: u32);
+    renderPassEncoderDrawIndexedIndirect => fn wgpuRenderPassEncoderDrawIndexedIndirect(renderPassEncoder: WGPURenderPassEncoder, indirectBuffer: WGPUBuffer, indirectOffset: u64);
+    renderPassEncoderDrawIndirect => fn wgpuRenderPassEncoderDrawIndirect(renderPassEncoder: WGPURenderPassEncoder, indirectBuffer: WGPUBuffer, indirectOffset: u64);
+    renderPassEncoderEndPass => fn wgpuRenderPassEncoderEndPass(renderPassEncoder: WGPURenderPassEncoder);
+    renderPassEncoderExecuteBundles => fn wgpuRenderPassEncoderExecuteBundles(renderPassEncoder: WGPURenderPassEncoder, bundlesCount: u32, bundles: *const WGPURenderBundle);
+    renderPassEncoderInsertDebugMarker => fn wgpuRenderPassEncoderInsertDebugMarker(renderPassEncoder: WGPURenderPassEncoder, groupLabel: *const libc::c_char);
+    renderPassEncoderPopDebugGroup => fn wgpuRenderPassEncoderPopDebugGroup(renderPassEncoder: WGPURenderPassEncoder);
+    renderPassEncoderPushDebugGroup => fn wgpuRenderPassEncoderPushDebugGroup(renderPassEncoder: WGPURenderPassEncoder, groupLabel: *const libc::c_char);
+    renderPassEncoderSetBindGroup => fn wgpuRenderPassEncoderSetBindGroup(renderPassEncoder: WGPURenderPassEncoder, groupIndex: u32, group: WGPUBindGroup, dynamicOffsetCount: u32, dynamicOffsets: *const u32);
+    renderPassEncoderSetBlendColor => fn wgpuRenderPassEncoderSetBlendColor(renderPassEncoder: WGPURenderPassEncoder, color: *const WGPUColor);
+    renderPassEncoderSetIndexBuffer => fn wgpuRenderPassEncoderSetIndexBuffer(renderPassEncoder: WGPURenderPassEncoder, buffer: WGPUBuffer, offset: u64);
+    renderPassEncoderSetPipeline => fn wgpuRenderPassEncoderSetPipeline(renderPassEncoder: WGPURenderPassEncoder, pipeline: WGPURenderPipeline);
+    renderPassEncoderSetScissorRect => fn wgpuRenderPassEncoderSetScissorRect(renderPassEncoder: WGPURenderPassEncoder, x: u32, y: u32, width: u32, height: u32);
+    renderPassEncoderSetStencilReference => fn wgpuRenderPassEncoderSetStencilReference(renderPassEncoder: WGPURenderPassEncoder, reference: u32);
+    renderPassEncoderSetVertexBuffer => fn wgpuRenderPassEncoderSetVertexBuffer(renderPassEncoder: WGPURenderPassEncoder, slot: u32, buffer: WGPUBuffer, offset: u64);
+    renderPassEncoderSetViewport => fn wgpuRenderPassEncoderSetViewport(renderPassEncoder: WGPURenderPassEncoder, x: f32, y: f32, width: f32, height: f32, minDepth: f32, maxDepth: f32);
+    renderPassEncoderReference => fn wgpuRenderPassEncoderReference(renderPassEncoder: WGPURenderPassEncoder);
+    renderPassEncoderRelease => fn wgpuRenderPassEncoderRelease(renderPassEncoder: WGPURenderPassEncoder);
+    renderPipelineGetBindGroupLayout => fn wgpuRenderPipelineGetBindGroupLayout(renderPipeline: WGPURenderPipeline, groupIndex: u32) -> WGPUBindGroupLayout;
+    renderPipelineReference => fn wgpuRenderPipelineReference(renderPipeline: WGPURenderPipeline);
+    renderPipelineRelease => fn wgpuRenderPipelineRelease(renderPipeline: WGPURenderPipeline);
+    samplerReference => fn wgpuSamplerReference(sampler: WGPUSampler);
+    samplerRelease => fn wgpuSamplerRelease(sampler: WGPUSampler);
+    shaderModuleReference => fn wgpuShaderModuleReference(shaderModule: WGPUShaderModule);
+    shaderModuleRelease => fn wgpuShaderModuleRelease(shaderModule: WGPUShaderModule);
+    surfaceReference => fn wgpuSurfaceReference(surface: WGPUSurface);
+    surfaceRelease => fn wgpuSurfaceRelease(surface: WGPUSurface);
+    swapChainConfigure => fn wgpuSwapChainConfigure(swapChain: WGPUSwapChain, format: WGPUTextureFormat, allowedUsage: WGPUTextureUsageFlags, width: u32, height: u32);
+    swapChainGetCurrentTextureView => fn wgpuSwapChainGetCurrentTextureView(swapChain: WGPUSwapChain) -> WGPUTextureView;
+    swapChainPresent => fn wgpuSwapChainPresent(swapChain: WGPUSwapChain);
+    swapChainReference => fn wgpuSwapChainReference(swapChain: WGPUSwapChain);
+    swapChainRelease => fn wgpuSwapChainRelease(swapChain: WGPUSwapChain);
+    textureCreateView => fn wgpuTextureCreateView(texture: WGPUTexture, descriptor: *const WGPUTextureViewDescriptor) -> WGPUTextureView;
+    textureDestroy => fn wgpuTextureDestroy(texture: WGPUTexture);
+    textureReference => fn wgpuTextureReference(texture: WGPUTexture);
+    textureRelease => fn wgpuTextureRelease(texture: WGPUTexture);
+    textureViewReference => fn wgpuTextureViewReference(textureView: WGPUTextureView);
+    textureViewRelease => fn wgpuTextureViewRelease(textureView: WGPUTextureView);
+}
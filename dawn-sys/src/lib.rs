@@ -69,6 +69,12 @@ pub mod dawn_wsi {
 
 pub use dawn_wsi::*;
 
+/// Bindings and a loader for the official `webgpu.h`, implemented by e.g. wgpu-native.
+pub mod webgpu_native;
+
+#[cfg(feature = "dispatch")]
+mod dispatch;
+
 // A physical device and backend.
 // #[repr(C)]
 // #[derive(Debug, Copy, Clone)]
@@ -192,6 +198,10 @@ extern "C" {
         properties: *mut WGPUAdapterProperties,
     );
 
+    /// The number of times a resource was cleared because it was used before being
+    /// initialized.
+    pub fn dawn_native__GetLazyClearCountForTesting(device: WGPUDevice) -> usize;
+
     pub fn dawn_native__vulkan__GetInstance(device: WGPUDevice) -> VkInstance;
 
     pub fn dawn_native__Adapter__CreateDevice(
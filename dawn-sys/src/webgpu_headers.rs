@@ -0,0 +1,4734 @@
+/* The types and `WGPUProc*` pointers of webgpu-headers' `webgpu.h`, with bindgen's layout tests. */
+
+pub const WGPU_WHOLE_SIZE: u64 = 18446744073709551615;
+pub type WGPUFlags = u32;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUAdapterImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUAdapter = *mut WGPUAdapterImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUBindGroupImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUBindGroup = *mut WGPUBindGroupImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUBindGroupLayoutImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUBindGroupLayout = *mut WGPUBindGroupLayoutImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUBufferImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUBuffer = *mut WGPUBufferImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUCommandBufferImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUCommandBuffer = *mut WGPUCommandBufferImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUCommandEncoderImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUCommandEncoder = *mut WGPUCommandEncoderImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUComputePassEncoderImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUComputePassEncoder = *mut WGPUComputePassEncoderImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUComputePipelineImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUComputePipeline = *mut WGPUComputePipelineImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUDeviceImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUDevice = *mut WGPUDeviceImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUFenceImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUFence = *mut WGPUFenceImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUInstanceImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUInstance = *mut WGPUInstanceImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUPipelineLayoutImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUPipelineLayout = *mut WGPUPipelineLayoutImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUQuerySetImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUQuerySet = *mut WGPUQuerySetImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUQueueImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUQueue = *mut WGPUQueueImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPURenderBundleImpl {
+    _unused: [u8; 0],
+}
+pub type WGPURenderBundle = *mut WGPURenderBundleImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPURenderBundleEncoderImpl {
+    _unused: [u8; 0],
+}
+pub type WGPURenderBundleEncoder = *mut WGPURenderBundleEncoderImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPURenderPassEncoderImpl {
+    _unused: [u8; 0],
+}
+pub type WGPURenderPassEncoder = *mut WGPURenderPassEncoderImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPURenderPipelineImpl {
+    _unused: [u8; 0],
+}
+pub type WGPURenderPipeline = *mut WGPURenderPipelineImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUSamplerImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUSampler = *mut WGPUSamplerImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUShaderModuleImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUShaderModule = *mut WGPUShaderModuleImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUSurfaceImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUSurface = *mut WGPUSurfaceImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUSwapChainImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUSwapChain = *mut WGPUSwapChainImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUTextureImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUTexture = *mut WGPUTextureImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUTextureViewImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUTextureView = *mut WGPUTextureViewImpl;
+pub const WGPUAdapterType_DiscreteGPU: WGPUAdapterType = 0;
+pub const WGPUAdapterType_IntegratedGPU: WGPUAdapterType = 1;
+pub const WGPUAdapterType_CPU: WGPUAdapterType = 2;
+pub const WGPUAdapterType_Unknown: WGPUAdapterType = 3;
+pub const WGPUAdapterType_Force32: WGPUAdapterType = 2147483647;
+pub type WGPUAdapterType = i32;
+pub const WGPUAddressMode_Repeat: WGPUAddressMode = 0;
+pub const WGPUAddressMode_MirrorRepeat: WGPUAddressMode = 1;
+pub const WGPUAddressMode_ClampToEdge: WGPUAddressMode = 2;
+pub const WGPUAddressMode_Force32: WGPUAddressMode = 2147483647;
+pub type WGPUAddressMode = i32;
+pub const WGPUBackendType_Null: WGPUBackendType = 0;
+pub const WGPUBackendType_D3D11: WGPUBackendType = 1;
+pub const WGPUBackendType_D3D12: WGPUBackendType = 2;
+pub const WGPUBackendType_Metal: WGPUBackendType = 3;
+pub const WGPUBackendType_Vulkan: WGPUBackendType = 4;
+pub const WGPUBackendType_OpenGL: WGPUBackendType = 5;
+pub const WGPUBackendType_OpenGLES: WGPUBackendType = 6;
+pub const WGPUBackendType_Force32: WGPUBackendType = 2147483647;
+pub type WGPUBackendType = i32;
+pub const WGPUBindingType_UniformBuffer: WGPUBindingType = 0;
+pub const WGPUBindingType_StorageBuffer: WGPUBindingType = 1;
+pub const WGPUBindingType_ReadonlyStorageBuffer: WGPUBindingType = 2;
+pub const WGPUBindingType_Sampler: WGPUBindingType = 3;
+pub const WGPUBindingType_ComparisonSampler: WGPUBindingType = 4;
+pub const WGPUBindingType_SampledTexture: WGPUBindingType = 5;
+pub const WGPUBindingType_ReadonlyStorageTexture: WGPUBindingType = 6;
+pub const WGPUBindingType_WriteonlyStorageTexture: WGPUBindingType = 7;
+pub const WGPUBindingType_Force32: WGPUBindingType = 2147483647;
+pub type WGPUBindingType = i32;
+pub const WGPUBlendFactor_Zero: WGPUBlendFactor = 0;
+pub const WGPUBlendFactor_One: WGPUBlendFactor = 1;
+pub const WGPUBlendFactor_SrcColor: WGPUBlendFactor = 2;
+pub const WGPUBlendFactor_OneMinusSrcColor: WGPUBlendFactor = 3;
+pub const WGPUBlendFactor_SrcAlpha: WGPUBlendFactor = 4;
+pub const WGPUBlendFactor_OneMinusSrcAlpha: WGPUBlendFactor = 5;
+pub const WGPUBlendFactor_DstColor: WGPUBlendFactor = 6;
+pub const WGPUBlendFactor_OneMinusDstColor: WGPUBlendFactor = 7;
+pub const WGPUBlendFactor_DstAlpha: WGPUBlendFactor = 8;
+pub const WGPUBlendFactor_OneMinusDstAlpha: WGPUBlendFactor = 9;
+pub const WGPUBlendFactor_SrcAlphaSaturated: WGPUBlendFactor = 10;
+pub const WGPUBlendFactor_BlendColor: WGPUBlendFactor = 11;
+pub const WGPUBlendFactor_OneMinusBlendColor: WGPUBlendFactor = 12;
+pub const WGPUBlendFactor_Force32: WGPUBlendFactor = 2147483647;
+pub type WGPUBlendFactor = i32;
+pub const WGPUBlendOperation_Add: WGPUBlendOperation = 0;
+pub const WGPUBlendOperation_Subtract: WGPUBlendOperation = 1;
+pub const WGPUBlendOperation_ReverseSubtract: WGPUBlendOperation = 2;
+pub const WGPUBlendOperation_Min: WGPUBlendOperation = 3;
+pub const WGPUBlendOperation_Max: WGPUBlendOperation = 4;
+pub const WGPUBlendOperation_Force32: WGPUBlendOperation = 2147483647;
+pub type WGPUBlendOperation = i32;
+pub const WGPUBufferMapAsyncStatus_Success: WGPUBufferMapAsyncStatus = 0;
+pub const WGPUBufferMapAsyncStatus_Error: WGPUBufferMapAsyncStatus = 1;
+pub const WGPUBufferMapAsyncStatus_Unknown: WGPUBufferMapAsyncStatus = 2;
+pub const WGPUBufferMapAsyncStatus_DeviceLost: WGPUBufferMapAsyncStatus = 3;
+pub const WGPUBufferMapAsyncStatus_DestroyedBeforeCallback: WGPUBufferMapAsyncStatus = 4;
+pub const WGPUBufferMapAsyncStatus_UnmappedBeforeCallback: WGPUBufferMapAsyncStatus = 5;
+pub const WGPUBufferMapAsyncStatus_Force32: WGPUBufferMapAsyncStatus = 2147483647;
+pub type WGPUBufferMapAsyncStatus = i32;
+pub const WGPUCompareFunction_Undefined: WGPUCompareFunction = 0;
+pub const WGPUCompareFunction_Never: WGPUCompareFunction = 1;
+pub const WGPUCompareFunction_Less: WGPUCompareFunction = 2;
+pub const WGPUCompareFunction_LessEqual: WGPUCompareFunction = 3;
+pub const WGPUCompareFunction_Greater: WGPUCompareFunction = 4;
+pub const WGPUCompareFunction_GreaterEqual: WGPUCompareFunction = 5;
+pub const WGPUCompareFunction_Equal: WGPUCompareFunction = 6;
+pub const WGPUCompareFunction_NotEqual: WGPUCompareFunction = 7;
+pub const WGPUCompareFunction_Always: WGPUCompareFunction = 8;
+pub const WGPUCompareFunction_Force32: WGPUCompareFunction = 2147483647;
+pub type WGPUCompareFunction = i32;
+pub const WGPUCullMode_None: WGPUCullMode = 0;
+pub const WGPUCullMode_Front: WGPUCullMode = 1;
+pub const WGPUCullMode_Back: WGPUCullMode = 2;
+pub const WGPUCullMode_Force32: WGPUCullMode = 2147483647;
+pub type WGPUCullMode = i32;
+pub const WGPUErrorFilter_None: WGPUErrorFilter = 0;
+pub const WGPUErrorFilter_Validation: WGPUErrorFilter = 1;
+pub const WGPUErrorFilter_OutOfMemory: WGPUErrorFilter = 2;
+pub const WGPUErrorFilter_Force32: WGPUErrorFilter = 2147483647;
+pub type WGPUErrorFilter = i32;
+pub const WGPUErrorType_NoError: WGPUErrorType = 0;
+pub const WGPUErrorType_Validation: WGPUErrorType = 1;
+pub const WGPUErrorType_OutOfMemory: WGPUErrorType = 2;
+pub const WGPUErrorType_Unknown: WGPUErrorType = 3;
+pub const WGPUErrorType_DeviceLost: WGPUErrorType = 4;
+pub const WGPUErrorType_Force32: WGPUErrorType = 2147483647;
+pub type WGPUErrorType = i32;
+pub const WGPUFenceCompletionStatus_Success: WGPUFenceCompletionStatus = 0;
+pub const WGPUFenceCompletionStatus_Error: WGPUFenceCompletionStatus = 1;
+pub const WGPUFenceCompletionStatus_Unknown: WGPUFenceCompletionStatus = 2;
+pub const WGPUFenceCompletionStatus_DeviceLost: WGPUFenceCompletionStatus = 3;
+pub const WGPUFenceCompletionStatus_Force32: WGPUFenceCompletionStatus = 2147483647;
+pub type WGPUFenceCompletionStatus = i32;
+pub const WGPUFilterMode_Nearest: WGPUFilterMode = 0;
+pub const WGPUFilterMode_Linear: WGPUFilterMode = 1;
+pub const WGPUFilterMode_Force32: WGPUFilterMode = 2147483647;
+pub type WGPUFilterMode = i32;
+pub const WGPUFrontFace_CCW: WGPUFrontFace = 0;
+pub const WGPUFrontFace_CW: WGPUFrontFace = 1;
+pub const WGPUFrontFace_Force32: WGPUFrontFace = 2147483647;
+pub type WGPUFrontFace = i32;
+pub const WGPUIndexFormat_Undefined: WGPUIndexFormat = 0;
+pub const WGPUIndexFormat_Uint16: WGPUIndexFormat = 1;
+pub const WGPUIndexFormat_Uint32: WGPUIndexFormat = 2;
+pub const WGPUIndexFormat_Force32: WGPUIndexFormat = 2147483647;
+pub type WGPUIndexFormat = i32;
+pub const WGPUInputStepMode_Vertex: WGPUInputStepMode = 0;
+pub const WGPUInputStepMode_Instance: WGPUInputStepMode = 1;
+pub const WGPUInputStepMode_Force32: WGPUInputStepMode = 2147483647;
+pub type WGPUInputStepMode = i32;
+pub const WGPULoadOp_Clear: WGPULoadOp = 0;
+pub const WGPULoadOp_Load: WGPULoadOp = 1;
+pub const WGPULoadOp_Force32: WGPULoadOp = 2147483647;
+pub type WGPULoadOp = i32;
+pub const WGPUPresentMode_Immediate: WGPUPresentMode = 0;
+pub const WGPUPresentMode_Mailbox: WGPUPresentMode = 1;
+pub const WGPUPresentMode_Fifo: WGPUPresentMode = 2;
+pub const WGPUPresentMode_Force32: WGPUPresentMode = 2147483647;
+pub type WGPUPresentMode = i32;
+pub const WGPUPrimitiveTopology_PointList: WGPUPrimitiveTopology = 0;
+pub const WGPUPrimitiveTopology_LineList: WGPUPrimitiveTopology = 1;
+pub const WGPUPrimitiveTopology_LineStrip: WGPUPrimitiveTopology = 2;
+pub const WGPUPrimitiveTopology_TriangleList: WGPUPrimitiveTopology = 3;
+pub const WGPUPrimitiveTopology_TriangleStrip: WGPUPrimitiveTopology = 4;
+pub const WGPUPrimitiveTopology_Force32: WGPUPrimitiveTopology = 2147483647;
+pub type WGPUPrimitiveTopology = i32;
+pub const WGPUSType_Invalid: WGPUSType = 0;
+pub const WGPUSType_SurfaceDescriptorFromMetalLayer: WGPUSType = 1;
+pub const WGPUSType_SurfaceDescriptorFromWindowsHWND: WGPUSType = 2;
+pub const WGPUSType_SurfaceDescriptorFromXlib: WGPUSType = 3;
+pub const WGPUSType_SurfaceDescriptorFromHTMLCanvasId: WGPUSType = 4;
+pub const WGPUSType_ShaderModuleSPIRVDescriptor: WGPUSType = 5;
+pub const WGPUSType_ShaderModuleWGSLDescriptor: WGPUSType = 6;
+pub const WGPUSType_Force32: WGPUSType = 2147483647;
+pub type WGPUSType = i32;
+pub const WGPUStencilOperation_Keep: WGPUStencilOperation = 0;
+pub const WGPUStencilOperation_Zero: WGPUStencilOperation = 1;
+pub const WGPUStencilOperation_Replace: WGPUStencilOperation = 2;
+pub const WGPUStencilOperation_Invert: WGPUStencilOperation = 3;
+pub const WGPUStencilOperation_IncrementClamp: WGPUStencilOperation = 4;
+pub const WGPUStencilOperation_DecrementClamp: WGPUStencilOperation = 5;
+pub const WGPUStencilOperation_IncrementWrap: WGPUStencilOperation = 6;
+pub const WGPUStencilOperation_DecrementWrap: WGPUStencilOperation = 7;
+pub const WGPUStencilOperation_Force32: WGPUStencilOperation = 2147483647;
+pub type WGPUStencilOperation = i32;
+pub const WGPUStoreOp_Store: WGPUStoreOp = 0;
+pub const WGPUStoreOp_Clear: WGPUStoreOp = 1;
+pub const WGPUStoreOp_Force32: WGPUStoreOp = 2147483647;
+pub type WGPUStoreOp = i32;
+pub const WGPUTextureAspect_All: WGPUTextureAspect = 0;
+pub const WGPUTextureAspect_StencilOnly: WGPUTextureAspect = 1;
+pub const WGPUTextureAspect_DepthOnly: WGPUTextureAspect = 2;
+pub const WGPUTextureAspect_Force32: WGPUTextureAspect = 2147483647;
+pub type WGPUTextureAspect = i32;
+pub const WGPUTextureComponentType_Float: WGPUTextureComponentType = 0;
+pub const WGPUTextureComponentType_Sint: WGPUTextureComponentType = 1;
+pub const WGPUTextureComponentType_Uint: WGPUTextureComponentType = 2;
+pub const WGPUTextureComponentType_DepthComparison: WGPUTextureComponentType = 3;
+pub const WGPUTextureComponentType_Force32: WGPUTextureComponentType = 2147483647;
+pub type WGPUTextureComponentType = i32;
+pub const WGPUTextureDimension_1D: WGPUTextureDimension = 0;
+pub const WGPUTextureDimension_2D: WGPUTextureDimension = 1;
+pub const WGPUTextureDimension_3D: WGPUTextureDimension = 2;
+pub const WGPUTextureDimension_Force32: WGPUTextureDimension = 2147483647;
+pub type WGPUTextureDimension = i32;
+pub const WGPUTextureFormat_Undefined: WGPUTextureFormat = 0;
+pub const WGPUTextureFormat_R8Unorm: WGPUTextureFormat = 1;
+pub const WGPUTextureFormat_R8Snorm: WGPUTextureFormat = 2;
+pub const WGPUTextureFormat_R8Uint: WGPUTextureFormat = 3;
+pub const WGPUTextureFormat_R8Sint: WGPUTextureFormat = 4;
+pub const WGPUTextureFormat_R16Uint: WGPUTextureFormat = 5;
+pub const WGPUTextureFormat_R16Sint: WGPUTextureFormat = 6;
+pub const WGPUTextureFormat_R16Float: WGPUTextureFormat = 7;
+pub const WGPUTextureFormat_RG8Unorm: WGPUTextureFormat = 8;
+pub const WGPUTextureFormat_RG8Snorm: WGPUTextureFormat = 9;
+pub const WGPUTextureFormat_RG8Uint: WGPUTextureFormat = 10;
+pub const WGPUTextureFormat_RG8Sint: WGPUTextureFormat = 11;
+pub const WGPUTextureFormat_R32Float: WGPUTextureFormat = 12;
+pub const WGPUTextureFormat_R32Uint: WGPUTextureFormat = 13;
+pub const WGPUTextureFormat_R32Sint: WGPUTextureFormat = 14;
+pub const WGPUTextureFormat_RG16Uint: WGPUTextureFormat = 15;
+pub const WGPUTextureFormat_RG16Sint: WGPUTextureFormat = 16;
+pub const WGPUTextureFormat_RG16Float: WGPUTextureFormat = 17;
+pub const WGPUTextureFormat_RGBA8Unorm: WGPUTextureFormat = 18;
+pub const WGPUTextureFormat_RGBA8UnormSrgb: WGPUTextureFormat = 19;
+pub const WGPUTextureFormat_RGBA8Snorm: WGPUTextureFormat = 20;
+pub const WGPUTextureFormat_RGBA8Uint: WGPUTextureFormat = 21;
+pub const WGPUTextureFormat_RGBA8Sint: WGPUTextureFormat = 22;
+pub const WGPUTextureFormat_BGRA8Unorm: WGPUTextureFormat = 23;
+pub const WGPUTextureFormat_BGRA8UnormSrgb: WGPUTextureFormat = 24;
+pub const WGPUTextureFormat_RGB10A2Unorm: WGPUTextureFormat = 25;
+pub const WGPUTextureFormat_RG11B10Ufloat: WGPUTextureFormat = 26;
+pub const WGPUTextureFormat_RGB9E5Ufloat: WGPUTextureFormat = 27;
+pub const WGPUTextureFormat_RG32Float: WGPUTextureFormat = 28;
+pub const WGPUTextureFormat_RG32Uint: WGPUTextureFormat = 29;
+pub const WGPUTextureFormat_RG32Sint: WGPUTextureFormat = 30;
+pub const WGPUTextureFormat_RGBA16Uint: WGPUTextureFormat = 31;
+pub const WGPUTextureFormat_RGBA16Sint: WGPUTextureFormat = 32;
+pub const WGPUTextureFormat_RGBA16Float: WGPUTextureFormat = 33;
+pub const WGPUTextureFormat_RGBA32Float: WGPUTextureFormat = 34;
+pub const WGPUTextureFormat_RGBA32Uint: WGPUTextureFormat = 35;
+pub const WGPUTextureFormat_RGBA32Sint: WGPUTextureFormat = 36;
+pub const WGPUTextureFormat_Depth32Float: WGPUTextureFormat = 37;
+pub const WGPUTextureFormat_Depth24Plus: WGPUTextureFormat = 38;
+pub const WGPUTextureFormat_Depth24PlusStencil8: WGPUTextureFormat = 39;
+pub const WGPUTextureFormat_BC1RGBAUnorm: WGPUTextureFormat = 40;
+pub const WGPUTextureFormat_BC1RGBAUnormSrgb: WGPUTextureFormat = 41;
+pub const WGPUTextureFormat_BC2RGBAUnorm: WGPUTextureFormat = 42;
+pub const WGPUTextureFormat_BC2RGBAUnormSrgb: WGPUTextureFormat = 43;
+pub const WGPUTextureFormat_BC3RGBAUnorm: WGPUTextureFormat = 44;
+pub const WGPUTextureFormat_BC3RGBAUnormSrgb: WGPUTextureFormat = 45;
+pub const WGPUTextureFormat_BC4RUnorm: WGPUTextureFormat = 46;
+pub const WGPUTextureFormat_BC4RSnorm: WGPUTextureFormat = 47;
+pub const WGPUTextureFormat_BC5RGUnorm: WGPUTextureFormat = 48;
+pub const WGPUTextureFormat_BC5RGSnorm: WGPUTextureFormat = 49;
+pub const WGPUTextureFormat_BC6HRGBUfloat: WGPUTextureFormat = 50;
+pub const WGPUTextureFormat_BC6HRGBFloat: WGPUTextureFormat = 51;
+pub const WGPUTextureFormat_BC7RGBAUnorm: WGPUTextureFormat = 52;
+pub const WGPUTextureFormat_BC7RGBAUnormSrgb: WGPUTextureFormat = 53;
+pub const WGPUTextureFormat_Force32: WGPUTextureFormat = 2147483647;
+pub type WGPUTextureFormat = i32;
+pub const WGPUTextureViewDimension_Undefined: WGPUTextureViewDimension = 0;
+pub const WGPUTextureViewDimension_1D: WGPUTextureViewDimension = 1;
+pub const WGPUTextureViewDimension_2D: WGPUTextureViewDimension = 2;
+pub const WGPUTextureViewDimension_2DArray: WGPUTextureViewDimension = 3;
+pub const WGPUTextureViewDimension_Cube: WGPUTextureViewDimension = 4;
+pub const WGPUTextureViewDimension_CubeArray: WGPUTextureViewDimension = 5;
+pub const WGPUTextureViewDimension_3D: WGPUTextureViewDimension = 6;
+pub const WGPUTextureViewDimension_Force32: WGPUTextureViewDimension = 2147483647;
+pub type WGPUTextureViewDimension = i32;
+pub const WGPUVertexFormat_UChar2: WGPUVertexFormat = 0;
+pub const WGPUVertexFormat_UChar4: WGPUVertexFormat = 1;
+pub const WGPUVertexFormat_Char2: WGPUVertexFormat = 2;
+pub const WGPUVertexFormat_Char4: WGPUVertexFormat = 3;
+pub const WGPUVertexFormat_UChar2Norm: WGPUVertexFormat = 4;
+pub const WGPUVertexFormat_UChar4Norm: WGPUVertexFormat = 5;
+pub const WGPUVertexFormat_Char2Norm: WGPUVertexFormat = 6;
+pub const WGPUVertexFormat_Char4Norm: WGPUVertexFormat = 7;
+pub const WGPUVertexFormat_UShort2: WGPUVertexFormat = 8;
+pub const WGPUVertexFormat_UShort4: WGPUVertexFormat = 9;
+pub const WGPUVertexFormat_Short2: WGPUVertexFormat = 10;
+pub const WGPUVertexFormat_Short4: WGPUVertexFormat = 11;
+pub const WGPUVertexFormat_UShort2Norm: WGPUVertexFormat = 12;
+pub const WGPUVertexFormat_UShort4Norm: WGPUVertexFormat = 13;
+pub const WGPUVertexFormat_Short2Norm: WGPUVertexFormat = 14;
+pub const WGPUVertexFormat_Short4Norm: WGPUVertexFormat = 15;
+pub const WGPUVertexFormat_Half2: WGPUVertexFormat = 16;
+pub const WGPUVertexFormat_Half4: WGPUVertexFormat = 17;
+pub const WGPUVertexFormat_Float: WGPUVertexFormat = 18;
+pub const WGPUVertexFormat_Float2: WGPUVertexFormat = 19;
+pub const WGPUVertexFormat_Float3: WGPUVertexFormat = 20;
+pub const WGPUVertexFormat_Float4: WGPUVertexFormat = 21;
+pub const WGPUVertexFormat_UInt: WGPUVertexFormat = 22;
+pub const WGPUVertexFormat_UInt2: WGPUVertexFormat = 23;
+pub const WGPUVertexFormat_UInt3: WGPUVertexFormat = 24;
+pub const WGPUVertexFormat_UInt4: WGPUVertexFormat = 25;
+pub const WGPUVertexFormat_Int: WGPUVertexFormat = 26;
+pub const WGPUVertexFormat_Int2: WGPUVertexFormat = 27;
+pub const WGPUVertexFormat_Int3: WGPUVertexFormat = 28;
+pub const WGPUVertexFormat_Int4: WGPUVertexFormat = 29;
+pub const WGPUVertexFormat_Force32: WGPUVertexFormat = 2147483647;
+pub type WGPUVertexFormat = i32;
+pub const WGPUBufferUsage_None: WGPUBufferUsage = 0;
+pub const WGPUBufferUsage_MapRead: WGPUBufferUsage = 1;
+pub const WGPUBufferUsage_MapWrite: WGPUBufferUsage = 2;
+pub const WGPUBufferUsage_CopySrc: WGPUBufferUsage = 4;
+pub const WGPUBufferUsage_CopyDst: WGPUBufferUsage = 8;
+pub const WGPUBufferUsage_Index: WGPUBufferUsage = 16;
+pub const WGPUBufferUsage_Vertex: WGPUBufferUsage = 32;
+pub const WGPUBufferUsage_Uniform: WGPUBufferUsage = 64;
+pub const WGPUBufferUsage_Storage: WGPUBufferUsage = 128;
+pub const WGPUBufferUsage_Indirect: WGPUBufferUsage = 256;
+pub const WGPUBufferUsage_QueryResolve: WGPUBufferUsage = 512;
+pub const WGPUBufferUsage_Force32: WGPUBufferUsage = 2147483647;
+pub type WGPUBufferUsage = i32;
+pub type WGPUBufferUsageFlags = WGPUFlags;
+pub const WGPUColorWriteMask_None: WGPUColorWriteMask = 0;
+pub const WGPUColorWriteMask_Red: WGPUColorWriteMask = 1;
+pub const WGPUColorWriteMask_Green: WGPUColorWriteMask = 2;
+pub const WGPUColorWriteMask_Blue: WGPUColorWriteMask = 4;
+pub const WGPUColorWriteMask_Alpha: WGPUColorWriteMask = 8;
+pub const WGPUColorWriteMask_All: WGPUColorWriteMask = 15;
+pub const WGPUColorWriteMask_Force32: WGPUColorWriteMask = 2147483647;
+pub type WGPUColorWriteMask = i32;
+pub type WGPUColorWriteMaskFlags = WGPUFlags;
+pub const WGPUMapMode_Read: WGPUMapMode = 1;
+pub const WGPUMapMode_Write: WGPUMapMode = 2;
+pub const WGPUMapMode_Force32: WGPUMapMode = 2147483647;
+pub type WGPUMapMode = i32;
+pub type WGPUMapModeFlags = WGPUFlags;
+pub const WGPUShaderStage_None: WGPUShaderStage = 0;
+pub const WGPUShaderStage_Vertex: WGPUShaderStage = 1;
+pub const WGPUShaderStage_Fragment: WGPUShaderStage = 2;
+pub const WGPUShaderStage_Compute: WGPUShaderStage = 4;
+pub const WGPUShaderStage_Force32: WGPUShaderStage = 2147483647;
+pub type WGPUShaderStage = i32;
+pub type WGPUShaderStageFlags = WGPUFlags;
+pub const WGPUTextureUsage_None: WGPUTextureUsage = 0;
+pub const WGPUTextureUsage_CopySrc: WGPUTextureUsage = 1;
+pub const WGPUTextureUsage_CopyDst: WGPUTextureUsage = 2;
+pub const WGPUTextureUsage_Sampled: WGPUTextureUsage = 4;
+pub const WGPUTextureUsage_Storage: WGPUTextureUsage = 8;
+pub const WGPUTextureUsage_OutputAttachment: WGPUTextureUsage = 16;
+pub const WGPUTextureUsage_Force32: WGPUTextureUsage = 2147483647;
+pub type WGPUTextureUsage = i32;
+pub type WGPUTextureUsageFlags = WGPUFlags;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUChainedStruct {
+    pub next: *const WGPUChainedStruct,
+    pub sType: WGPUSType,
+}
+#[test]
+fn bindgen_test_layout_WGPUChainedStruct() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUChainedStruct>(),
+        16usize,
+        concat!("Size of: ", stringify!(WGPUChainedStruct))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUChainedStruct>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUChainedStruct))
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUChainedStruct>())).next as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUChainedStruct),
+            "::",
+            stringify!(next)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUChainedStruct>())).sType as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUChainedStruct),
+            "::",
+            stringify!(sType)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUAdapterProperties {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub deviceID: u32,
+    pub vendorID: u32,
+    pub name: *const libc::c_char,
+    pub driverDescription: *const libc::c_char,
+    pub adapterType: WGPUAdapterType,
+    pub backendType: WGPUBackendType,
+}
+#[test]
+fn bindgen_test_layout_WGPUAdapterProperties() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUAdapterProperties>(),
+        40usize,
+        concat!("Size of: ", stringify!(WGPUAdapterProperties))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUAdapterProperties>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUAdapterProperties))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUAdapterProperties>())).nextInChain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUAdapterProperties),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUAdapterProperties>())).deviceID as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUAdapterProperties),
+            "::",
+            stringify!(deviceID)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUAdapterProperties>())).vendorID as *const _ as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUAdapterProperties),
+            "::",
+            stringify!(vendorID)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUAdapterProperties>())).name as *const _ as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUAdapterProperties),
+            "::",
+            stringify!(name)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUAdapterProperties>())).driverDescription as *const _
+                as usize
+        },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUAdapterProperties),
+            "::",
+            stringify!(driverDescription)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUAdapterProperties>())).adapterType as *const _ as usize
+        },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUAdapterProperties),
+            "::",
+            stringify!(adapterType)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUAdapterProperties>())).backendType as *const _ as usize
+        },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUAdapterProperties),
+            "::",
+            stringify!(backendType)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUBindGroupEntry {
+    pub binding: u32,
+    pub buffer: WGPUBuffer,
+    pub offset: u64,
+    pub size: u64,
+    pub sampler: WGPUSampler,
+    pub textureView: WGPUTextureView,
+}
+#[test]
+fn bindgen_test_layout_WGPUBindGroupEntry() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUBindGroupEntry>(),
+        48usize,
+        concat!("Size of: ", stringify!(WGPUBindGroupEntry))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUBindGroupEntry>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUBindGroupEntry))
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUBindGroupEntry>())).binding as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupEntry),
+            "::",
+            stringify!(binding)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUBindGroupEntry>())).buffer as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupEntry),
+            "::",
+            stringify!(buffer)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUBindGroupEntry>())).offset as *const _ as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupEntry),
+            "::",
+            stringify!(offset)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUBindGroupEntry>())).size as *const _ as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupEntry),
+            "::",
+            stringify!(size)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUBindGroupEntry>())).sampler as *const _ as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupEntry),
+            "::",
+            stringify!(sampler)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUBindGroupEntry>())).textureView as *const _ as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupEntry),
+            "::",
+            stringify!(textureView)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUBindGroupLayoutEntry {
+    pub binding: u32,
+    pub visibility: WGPUShaderStageFlags,
+    pub type_: WGPUBindingType,
+    pub hasDynamicOffset: bool,
+    pub minBufferBindingSize: u64,
+    pub multisampled: bool,
+    pub viewDimension: WGPUTextureViewDimension,
+    pub textureComponentType: WGPUTextureComponentType,
+    pub storageTextureFormat: WGPUTextureFormat,
+}
+#[test]
+fn bindgen_test_layout_WGPUBindGroupLayoutEntry() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUBindGroupLayoutEntry>(),
+        40usize,
+        concat!("Size of: ", stringify!(WGPUBindGroupLayoutEntry))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUBindGroupLayoutEntry>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUBindGroupLayoutEntry))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUBindGroupLayoutEntry>())).binding as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupLayoutEntry),
+            "::",
+            stringify!(binding)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUBindGroupLayoutEntry>())).visibility as *const _ as usize
+        },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupLayoutEntry),
+            "::",
+            stringify!(visibility)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUBindGroupLayoutEntry>())).type_ as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupLayoutEntry),
+            "::",
+            stringify!(type_)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUBindGroupLayoutEntry>())).hasDynamicOffset as *const _
+                as usize
+        },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupLayoutEntry),
+            "::",
+            stringify!(hasDynamicOffset)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUBindGroupLayoutEntry>())).minBufferBindingSize as *const _
+                as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupLayoutEntry),
+            "::",
+            stringify!(minBufferBindingSize)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUBindGroupLayoutEntry>())).multisampled as *const _ as usize
+        },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupLayoutEntry),
+            "::",
+            stringify!(multisampled)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUBindGroupLayoutEntry>())).viewDimension as *const _ as usize
+        },
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupLayoutEntry),
+            "::",
+            stringify!(viewDimension)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUBindGroupLayoutEntry>())).textureComponentType as *const _
+                as usize
+        },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupLayoutEntry),
+            "::",
+            stringify!(textureComponentType)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUBindGroupLayoutEntry>())).storageTextureFormat as *const _
+                as usize
+        },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupLayoutEntry),
+            "::",
+            stringify!(storageTextureFormat)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUBlendDescriptor {
+    pub operation: WGPUBlendOperation,
+    pub srcFactor: WGPUBlendFactor,
+    pub dstFactor: WGPUBlendFactor,
+}
+#[test]
+fn bindgen_test_layout_WGPUBlendDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUBlendDescriptor>(),
+        12usize,
+        concat!("Size of: ", stringify!(WGPUBlendDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUBlendDescriptor>(),
+        4usize,
+        concat!("Alignment of ", stringify!(WGPUBlendDescriptor))
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUBlendDescriptor>())).operation as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBlendDescriptor),
+            "::",
+            stringify!(operation)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUBlendDescriptor>())).srcFactor as *const _ as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBlendDescriptor),
+            "::",
+            stringify!(srcFactor)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUBlendDescriptor>())).dstFactor as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBlendDescriptor),
+            "::",
+            stringify!(dstFactor)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUBufferDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub label: *const libc::c_char,
+    pub usage: WGPUBufferUsageFlags,
+    pub size: u64,
+    pub mappedAtCreation: bool,
+}
+#[test]
+fn bindgen_test_layout_WGPUBufferDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUBufferDescriptor>(),
+        40usize,
+        concat!("Size of: ", stringify!(WGPUBufferDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUBufferDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUBufferDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUBufferDescriptor>())).nextInChain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBufferDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUBufferDescriptor>())).label as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBufferDescriptor),
+            "::",
+            stringify!(label)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUBufferDescriptor>())).usage as *const _ as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBufferDescriptor),
+            "::",
+            stringify!(usage)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUBufferDescriptor>())).size as *const _ as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBufferDescriptor),
+            "::",
+            stringify!(size)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUBufferDescriptor>())).mappedAtCreation as *const _ as usize
+        },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBufferDescriptor),
+            "::",
+            stringify!(mappedAtCreation)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUColor {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+#[test]
+fn bindgen_test_layout_WGPUColor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUColor>(),
+        32usize,
+        concat!("Size of: ", stringify!(WGPUColor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUColor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUColor))
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUColor>())).r as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUColor),
+            "::",
+            stringify!(r)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUColor>())).g as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUColor),
+            "::",
+            stringify!(g)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUColor>())).b as *const _ as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUColor),
+            "::",
+            stringify!(b)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUColor>())).a as *const _ as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUColor),
+            "::",
+            stringify!(a)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUCommandBufferDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub label: *const libc::c_char,
+}
+#[test]
+fn bindgen_test_layout_WGPUCommandBufferDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUCommandBufferDescriptor>(),
+        16usize,
+        concat!("Size of: ", stringify!(WGPUCommandBufferDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUCommandBufferDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUCommandBufferDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUCommandBufferDescriptor>())).nextInChain as *const _
+                as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUCommandBufferDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUCommandBufferDescriptor>())).label as *const _ as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUCommandBufferDescriptor),
+            "::",
+            stringify!(label)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUCommandEncoderDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub label: *const libc::c_char,
+}
+#[test]
+fn bindgen_test_layout_WGPUCommandEncoderDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUCommandEncoderDescriptor>(),
+        16usize,
+        concat!("Size of: ", stringify!(WGPUCommandEncoderDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUCommandEncoderDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUCommandEncoderDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUCommandEncoderDescriptor>())).nextInChain as *const _
+                as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUCommandEncoderDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUCommandEncoderDescriptor>())).label as *const _ as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUCommandEncoderDescriptor),
+            "::",
+            stringify!(label)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUComputePassDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub label: *const libc::c_char,
+}
+#[test]
+fn bindgen_test_layout_WGPUComputePassDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUComputePassDescriptor>(),
+        16usize,
+        concat!("Size of: ", stringify!(WGPUComputePassDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUComputePassDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUComputePassDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUComputePassDescriptor>())).nextInChain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUComputePassDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUComputePassDescriptor>())).label as *const _ as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUComputePassDescriptor),
+            "::",
+            stringify!(label)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUDeviceDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+}
+#[test]
+fn bindgen_test_layout_WGPUDeviceDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUDeviceDescriptor>(),
+        8usize,
+        concat!("Size of: ", stringify!(WGPUDeviceDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUDeviceDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUDeviceDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUDeviceDescriptor>())).nextInChain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUDeviceDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUExtent3D {
+    pub width: u32,
+    pub height: u32,
+    pub depth: u32,
+}
+#[test]
+fn bindgen_test_layout_WGPUExtent3D() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUExtent3D>(),
+        12usize,
+        concat!("Size of: ", stringify!(WGPUExtent3D))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUExtent3D>(),
+        4usize,
+        concat!("Alignment of ", stringify!(WGPUExtent3D))
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUExtent3D>())).width as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUExtent3D),
+            "::",
+            stringify!(width)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUExtent3D>())).height as *const _ as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUExtent3D),
+            "::",
+            stringify!(height)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUExtent3D>())).depth as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUExtent3D),
+            "::",
+            stringify!(depth)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUFenceDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub label: *const libc::c_char,
+    pub initialValue: u64,
+}
+#[test]
+fn bindgen_test_layout_WGPUFenceDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUFenceDescriptor>(),
+        24usize,
+        concat!("Size of: ", stringify!(WGPUFenceDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUFenceDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUFenceDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUFenceDescriptor>())).nextInChain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUFenceDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUFenceDescriptor>())).label as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUFenceDescriptor),
+            "::",
+            stringify!(label)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUFenceDescriptor>())).initialValue as *const _ as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUFenceDescriptor),
+            "::",
+            stringify!(initialValue)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUInstanceDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+}
+#[test]
+fn bindgen_test_layout_WGPUInstanceDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUInstanceDescriptor>(),
+        8usize,
+        concat!("Size of: ", stringify!(WGPUInstanceDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUInstanceDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUInstanceDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUInstanceDescriptor>())).nextInChain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUInstanceDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUOrigin3D {
+    pub x: u32,
+    pub y: u32,
+    pub z: u32,
+}
+#[test]
+fn bindgen_test_layout_WGPUOrigin3D() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUOrigin3D>(),
+        12usize,
+        concat!("Size of: ", stringify!(WGPUOrigin3D))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUOrigin3D>(),
+        4usize,
+        concat!("Alignment of ", stringify!(WGPUOrigin3D))
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUOrigin3D>())).x as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUOrigin3D),
+            "::",
+            stringify!(x)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUOrigin3D>())).y as *const _ as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUOrigin3D),
+            "::",
+            stringify!(y)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUOrigin3D>())).z as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUOrigin3D),
+            "::",
+            stringify!(z)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUPipelineLayoutDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub label: *const libc::c_char,
+    pub bindGroupLayoutCount: u32,
+    pub bindGroupLayouts: *const WGPUBindGroupLayout,
+}
+#[test]
+fn bindgen_test_layout_WGPUPipelineLayoutDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUPipelineLayoutDescriptor>(),
+        32usize,
+        concat!("Size of: ", stringify!(WGPUPipelineLayoutDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUPipelineLayoutDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUPipelineLayoutDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUPipelineLayoutDescriptor>())).nextInChain as *const _
+                as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUPipelineLayoutDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUPipelineLayoutDescriptor>())).label as *const _ as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUPipelineLayoutDescriptor),
+            "::",
+            stringify!(label)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUPipelineLayoutDescriptor>())).bindGroupLayoutCount
+                as *const _ as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUPipelineLayoutDescriptor),
+            "::",
+            stringify!(bindGroupLayoutCount)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUPipelineLayoutDescriptor>())).bindGroupLayouts as *const _
+                as usize
+        },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUPipelineLayoutDescriptor),
+            "::",
+            stringify!(bindGroupLayouts)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUProgrammableStageDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub module: WGPUShaderModule,
+    pub entryPoint: *const libc::c_char,
+}
+#[test]
+fn bindgen_test_layout_WGPUProgrammableStageDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUProgrammableStageDescriptor>(),
+        24usize,
+        concat!("Size of: ", stringify!(WGPUProgrammableStageDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUProgrammableStageDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUProgrammableStageDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUProgrammableStageDescriptor>())).nextInChain as *const _
+                as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUProgrammableStageDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUProgrammableStageDescriptor>())).module as *const _ as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUProgrammableStageDescriptor),
+            "::",
+            stringify!(module)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUProgrammableStageDescriptor>())).entryPoint as *const _
+                as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUProgrammableStageDescriptor),
+            "::",
+            stringify!(entryPoint)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPURasterizationStateDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub frontFace: WGPUFrontFace,
+    pub cullMode: WGPUCullMode,
+    pub depthBias: i32,
+    pub depthBiasSlopeScale: f32,
+    pub depthBiasClamp: f32,
+}
+#[test]
+fn bindgen_test_layout_WGPURasterizationStateDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPURasterizationStateDescriptor>(),
+        32usize,
+        concat!("Size of: ", stringify!(WGPURasterizationStateDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPURasterizationStateDescriptor>(),
+        8usize,
+        concat!(
+            "Alignment of ",
+            stringify!(WGPURasterizationStateDescriptor)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURasterizationStateDescriptor>())).nextInChain as *const _
+                as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURasterizationStateDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURasterizationStateDescriptor>())).frontFace as *const _
+                as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURasterizationStateDescriptor),
+            "::",
+            stringify!(frontFace)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURasterizationStateDescriptor>())).cullMode as *const _
+                as usize
+        },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURasterizationStateDescriptor),
+            "::",
+            stringify!(cullMode)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURasterizationStateDescriptor>())).depthBias as *const _
+                as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURasterizationStateDescriptor),
+            "::",
+            stringify!(depthBias)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURasterizationStateDescriptor>())).depthBiasSlopeScale
+                as *const _ as usize
+        },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURasterizationStateDescriptor),
+            "::",
+            stringify!(depthBiasSlopeScale)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURasterizationStateDescriptor>())).depthBiasClamp as *const _
+                as usize
+        },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURasterizationStateDescriptor),
+            "::",
+            stringify!(depthBiasClamp)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPURenderBundleDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub label: *const libc::c_char,
+}
+#[test]
+fn bindgen_test_layout_WGPURenderBundleDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPURenderBundleDescriptor>(),
+        16usize,
+        concat!("Size of: ", stringify!(WGPURenderBundleDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPURenderBundleDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPURenderBundleDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderBundleDescriptor>())).nextInChain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderBundleDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderBundleDescriptor>())).label as *const _ as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderBundleDescriptor),
+            "::",
+            stringify!(label)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPURenderBundleEncoderDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub label: *const libc::c_char,
+    pub colorFormatsCount: u32,
+    pub colorFormats: *const WGPUTextureFormat,
+    pub depthStencilFormat: WGPUTextureFormat,
+    pub sampleCount: u32,
+}
+#[test]
+fn bindgen_test_layout_WGPURenderBundleEncoderDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPURenderBundleEncoderDescriptor>(),
+        40usize,
+        concat!("Size of: ", stringify!(WGPURenderBundleEncoderDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPURenderBundleEncoderDescriptor>(),
+        8usize,
+        concat!(
+            "Alignment of ",
+            stringify!(WGPURenderBundleEncoderDescriptor)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderBundleEncoderDescriptor>())).nextInChain as *const _
+                as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderBundleEncoderDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderBundleEncoderDescriptor>())).label as *const _
+                as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderBundleEncoderDescriptor),
+            "::",
+            stringify!(label)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderBundleEncoderDescriptor>())).colorFormatsCount
+                as *const _ as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderBundleEncoderDescriptor),
+            "::",
+            stringify!(colorFormatsCount)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderBundleEncoderDescriptor>())).colorFormats as *const _
+                as usize
+        },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderBundleEncoderDescriptor),
+            "::",
+            stringify!(colorFormats)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderBundleEncoderDescriptor>())).depthStencilFormat
+                as *const _ as usize
+        },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderBundleEncoderDescriptor),
+            "::",
+            stringify!(depthStencilFormat)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderBundleEncoderDescriptor>())).sampleCount as *const _
+                as usize
+        },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderBundleEncoderDescriptor),
+            "::",
+            stringify!(sampleCount)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPURenderPassDepthStencilAttachmentDescriptor {
+    pub attachment: WGPUTextureView,
+    pub depthLoadOp: WGPULoadOp,
+    pub depthStoreOp: WGPUStoreOp,
+    pub clearDepth: f32,
+    pub depthReadOnly: bool,
+    pub stencilLoadOp: WGPULoadOp,
+    pub stencilStoreOp: WGPUStoreOp,
+    pub clearStencil: u32,
+    pub stencilReadOnly: bool,
+}
+#[test]
+fn bindgen_test_layout_WGPURenderPassDepthStencilAttachmentDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPURenderPassDepthStencilAttachmentDescriptor>(),
+        40usize,
+        concat!(
+            "Size of: ",
+            stringify!(WGPURenderPassDepthStencilAttachmentDescriptor)
+        )
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPURenderPassDepthStencilAttachmentDescriptor>(),
+        8usize,
+        concat!(
+            "Alignment of ",
+            stringify!(WGPURenderPassDepthStencilAttachmentDescriptor)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPassDepthStencilAttachmentDescriptor>())).attachment
+                as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPassDepthStencilAttachmentDescriptor),
+            "::",
+            stringify!(attachment)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPassDepthStencilAttachmentDescriptor>())).depthLoadOp
+                as *const _ as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPassDepthStencilAttachmentDescriptor),
+            "::",
+            stringify!(depthLoadOp)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPassDepthStencilAttachmentDescriptor>())).depthStoreOp
+                as *const _ as usize
+        },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPassDepthStencilAttachmentDescriptor),
+            "::",
+            stringify!(depthStoreOp)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPassDepthStencilAttachmentDescriptor>())).clearDepth
+                as *const _ as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPassDepthStencilAttachmentDescriptor),
+            "::",
+            stringify!(clearDepth)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPassDepthStencilAttachmentDescriptor>()))
+                .depthReadOnly as *const _ as usize
+        },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPassDepthStencilAttachmentDescriptor),
+            "::",
+            stringify!(depthReadOnly)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPassDepthStencilAttachmentDescriptor>()))
+                .stencilLoadOp as *const _ as usize
+        },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPassDepthStencilAttachmentDescriptor),
+            "::",
+            stringify!(stencilLoadOp)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPassDepthStencilAttachmentDescriptor>()))
+                .stencilStoreOp as *const _ as usize
+        },
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPassDepthStencilAttachmentDescriptor),
+            "::",
+            stringify!(stencilStoreOp)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPassDepthStencilAttachmentDescriptor>())).clearStencil
+                as *const _ as usize
+        },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPassDepthStencilAttachmentDescriptor),
+            "::",
+            stringify!(clearStencil)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPassDepthStencilAttachmentDescriptor>()))
+                .stencilReadOnly as *const _ as usize
+        },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPassDepthStencilAttachmentDescriptor),
+            "::",
+            stringify!(stencilReadOnly)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPURequestAdapterOptions {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub compatibleSurface: WGPUSurface,
+}
+#[test]
+fn bindgen_test_layout_WGPURequestAdapterOptions() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPURequestAdapterOptions>(),
+        16usize,
+        concat!("Size of: ", stringify!(WGPURequestAdapterOptions))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPURequestAdapterOptions>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPURequestAdapterOptions))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURequestAdapterOptions>())).nextInChain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURequestAdapterOptions),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURequestAdapterOptions>())).compatibleSurface as *const _
+                as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURequestAdapterOptions),
+            "::",
+            stringify!(compatibleSurface)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUSamplerDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub label: *const libc::c_char,
+    pub addressModeU: WGPUAddressMode,
+    pub addressModeV: WGPUAddressMode,
+    pub addressModeW: WGPUAddressMode,
+    pub magFilter: WGPUFilterMode,
+    pub minFilter: WGPUFilterMode,
+    pub mipmapFilter: WGPUFilterMode,
+    pub lodMinClamp: f32,
+    pub lodMaxClamp: f32,
+    pub compare: WGPUCompareFunction,
+}
+#[test]
+fn bindgen_test_layout_WGPUSamplerDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUSamplerDescriptor>(),
+        56usize,
+        concat!("Size of: ", stringify!(WGPUSamplerDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUSamplerDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUSamplerDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSamplerDescriptor>())).nextInChain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSamplerDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUSamplerDescriptor>())).label as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSamplerDescriptor),
+            "::",
+            stringify!(label)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSamplerDescriptor>())).addressModeU as *const _ as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSamplerDescriptor),
+            "::",
+            stringify!(addressModeU)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSamplerDescriptor>())).addressModeV as *const _ as usize
+        },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSamplerDescriptor),
+            "::",
+            stringify!(addressModeV)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSamplerDescriptor>())).addressModeW as *const _ as usize
+        },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSamplerDescriptor),
+            "::",
+            stringify!(addressModeW)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSamplerDescriptor>())).magFilter as *const _ as usize
+        },
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSamplerDescriptor),
+            "::",
+            stringify!(magFilter)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSamplerDescriptor>())).minFilter as *const _ as usize
+        },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSamplerDescriptor),
+            "::",
+            stringify!(minFilter)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSamplerDescriptor>())).mipmapFilter as *const _ as usize
+        },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSamplerDescriptor),
+            "::",
+            stringify!(mipmapFilter)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSamplerDescriptor>())).lodMinClamp as *const _ as usize
+        },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSamplerDescriptor),
+            "::",
+            stringify!(lodMinClamp)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSamplerDescriptor>())).lodMaxClamp as *const _ as usize
+        },
+        44usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSamplerDescriptor),
+            "::",
+            stringify!(lodMaxClamp)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUSamplerDescriptor>())).compare as *const _ as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSamplerDescriptor),
+            "::",
+            stringify!(compare)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUShaderModuleDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub label: *const libc::c_char,
+}
+#[test]
+fn bindgen_test_layout_WGPUShaderModuleDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUShaderModuleDescriptor>(),
+        16usize,
+        concat!("Size of: ", stringify!(WGPUShaderModuleDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUShaderModuleDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUShaderModuleDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUShaderModuleDescriptor>())).nextInChain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUShaderModuleDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUShaderModuleDescriptor>())).label as *const _ as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUShaderModuleDescriptor),
+            "::",
+            stringify!(label)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUShaderModuleSPIRVDescriptor {
+    pub chain: WGPUChainedStruct,
+    pub codeSize: u32,
+    pub code: *const u32,
+}
+#[test]
+fn bindgen_test_layout_WGPUShaderModuleSPIRVDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUShaderModuleSPIRVDescriptor>(),
+        32usize,
+        concat!("Size of: ", stringify!(WGPUShaderModuleSPIRVDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUShaderModuleSPIRVDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUShaderModuleSPIRVDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUShaderModuleSPIRVDescriptor>())).chain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUShaderModuleSPIRVDescriptor),
+            "::",
+            stringify!(chain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUShaderModuleSPIRVDescriptor>())).codeSize as *const _
+                as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUShaderModuleSPIRVDescriptor),
+            "::",
+            stringify!(codeSize)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUShaderModuleSPIRVDescriptor>())).code as *const _ as usize
+        },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUShaderModuleSPIRVDescriptor),
+            "::",
+            stringify!(code)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUShaderModuleWGSLDescriptor {
+    pub chain: WGPUChainedStruct,
+    pub source: *const libc::c_char,
+}
+#[test]
+fn bindgen_test_layout_WGPUShaderModuleWGSLDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUShaderModuleWGSLDescriptor>(),
+        24usize,
+        concat!("Size of: ", stringify!(WGPUShaderModuleWGSLDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUShaderModuleWGSLDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUShaderModuleWGSLDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUShaderModuleWGSLDescriptor>())).chain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUShaderModuleWGSLDescriptor),
+            "::",
+            stringify!(chain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUShaderModuleWGSLDescriptor>())).source as *const _ as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUShaderModuleWGSLDescriptor),
+            "::",
+            stringify!(source)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUStencilStateFaceDescriptor {
+    pub compare: WGPUCompareFunction,
+    pub failOp: WGPUStencilOperation,
+    pub depthFailOp: WGPUStencilOperation,
+    pub passOp: WGPUStencilOperation,
+}
+#[test]
+fn bindgen_test_layout_WGPUStencilStateFaceDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUStencilStateFaceDescriptor>(),
+        16usize,
+        concat!("Size of: ", stringify!(WGPUStencilStateFaceDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUStencilStateFaceDescriptor>(),
+        4usize,
+        concat!("Alignment of ", stringify!(WGPUStencilStateFaceDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUStencilStateFaceDescriptor>())).compare as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUStencilStateFaceDescriptor),
+            "::",
+            stringify!(compare)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUStencilStateFaceDescriptor>())).failOp as *const _ as usize
+        },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUStencilStateFaceDescriptor),
+            "::",
+            stringify!(failOp)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUStencilStateFaceDescriptor>())).depthFailOp as *const _
+                as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUStencilStateFaceDescriptor),
+            "::",
+            stringify!(depthFailOp)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUStencilStateFaceDescriptor>())).passOp as *const _ as usize
+        },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUStencilStateFaceDescriptor),
+            "::",
+            stringify!(passOp)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUSurfaceDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub label: *const libc::c_char,
+}
+#[test]
+fn bindgen_test_layout_WGPUSurfaceDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUSurfaceDescriptor>(),
+        16usize,
+        concat!("Size of: ", stringify!(WGPUSurfaceDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUSurfaceDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUSurfaceDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSurfaceDescriptor>())).nextInChain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSurfaceDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUSurfaceDescriptor>())).label as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSurfaceDescriptor),
+            "::",
+            stringify!(label)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUSurfaceDescriptorFromHTMLCanvasId {
+    pub chain: WGPUChainedStruct,
+    pub id: *const libc::c_char,
+}
+#[test]
+fn bindgen_test_layout_WGPUSurfaceDescriptorFromHTMLCanvasId() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUSurfaceDescriptorFromHTMLCanvasId>(),
+        24usize,
+        concat!(
+            "Size of: ",
+            stringify!(WGPUSurfaceDescriptorFromHTMLCanvasId)
+        )
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUSurfaceDescriptorFromHTMLCanvasId>(),
+        8usize,
+        concat!(
+            "Alignment of ",
+            stringify!(WGPUSurfaceDescriptorFromHTMLCanvasId)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSurfaceDescriptorFromHTMLCanvasId>())).chain as *const _
+                as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSurfaceDescriptorFromHTMLCanvasId),
+            "::",
+            stringify!(chain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSurfaceDescriptorFromHTMLCanvasId>())).id as *const _
+                as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSurfaceDescriptorFromHTMLCanvasId),
+            "::",
+            stringify!(id)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUSurfaceDescriptorFromMetalLayer {
+    pub chain: WGPUChainedStruct,
+    pub layer: *mut libc::c_void,
+}
+#[test]
+fn bindgen_test_layout_WGPUSurfaceDescriptorFromMetalLayer() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUSurfaceDescriptorFromMetalLayer>(),
+        24usize,
+        concat!("Size of: ", stringify!(WGPUSurfaceDescriptorFromMetalLayer))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUSurfaceDescriptorFromMetalLayer>(),
+        8usize,
+        concat!(
+            "Alignment of ",
+            stringify!(WGPUSurfaceDescriptorFromMetalLayer)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSurfaceDescriptorFromMetalLayer>())).chain as *const _
+                as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSurfaceDescriptorFromMetalLayer),
+            "::",
+            stringify!(chain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSurfaceDescriptorFromMetalLayer>())).layer as *const _
+                as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSurfaceDescriptorFromMetalLayer),
+            "::",
+            stringify!(layer)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUSurfaceDescriptorFromWindowsHWND {
+    pub chain: WGPUChainedStruct,
+    pub hinstance: *mut libc::c_void,
+    pub hwnd: *mut libc::c_void,
+}
+#[test]
+fn bindgen_test_layout_WGPUSurfaceDescriptorFromWindowsHWND() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUSurfaceDescriptorFromWindowsHWND>(),
+        32usize,
+        concat!(
+            "Size of: ",
+            stringify!(WGPUSurfaceDescriptorFromWindowsHWND)
+        )
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUSurfaceDescriptorFromWindowsHWND>(),
+        8usize,
+        concat!(
+            "Alignment of ",
+            stringify!(WGPUSurfaceDescriptorFromWindowsHWND)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSurfaceDescriptorFromWindowsHWND>())).chain as *const _
+                as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSurfaceDescriptorFromWindowsHWND),
+            "::",
+            stringify!(chain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSurfaceDescriptorFromWindowsHWND>())).hinstance as *const _
+                as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSurfaceDescriptorFromWindowsHWND),
+            "::",
+            stringify!(hinstance)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSurfaceDescriptorFromWindowsHWND>())).hwnd as *const _
+                as usize
+        },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSurfaceDescriptorFromWindowsHWND),
+            "::",
+            stringify!(hwnd)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUSurfaceDescriptorFromXlib {
+    pub chain: WGPUChainedStruct,
+    pub display: *mut libc::c_void,
+    pub window: u32,
+}
+#[test]
+fn bindgen_test_layout_WGPUSurfaceDescriptorFromXlib() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUSurfaceDescriptorFromXlib>(),
+        32usize,
+        concat!("Size of: ", stringify!(WGPUSurfaceDescriptorFromXlib))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUSurfaceDescriptorFromXlib>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUSurfaceDescriptorFromXlib))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSurfaceDescriptorFromXlib>())).chain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSurfaceDescriptorFromXlib),
+            "::",
+            stringify!(chain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSurfaceDescriptorFromXlib>())).display as *const _ as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSurfaceDescriptorFromXlib),
+            "::",
+            stringify!(display)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSurfaceDescriptorFromXlib>())).window as *const _ as usize
+        },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSurfaceDescriptorFromXlib),
+            "::",
+            stringify!(window)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUSwapChainDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub label: *const libc::c_char,
+    pub usage: WGPUTextureUsageFlags,
+    pub format: WGPUTextureFormat,
+    pub width: u32,
+    pub height: u32,
+    pub presentMode: WGPUPresentMode,
+    pub implementation: u64,
+}
+#[test]
+fn bindgen_test_layout_WGPUSwapChainDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUSwapChainDescriptor>(),
+        48usize,
+        concat!("Size of: ", stringify!(WGPUSwapChainDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUSwapChainDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUSwapChainDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSwapChainDescriptor>())).nextInChain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSwapChainDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUSwapChainDescriptor>())).label as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSwapChainDescriptor),
+            "::",
+            stringify!(label)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUSwapChainDescriptor>())).usage as *const _ as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSwapChainDescriptor),
+            "::",
+            stringify!(usage)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUSwapChainDescriptor>())).format as *const _ as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSwapChainDescriptor),
+            "::",
+            stringify!(format)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUSwapChainDescriptor>())).width as *const _ as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSwapChainDescriptor),
+            "::",
+            stringify!(width)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUSwapChainDescriptor>())).height as *const _ as usize },
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSwapChainDescriptor),
+            "::",
+            stringify!(height)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSwapChainDescriptor>())).presentMode as *const _ as usize
+        },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSwapChainDescriptor),
+            "::",
+            stringify!(presentMode)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUSwapChainDescriptor>())).implementation as *const _ as usize
+        },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUSwapChainDescriptor),
+            "::",
+            stringify!(implementation)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUTextureDataLayout {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub offset: u64,
+    pub bytesPerRow: u32,
+    pub rowsPerImage: u32,
+}
+#[test]
+fn bindgen_test_layout_WGPUTextureDataLayout() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUTextureDataLayout>(),
+        24usize,
+        concat!("Size of: ", stringify!(WGPUTextureDataLayout))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUTextureDataLayout>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUTextureDataLayout))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUTextureDataLayout>())).nextInChain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureDataLayout),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUTextureDataLayout>())).offset as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureDataLayout),
+            "::",
+            stringify!(offset)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUTextureDataLayout>())).bytesPerRow as *const _ as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureDataLayout),
+            "::",
+            stringify!(bytesPerRow)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUTextureDataLayout>())).rowsPerImage as *const _ as usize
+        },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureDataLayout),
+            "::",
+            stringify!(rowsPerImage)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUTextureViewDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub label: *const libc::c_char,
+    pub format: WGPUTextureFormat,
+    pub dimension: WGPUTextureViewDimension,
+    pub baseMipLevel: u32,
+    pub mipLevelCount: u32,
+    pub baseArrayLayer: u32,
+    pub arrayLayerCount: u32,
+    pub aspect: WGPUTextureAspect,
+}
+#[test]
+fn bindgen_test_layout_WGPUTextureViewDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUTextureViewDescriptor>(),
+        48usize,
+        concat!("Size of: ", stringify!(WGPUTextureViewDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUTextureViewDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUTextureViewDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUTextureViewDescriptor>())).nextInChain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureViewDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUTextureViewDescriptor>())).label as *const _ as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureViewDescriptor),
+            "::",
+            stringify!(label)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUTextureViewDescriptor>())).format as *const _ as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureViewDescriptor),
+            "::",
+            stringify!(format)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUTextureViewDescriptor>())).dimension as *const _ as usize
+        },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureViewDescriptor),
+            "::",
+            stringify!(dimension)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUTextureViewDescriptor>())).baseMipLevel as *const _ as usize
+        },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureViewDescriptor),
+            "::",
+            stringify!(baseMipLevel)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUTextureViewDescriptor>())).mipLevelCount as *const _
+                as usize
+        },
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureViewDescriptor),
+            "::",
+            stringify!(mipLevelCount)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUTextureViewDescriptor>())).baseArrayLayer as *const _
+                as usize
+        },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureViewDescriptor),
+            "::",
+            stringify!(baseArrayLayer)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUTextureViewDescriptor>())).arrayLayerCount as *const _
+                as usize
+        },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureViewDescriptor),
+            "::",
+            stringify!(arrayLayerCount)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUTextureViewDescriptor>())).aspect as *const _ as usize
+        },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureViewDescriptor),
+            "::",
+            stringify!(aspect)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUVertexAttributeDescriptor {
+    pub format: WGPUVertexFormat,
+    pub offset: u64,
+    pub shaderLocation: u32,
+}
+#[test]
+fn bindgen_test_layout_WGPUVertexAttributeDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUVertexAttributeDescriptor>(),
+        24usize,
+        concat!("Size of: ", stringify!(WGPUVertexAttributeDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUVertexAttributeDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUVertexAttributeDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUVertexAttributeDescriptor>())).format as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUVertexAttributeDescriptor),
+            "::",
+            stringify!(format)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUVertexAttributeDescriptor>())).offset as *const _ as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUVertexAttributeDescriptor),
+            "::",
+            stringify!(offset)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUVertexAttributeDescriptor>())).shaderLocation as *const _
+                as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUVertexAttributeDescriptor),
+            "::",
+            stringify!(shaderLocation)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUBindGroupDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub label: *const libc::c_char,
+    pub layout: WGPUBindGroupLayout,
+    pub entryCount: u32,
+    pub entries: *const WGPUBindGroupEntry,
+}
+#[test]
+fn bindgen_test_layout_WGPUBindGroupDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUBindGroupDescriptor>(),
+        40usize,
+        concat!("Size of: ", stringify!(WGPUBindGroupDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUBindGroupDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUBindGroupDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUBindGroupDescriptor>())).nextInChain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUBindGroupDescriptor>())).label as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupDescriptor),
+            "::",
+            stringify!(label)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUBindGroupDescriptor>())).layout as *const _ as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupDescriptor),
+            "::",
+            stringify!(layout)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUBindGroupDescriptor>())).entryCount as *const _ as usize
+        },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupDescriptor),
+            "::",
+            stringify!(entryCount)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUBindGroupDescriptor>())).entries as *const _ as usize
+        },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupDescriptor),
+            "::",
+            stringify!(entries)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUBindGroupLayoutDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub label: *const libc::c_char,
+    pub entryCount: u32,
+    pub entries: *const WGPUBindGroupLayoutEntry,
+}
+#[test]
+fn bindgen_test_layout_WGPUBindGroupLayoutDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUBindGroupLayoutDescriptor>(),
+        32usize,
+        concat!("Size of: ", stringify!(WGPUBindGroupLayoutDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUBindGroupLayoutDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUBindGroupLayoutDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUBindGroupLayoutDescriptor>())).nextInChain as *const _
+                as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupLayoutDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUBindGroupLayoutDescriptor>())).label as *const _ as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupLayoutDescriptor),
+            "::",
+            stringify!(label)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUBindGroupLayoutDescriptor>())).entryCount as *const _
+                as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupLayoutDescriptor),
+            "::",
+            stringify!(entryCount)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUBindGroupLayoutDescriptor>())).entries as *const _ as usize
+        },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBindGroupLayoutDescriptor),
+            "::",
+            stringify!(entries)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUBufferCopyView {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub layout: WGPUTextureDataLayout,
+    pub buffer: WGPUBuffer,
+}
+#[test]
+fn bindgen_test_layout_WGPUBufferCopyView() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUBufferCopyView>(),
+        40usize,
+        concat!("Size of: ", stringify!(WGPUBufferCopyView))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUBufferCopyView>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUBufferCopyView))
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUBufferCopyView>())).nextInChain as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBufferCopyView),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUBufferCopyView>())).layout as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBufferCopyView),
+            "::",
+            stringify!(layout)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUBufferCopyView>())).buffer as *const _ as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUBufferCopyView),
+            "::",
+            stringify!(buffer)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUColorStateDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub format: WGPUTextureFormat,
+    pub alphaBlend: WGPUBlendDescriptor,
+    pub colorBlend: WGPUBlendDescriptor,
+    pub writeMask: WGPUColorWriteMaskFlags,
+}
+#[test]
+fn bindgen_test_layout_WGPUColorStateDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUColorStateDescriptor>(),
+        40usize,
+        concat!("Size of: ", stringify!(WGPUColorStateDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUColorStateDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUColorStateDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUColorStateDescriptor>())).nextInChain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUColorStateDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUColorStateDescriptor>())).format as *const _ as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUColorStateDescriptor),
+            "::",
+            stringify!(format)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUColorStateDescriptor>())).alphaBlend as *const _ as usize
+        },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUColorStateDescriptor),
+            "::",
+            stringify!(alphaBlend)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUColorStateDescriptor>())).colorBlend as *const _ as usize
+        },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUColorStateDescriptor),
+            "::",
+            stringify!(colorBlend)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUColorStateDescriptor>())).writeMask as *const _ as usize
+        },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUColorStateDescriptor),
+            "::",
+            stringify!(writeMask)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUComputePipelineDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub label: *const libc::c_char,
+    pub layout: WGPUPipelineLayout,
+    pub computeStage: WGPUProgrammableStageDescriptor,
+}
+#[test]
+fn bindgen_test_layout_WGPUComputePipelineDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUComputePipelineDescriptor>(),
+        48usize,
+        concat!("Size of: ", stringify!(WGPUComputePipelineDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUComputePipelineDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUComputePipelineDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUComputePipelineDescriptor>())).nextInChain as *const _
+                as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUComputePipelineDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUComputePipelineDescriptor>())).label as *const _ as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUComputePipelineDescriptor),
+            "::",
+            stringify!(label)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUComputePipelineDescriptor>())).layout as *const _ as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUComputePipelineDescriptor),
+            "::",
+            stringify!(layout)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUComputePipelineDescriptor>())).computeStage as *const _
+                as usize
+        },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUComputePipelineDescriptor),
+            "::",
+            stringify!(computeStage)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUDepthStencilStateDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub format: WGPUTextureFormat,
+    pub depthWriteEnabled: bool,
+    pub depthCompare: WGPUCompareFunction,
+    pub stencilFront: WGPUStencilStateFaceDescriptor,
+    pub stencilBack: WGPUStencilStateFaceDescriptor,
+    pub stencilReadMask: u32,
+    pub stencilWriteMask: u32,
+}
+#[test]
+fn bindgen_test_layout_WGPUDepthStencilStateDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUDepthStencilStateDescriptor>(),
+        64usize,
+        concat!("Size of: ", stringify!(WGPUDepthStencilStateDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUDepthStencilStateDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUDepthStencilStateDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUDepthStencilStateDescriptor>())).nextInChain as *const _
+                as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUDepthStencilStateDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUDepthStencilStateDescriptor>())).format as *const _ as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUDepthStencilStateDescriptor),
+            "::",
+            stringify!(format)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUDepthStencilStateDescriptor>())).depthWriteEnabled
+                as *const _ as usize
+        },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUDepthStencilStateDescriptor),
+            "::",
+            stringify!(depthWriteEnabled)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUDepthStencilStateDescriptor>())).depthCompare as *const _
+                as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUDepthStencilStateDescriptor),
+            "::",
+            stringify!(depthCompare)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUDepthStencilStateDescriptor>())).stencilFront as *const _
+                as usize
+        },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUDepthStencilStateDescriptor),
+            "::",
+            stringify!(stencilFront)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUDepthStencilStateDescriptor>())).stencilBack as *const _
+                as usize
+        },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUDepthStencilStateDescriptor),
+            "::",
+            stringify!(stencilBack)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUDepthStencilStateDescriptor>())).stencilReadMask as *const _
+                as usize
+        },
+        52usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUDepthStencilStateDescriptor),
+            "::",
+            stringify!(stencilReadMask)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUDepthStencilStateDescriptor>())).stencilWriteMask
+                as *const _ as usize
+        },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUDepthStencilStateDescriptor),
+            "::",
+            stringify!(stencilWriteMask)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPURenderPassColorAttachmentDescriptor {
+    pub attachment: WGPUTextureView,
+    pub resolveTarget: WGPUTextureView,
+    pub loadOp: WGPULoadOp,
+    pub storeOp: WGPUStoreOp,
+    pub clearColor: WGPUColor,
+}
+#[test]
+fn bindgen_test_layout_WGPURenderPassColorAttachmentDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPURenderPassColorAttachmentDescriptor>(),
+        56usize,
+        concat!(
+            "Size of: ",
+            stringify!(WGPURenderPassColorAttachmentDescriptor)
+        )
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPURenderPassColorAttachmentDescriptor>(),
+        8usize,
+        concat!(
+            "Alignment of ",
+            stringify!(WGPURenderPassColorAttachmentDescriptor)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPassColorAttachmentDescriptor>())).attachment
+                as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPassColorAttachmentDescriptor),
+            "::",
+            stringify!(attachment)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPassColorAttachmentDescriptor>())).resolveTarget
+                as *const _ as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPassColorAttachmentDescriptor),
+            "::",
+            stringify!(resolveTarget)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPassColorAttachmentDescriptor>())).loadOp as *const _
+                as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPassColorAttachmentDescriptor),
+            "::",
+            stringify!(loadOp)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPassColorAttachmentDescriptor>())).storeOp as *const _
+                as usize
+        },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPassColorAttachmentDescriptor),
+            "::",
+            stringify!(storeOp)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPassColorAttachmentDescriptor>())).clearColor
+                as *const _ as usize
+        },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPassColorAttachmentDescriptor),
+            "::",
+            stringify!(clearColor)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUTextureCopyView {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub texture: WGPUTexture,
+    pub mipLevel: u32,
+    pub origin: WGPUOrigin3D,
+}
+#[test]
+fn bindgen_test_layout_WGPUTextureCopyView() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUTextureCopyView>(),
+        32usize,
+        concat!("Size of: ", stringify!(WGPUTextureCopyView))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUTextureCopyView>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUTextureCopyView))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUTextureCopyView>())).nextInChain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureCopyView),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUTextureCopyView>())).texture as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureCopyView),
+            "::",
+            stringify!(texture)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUTextureCopyView>())).mipLevel as *const _ as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureCopyView),
+            "::",
+            stringify!(mipLevel)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUTextureCopyView>())).origin as *const _ as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureCopyView),
+            "::",
+            stringify!(origin)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUTextureDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub label: *const libc::c_char,
+    pub usage: WGPUTextureUsageFlags,
+    pub dimension: WGPUTextureDimension,
+    pub size: WGPUExtent3D,
+    pub format: WGPUTextureFormat,
+    pub mipLevelCount: u32,
+    pub sampleCount: u32,
+}
+#[test]
+fn bindgen_test_layout_WGPUTextureDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUTextureDescriptor>(),
+        48usize,
+        concat!("Size of: ", stringify!(WGPUTextureDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUTextureDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUTextureDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUTextureDescriptor>())).nextInChain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUTextureDescriptor>())).label as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureDescriptor),
+            "::",
+            stringify!(label)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUTextureDescriptor>())).usage as *const _ as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureDescriptor),
+            "::",
+            stringify!(usage)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUTextureDescriptor>())).dimension as *const _ as usize
+        },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureDescriptor),
+            "::",
+            stringify!(dimension)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUTextureDescriptor>())).size as *const _ as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureDescriptor),
+            "::",
+            stringify!(size)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPUTextureDescriptor>())).format as *const _ as usize },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureDescriptor),
+            "::",
+            stringify!(format)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUTextureDescriptor>())).mipLevelCount as *const _ as usize
+        },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureDescriptor),
+            "::",
+            stringify!(mipLevelCount)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUTextureDescriptor>())).sampleCount as *const _ as usize
+        },
+        44usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUTextureDescriptor),
+            "::",
+            stringify!(sampleCount)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUVertexBufferLayoutDescriptor {
+    pub arrayStride: u64,
+    pub stepMode: WGPUInputStepMode,
+    pub attributeCount: u32,
+    pub attributes: *const WGPUVertexAttributeDescriptor,
+}
+#[test]
+fn bindgen_test_layout_WGPUVertexBufferLayoutDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUVertexBufferLayoutDescriptor>(),
+        24usize,
+        concat!("Size of: ", stringify!(WGPUVertexBufferLayoutDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUVertexBufferLayoutDescriptor>(),
+        8usize,
+        concat!(
+            "Alignment of ",
+            stringify!(WGPUVertexBufferLayoutDescriptor)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUVertexBufferLayoutDescriptor>())).arrayStride as *const _
+                as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUVertexBufferLayoutDescriptor),
+            "::",
+            stringify!(arrayStride)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUVertexBufferLayoutDescriptor>())).stepMode as *const _
+                as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUVertexBufferLayoutDescriptor),
+            "::",
+            stringify!(stepMode)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUVertexBufferLayoutDescriptor>())).attributeCount as *const _
+                as usize
+        },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUVertexBufferLayoutDescriptor),
+            "::",
+            stringify!(attributeCount)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUVertexBufferLayoutDescriptor>())).attributes as *const _
+                as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUVertexBufferLayoutDescriptor),
+            "::",
+            stringify!(attributes)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPURenderPassDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub label: *const libc::c_char,
+    pub colorAttachmentCount: u32,
+    pub colorAttachments: *const WGPURenderPassColorAttachmentDescriptor,
+    pub depthStencilAttachment: *const WGPURenderPassDepthStencilAttachmentDescriptor,
+    pub occlusionQuerySet: WGPUQuerySet,
+}
+#[test]
+fn bindgen_test_layout_WGPURenderPassDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPURenderPassDescriptor>(),
+        48usize,
+        concat!("Size of: ", stringify!(WGPURenderPassDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPURenderPassDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPURenderPassDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPassDescriptor>())).nextInChain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPassDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<WGPURenderPassDescriptor>())).label as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPassDescriptor),
+            "::",
+            stringify!(label)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPassDescriptor>())).colorAttachmentCount as *const _
+                as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPassDescriptor),
+            "::",
+            stringify!(colorAttachmentCount)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPassDescriptor>())).colorAttachments as *const _
+                as usize
+        },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPassDescriptor),
+            "::",
+            stringify!(colorAttachments)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPassDescriptor>())).depthStencilAttachment as *const _
+                as usize
+        },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPassDescriptor),
+            "::",
+            stringify!(depthStencilAttachment)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPassDescriptor>())).occlusionQuerySet as *const _
+                as usize
+        },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPassDescriptor),
+            "::",
+            stringify!(occlusionQuerySet)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUVertexStateDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub indexFormat: WGPUIndexFormat,
+    pub vertexBufferCount: u32,
+    pub vertexBuffers: *const WGPUVertexBufferLayoutDescriptor,
+}
+#[test]
+fn bindgen_test_layout_WGPUVertexStateDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPUVertexStateDescriptor>(),
+        24usize,
+        concat!("Size of: ", stringify!(WGPUVertexStateDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPUVertexStateDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPUVertexStateDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUVertexStateDescriptor>())).nextInChain as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUVertexStateDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUVertexStateDescriptor>())).indexFormat as *const _ as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUVertexStateDescriptor),
+            "::",
+            stringify!(indexFormat)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUVertexStateDescriptor>())).vertexBufferCount as *const _
+                as usize
+        },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUVertexStateDescriptor),
+            "::",
+            stringify!(vertexBufferCount)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPUVertexStateDescriptor>())).vertexBuffers as *const _
+                as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPUVertexStateDescriptor),
+            "::",
+            stringify!(vertexBuffers)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPURenderPipelineDescriptor {
+    pub nextInChain: *const WGPUChainedStruct,
+    pub label: *const libc::c_char,
+    pub layout: WGPUPipelineLayout,
+    pub vertexStage: WGPUProgrammableStageDescriptor,
+    pub fragmentStage: *const WGPUProgrammableStageDescriptor,
+    pub vertexState: *const WGPUVertexStateDescriptor,
+    pub primitiveTopology: WGPUPrimitiveTopology,
+    pub rasterizationState: *const WGPURasterizationStateDescriptor,
+    pub sampleCount: u32,
+    pub depthStencilState: *const WGPUDepthStencilStateDescriptor,
+    pub colorStateCount: u32,
+    pub colorStates: *const WGPUColorStateDescriptor,
+    pub sampleMask: u32,
+    pub alphaToCoverageEnabled: bool,
+}
+#[test]
+fn bindgen_test_layout_WGPURenderPipelineDescriptor() {
+    assert_eq!(
+        ::core::mem::size_of::<WGPURenderPipelineDescriptor>(),
+        120usize,
+        concat!("Size of: ", stringify!(WGPURenderPipelineDescriptor))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<WGPURenderPipelineDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(WGPURenderPipelineDescriptor))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPipelineDescriptor>())).nextInChain as *const _
+                as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPipelineDescriptor),
+            "::",
+            stringify!(nextInChain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPipelineDescriptor>())).label as *const _ as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPipelineDescriptor),
+            "::",
+            stringify!(label)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPipelineDescriptor>())).layout as *const _ as usize
+        },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPipelineDescriptor),
+            "::",
+            stringify!(layout)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPipelineDescriptor>())).vertexStage as *const _
+                as usize
+        },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPipelineDescriptor),
+            "::",
+            stringify!(vertexStage)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPipelineDescriptor>())).fragmentStage as *const _
+                as usize
+        },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPipelineDescriptor),
+            "::",
+            stringify!(fragmentStage)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPipelineDescriptor>())).vertexState as *const _
+                as usize
+        },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPipelineDescriptor),
+            "::",
+            stringify!(vertexState)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPipelineDescriptor>())).primitiveTopology as *const _
+                as usize
+        },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPipelineDescriptor),
+            "::",
+            stringify!(primitiveTopology)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPipelineDescriptor>())).rasterizationState as *const _
+                as usize
+        },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPipelineDescriptor),
+            "::",
+            stringify!(rasterizationState)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPipelineDescriptor>())).sampleCount as *const _
+                as usize
+        },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPipelineDescriptor),
+            "::",
+            stringify!(sampleCount)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPipelineDescriptor>())).depthStencilState as *const _
+                as usize
+        },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPipelineDescriptor),
+            "::",
+            stringify!(depthStencilState)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPipelineDescriptor>())).colorStateCount as *const _
+                as usize
+        },
+        96usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPipelineDescriptor),
+            "::",
+            stringify!(colorStateCount)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPipelineDescriptor>())).colorStates as *const _
+                as usize
+        },
+        104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPipelineDescriptor),
+            "::",
+            stringify!(colorStates)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPipelineDescriptor>())).sampleMask as *const _
+                as usize
+        },
+        112usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPipelineDescriptor),
+            "::",
+            stringify!(sampleMask)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::core::ptr::null::<WGPURenderPipelineDescriptor>())).alphaToCoverageEnabled
+                as *const _ as usize
+        },
+        116usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WGPURenderPipelineDescriptor),
+            "::",
+            stringify!(alphaToCoverageEnabled)
+        )
+    );
+}
+pub type WGPUBufferMapCallback = ::core::option::Option<
+    unsafe extern "C" fn(status: WGPUBufferMapAsyncStatus, userdata: *mut libc::c_void),
+>;
+pub type WGPUDeviceLostCallback = ::core::option::Option<
+    unsafe extern "C" fn(message: *const libc::c_char, userdata: *mut libc::c_void),
+>;
+pub type WGPUErrorCallback = ::core::option::Option<
+    unsafe extern "C" fn(
+        type_: WGPUErrorType,
+        message: *const libc::c_char,
+        userdata: *mut libc::c_void,
+    ),
+>;
+pub type WGPUFenceOnCompletionCallback = ::core::option::Option<
+    unsafe extern "C" fn(status: WGPUFenceCompletionStatus, userdata: *mut libc::c_void),
+>;
+pub type WGPURequestAdapterCallback =
+    ::core::option::Option<unsafe extern "C" fn(result: WGPUAdapter, userdata: *mut libc::c_void)>;
+pub type WGPURequestDeviceCallback =
+    ::core::option::Option<unsafe extern "C" fn(result: WGPUDevice, userdata: *mut libc::c_void)>;
+pub type WGPUProc = ::core::option::Option<unsafe extern "C" fn()>;
+pub type WGPUProcCreateInstance = ::core::option::Option<
+    unsafe extern "C" fn(descriptor: *const WGPUInstanceDescriptor) -> WGPUInstance,
+>;
+pub type WGPUProcGetProcAddress = ::core::option::Option<
+    unsafe extern "C" fn(device: WGPUDevice, procName: *const libc::c_char) -> WGPUProc,
+>;
+pub type WGPUProcAdapterGetProperties = ::core::option::Option<
+    unsafe extern "C" fn(adapter: WGPUAdapter, properties: *mut WGPUAdapterProperties),
+>;
+pub type WGPUProcAdapterRequestDevice = ::core::option::Option<
+    unsafe extern "C" fn(
+        adapter: WGPUAdapter,
+        descriptor: *const WGPUDeviceDescriptor,
+        callback: WGPURequestDeviceCallback,
+        userdata: *mut libc::c_void,
+    ),
+>;
+pub type WGPUProcBufferDestroy = ::core::option::Option<unsafe extern "C" fn(buffer: WGPUBuffer)>;
+pub type WGPUProcBufferGetConstMappedRange = ::core::option::Option<
+    unsafe extern "C" fn(buffer: WGPUBuffer, offset: usize, size: usize) -> *const libc::c_void,
+>;
+pub type WGPUProcBufferGetMappedRange = ::core::option::Option<
+    unsafe extern "C" fn(buffer: WGPUBuffer, offset: usize, size: usize) -> *mut libc::c_void,
+>;
+pub type WGPUProcBufferMapAsync = ::core::option::Option<
+    unsafe extern "C" fn(
+        buffer: WGPUBuffer,
+        mode: WGPUMapModeFlags,
+        offset: usize,
+        size: usize,
+        callback: WGPUBufferMapCallback,
+        userdata: *mut libc::c_void,
+    ),
+>;
+pub type WGPUProcBufferUnmap = ::core::option::Option<unsafe extern "C" fn(buffer: WGPUBuffer)>;
+pub type WGPUProcCommandEncoderBeginComputePass = ::core::option::Option<
+    unsafe extern "C" fn(
+        commandEncoder: WGPUCommandEncoder,
+        descriptor: *const WGPUComputePassDescriptor,
+    ) -> WGPUComputePassEncoder,
+>;
+pub type WGPUProcCommandEncoderBeginRenderPass = ::core::option::Option<
+    unsafe extern "C" fn(
+        commandEncoder: WGPUCommandEncoder,
+        descriptor: *const WGPURenderPassDescriptor,
+    ) -> WGPURenderPassEncoder,
+>;
+pub type WGPUProcCommandEncoderCopyBufferToBuffer = ::core::option::Option<
+    unsafe extern "C" fn(
+        commandEncoder: WGPUCommandEncoder,
+        source: WGPUBuffer,
+        sourceOffset: u64,
+        destination: WGPUBuffer,
+        destinationOffset: u64,
+        size: u64,
+    ),
+>;
+pub type WGPUProcCommandEncoderCopyBufferToTexture = ::core::option::Option<
+    unsafe extern "C" fn(
+        commandEncoder: WGPUCommandEncoder,
+        source: *const WGPUBufferCopyView,
+        destination: *const WGPUTextureCopyView,
+        copySize: *const WGPUExtent3D,
+    ),
+>;
+pub type WGPUProcCommandEncoderCopyTextureToBuffer = ::core::option::Option<
+    unsafe extern "C" fn(
+        commandEncoder: WGPUCommandEncoder,
+        source: *const WGPUTextureCopyView,
+        destination: *const WGPUBufferCopyView,
+        copySize: *const WGPUExtent3D,
+    ),
+>;
+pub type WGPUProcCommandEncoderCopyTextureToTexture = ::core::option::Option<
+    unsafe extern "C" fn(
+        commandEncoder: WGPUCommandEncoder,
+        source: *const WGPUTextureCopyView,
+        destination: *const WGPUTextureCopyView,
+        copySize: *const WGPUExtent3D,
+    ),
+>;
+pub type WGPUProcCommandEncoderFinish = ::core::option::Option<
+    unsafe extern "C" fn(
+        commandEncoder: WGPUCommandEncoder,
+        descriptor: *const WGPUCommandBufferDescriptor,
+    ) -> WGPUCommandBuffer,
+>;
+pub type WGPUProcCommandEncoderInsertDebugMarker = ::core::option::Option<
+    unsafe extern "C" fn(commandEncoder: WGPUCommandEncoder, markerLabel: *const libc::c_char),
+>;
+pub type WGPUProcCommandEncoderPopDebugGroup =
+    ::core::option::Option<unsafe extern "C" fn(commandEncoder: WGPUCommandEncoder)>;
+pub type WGPUProcCommandEncoderPushDebugGroup = ::core::option::Option<
+    unsafe extern "C" fn(commandEncoder: WGPUCommandEncoder, groupLabel: *const libc::c_char),
+>;
+pub type WGPUProcComputePassEncoderDispatch = ::core::option::Option<
+    unsafe extern "C" fn(computePassEncoder: WGPUComputePassEncoder, x: u32, y: u32, z: u32),
+>;
+pub type WGPUProcComputePassEncoderDispatchIndirect = ::core::option::Option<
+    unsafe extern "C" fn(
+        computePassEncoder: WGPUComputePassEncoder,
+        indirectBuffer: WGPUBuffer,
+        indirectOffset: u64,
+    ),
+>;
+pub type WGPUProcComputePassEncoderEndPass =
+    ::core::option::Option<unsafe extern "C" fn(computePassEncoder: WGPUComputePassEncoder)>;
+pub type WGPUProcComputePassEncoderInsertDebugMarker = ::core::option::Option<
+    unsafe extern "C" fn(
+        computePassEncoder: WGPUComputePassEncoder,
+        markerLabel: *const libc::c_char,
+    ),
+>;
+pub type WGPUProcComputePassEncoderPopDebugGroup =
+    ::core::option::Option<unsafe extern "C" fn(computePassEncoder: WGPUComputePassEncoder)>;
+pub type WGPUProcComputePassEncoderPushDebugGroup = ::core::option::Option<
+    unsafe extern "C" fn(
+        computePassEncoder: WGPUComputePassEncoder,
+        groupLabel: *const libc::c_char,
+    ),
+>;
+pub type WGPUProcComputePassEncoderSetBindGroup = ::core::option::Option<
+    unsafe extern "C" fn(
+        computePassEncoder: WGPUComputePassEncoder,
+        groupIndex: u32,
+        group: WGPUBindGroup,
+        dynamicOffsetCount: u32,
+        dynamicOffsets: *const u32,
+    ),
+>;
+pub type WGPUProcComputePassEncoderSetPipeline = ::core::option::Option<
+    unsafe extern "C" fn(computePassEncoder: WGPUComputePassEncoder, pipeline: WGPUComputePipeline),
+>;
+pub type WGPUProcComputePipelineGetBindGroupLayout = ::core::option::Option<
+    unsafe extern "C" fn(
+        computePipeline: WGPUComputePipeline,
+        groupIndex: u32,
+    ) -> WGPUBindGroupLayout,
+>;
+pub type WGPUProcDeviceCreateBindGroup = ::core::option::Option<
+    unsafe extern "C" fn(
+        device: WGPUDevice,
+        descriptor: *const WGPUBindGroupDescriptor,
+    ) -> WGPUBindGroup,
+>;
+pub type WGPUProcDeviceCreateBindGroupLayout = ::core::option::Option<
+    unsafe extern "C" fn(
+        device: WGPUDevice,
+        descriptor: *const WGPUBindGroupLayoutDescriptor,
+    ) -> WGPUBindGroupLayout,
+>;
+pub type WGPUProcDeviceCreateBuffer = ::core::option::Option<
+    unsafe extern "C" fn(device: WGPUDevice, descriptor: *const WGPUBufferDescriptor) -> WGPUBuffer,
+>;
+pub type WGPUProcDeviceCreateCommandEncoder = ::core::option::Option<
+    unsafe extern "C" fn(
+        device: WGPUDevice,
+        descriptor: *const WGPUCommandEncoderDescriptor,
+    ) -> WGPUCommandEncoder,
+>;
+pub type WGPUProcDeviceCreateComputePipeline = ::core::option::Option<
+    unsafe extern "C" fn(
+        device: WGPUDevice,
+        descriptor: *const WGPUComputePipelineDescriptor,
+    ) -> WGPUComputePipeline,
+>;
+pub type WGPUProcDeviceCreatePipelineLayout = ::core::option::Option<
+    unsafe extern "C" fn(
+        device: WGPUDevice,
+        descriptor: *const WGPUPipelineLayoutDescriptor,
+    ) -> WGPUPipelineLayout,
+>;
+pub type WGPUProcDeviceCreateRenderBundleEncoder = ::core::option::Option<
+    unsafe extern "C" fn(
+        device: WGPUDevice,
+        descriptor: *const WGPURenderBundleEncoderDescriptor,
+    ) -> WGPURenderBundleEncoder,
+>;
+pub type WGPUProcDeviceCreateRenderPipeline = ::core::option::Option<
+    unsafe extern "C" fn(
+        device: WGPUDevice,
+        descriptor: *const WGPURenderPipelineDescriptor,
+    ) -> WGPURenderPipeline,
+>;
+pub type WGPUProcDeviceCreateSampler = ::core::option::Option<
+    unsafe extern "C" fn(
+        device: WGPUDevice,
+        descriptor: *const WGPUSamplerDescriptor,
+    ) -> WGPUSampler,
+>;
+pub type WGPUProcDeviceCreateShaderModule = ::core::option::Option<
+    unsafe extern "C" fn(
+        device: WGPUDevice,
+        descriptor: *const WGPUShaderModuleDescriptor,
+    ) -> WGPUShaderModule,
+>;
+pub type WGPUProcDeviceCreateSwapChain = ::core::option::Option<
+    unsafe extern "C" fn(
+        device: WGPUDevice,
+        surface: WGPUSurface,
+        descriptor: *const WGPUSwapChainDescriptor,
+    ) -> WGPUSwapChain,
+>;
+pub type WGPUProcDeviceCreateTexture = ::core::option::Option<
+    unsafe extern "C" fn(
+        device: WGPUDevice,
+        descriptor: *const WGPUTextureDescriptor,
+    ) -> WGPUTexture,
+>;
+pub type WGPUProcDeviceGetDefaultQueue =
+    ::core::option::Option<unsafe extern "C" fn(device: WGPUDevice) -> WGPUQueue>;
+pub type WGPUProcDevicePopErrorScope = ::core::option::Option<
+    unsafe extern "C" fn(
+        device: WGPUDevice,
+        callback: WGPUErrorCallback,
+        userdata: *mut libc::c_void,
+    ) -> bool,
+>;
+pub type WGPUProcDevicePushErrorScope =
+    ::core::option::Option<unsafe extern "C" fn(device: WGPUDevice, filter: WGPUErrorFilter)>;
+pub type WGPUProcDeviceSetDeviceLostCallback = ::core::option::Option<
+    unsafe extern "C" fn(
+        device: WGPUDevice,
+        callback: WGPUDeviceLostCallback,
+        userdata: *mut libc::c_void,
+    ),
+>;
+pub type WGPUProcDeviceSetUncapturedErrorCallback = ::core::option::Option<
+    unsafe extern "C" fn(
+        device: WGPUDevice,
+        callback: WGPUErrorCallback,
+        userdata: *mut libc::c_void,
+    ),
+>;
+pub type WGPUProcFenceGetCompletedValue =
+    ::core::option::Option<unsafe extern "C" fn(fence: WGPUFence) -> u64>;
+pub type WGPUProcFenceOnCompletion = ::core::option::Option<
+    unsafe extern "C" fn(
+        fence: WGPUFence,
+        value: u64,
+        callback: WGPUFenceOnCompletionCallback,
+        userdata: *mut libc::c_void,
+    ),
+>;
+pub type WGPUProcInstanceCreateSurface = ::core::option::Option<
+    unsafe extern "C" fn(
+        instance: WGPUInstance,
+        descriptor: *const WGPUSurfaceDescriptor,
+    ) -> WGPUSurface,
+>;
+pub type WGPUProcInstanceRequestAdapter = ::core::option::Option<
+    unsafe extern "C" fn(
+        instance: WGPUInstance,
+        options: *const WGPURequestAdapterOptions,
+        callback: WGPURequestAdapterCallback,
+        userdata: *mut libc::c_void,
+    ),
+>;
+pub type WGPUProcQueueCreateFence = ::core::option::Option<
+    unsafe extern "C" fn(queue: WGPUQueue, descriptor: *const WGPUFenceDescriptor) -> WGPUFence,
+>;
+pub type WGPUProcQueueSignal = ::core::option::Option<
+    unsafe extern "C" fn(queue: WGPUQueue, fence: WGPUFence, signalValue: u64),
+>;
+pub type WGPUProcQueueSubmit = ::core::option::Option<
+    unsafe extern "C" fn(queue: WGPUQueue, commandCount: u32, commands: *const WGPUCommandBuffer),
+>;
+pub type WGPUProcQueueWriteBuffer = ::core::option::Option<
+    unsafe extern "C" fn(
+        queue: WGPUQueue,
+        buffer: WGPUBuffer,
+        bufferOffset: u64,
+        data: *const libc::c_void,
+        size: usize,
+    ),
+>;
+pub type WGPUProcQueueWriteTexture = ::core::option::Option<
+    unsafe extern "C" fn(
+        queue: WGPUQueue,
+        destination: *const WGPUTextureCopyView,
+        data: *const libc::c_void,
+        dataSize: usize,
+        dataLayout: *const WGPUTextureDataLayout,
+        writeSize: *const WGPUExtent3D,
+    ),
+>;
+pub type WGPUProcRenderBundleEncoderDraw = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderBundleEncoder: WGPURenderBundleEncoder,
+        vertexCount: u32,
+        instanceCount: u32,
+        firstVertex: u32,
+        firstInstance: u32,
+    ),
+>;
+pub type WGPUProcRenderBundleEncoderDrawIndexed = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderBundleEncoder: WGPURenderBundleEncoder,
+        indexCount: u32,
+        instanceCount: u32,
+        firstIndex: u32,
+        baseVertex: i32,
+        firstInstance: u32,
+    ),
+>;
+pub type WGPUProcRenderBundleEncoderDrawIndexedIndirect = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderBundleEncoder: WGPURenderBundleEncoder,
+        indirectBuffer: WGPUBuffer,
+        indirectOffset: u64,
+    ),
+>;
+pub type WGPUProcRenderBundleEncoderDrawIndirect = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderBundleEncoder: WGPURenderBundleEncoder,
+        indirectBuffer: WGPUBuffer,
+        indirectOffset: u64,
+    ),
+>;
+pub type WGPUProcRenderBundleEncoderFinish = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderBundleEncoder: WGPURenderBundleEncoder,
+        descriptor: *const WGPURenderBundleDescriptor,
+    ) -> WGPURenderBundle,
+>;
+pub type WGPUProcRenderBundleEncoderInsertDebugMarker = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderBundleEncoder: WGPURenderBundleEncoder,
+        markerLabel: *const libc::c_char,
+    ),
+>;
+pub type WGPUProcRenderBundleEncoderPopDebugGroup =
+    ::core::option::Option<unsafe extern "C" fn(renderBundleEncoder: WGPURenderBundleEncoder)>;
+pub type WGPUProcRenderBundleEncoderPushDebugGroup = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderBundleEncoder: WGPURenderBundleEncoder,
+        groupLabel: *const libc::c_char,
+    ),
+>;
+pub type WGPUProcRenderBundleEncoderSetBindGroup = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderBundleEncoder: WGPURenderBundleEncoder,
+        groupIndex: u32,
+        group: WGPUBindGroup,
+        dynamicOffsetCount: u32,
+        dynamicOffsets: *const u32,
+    ),
+>;
+pub type WGPUProcRenderBundleEncoderSetIndexBuffer = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderBundleEncoder: WGPURenderBundleEncoder,
+        buffer: WGPUBuffer,
+        offset: u64,
+        size: u64,
+    ),
+>;
+pub type WGPUProcRenderBundleEncoderSetPipeline = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderBundleEncoder: WGPURenderBundleEncoder,
+        pipeline: WGPURenderPipeline,
+    ),
+>;
+pub type WGPUProcRenderBundleEncoderSetVertexBuffer = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderBundleEncoder: WGPURenderBundleEncoder,
+        slot: u32,
+        buffer: WGPUBuffer,
+        offset: u64,
+        size: u64,
+    ),
+>;
+pub type WGPUProcRenderPassEncoderDraw = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderPassEncoder: WGPURenderPassEncoder,
+        vertexCount: u32,
+        instanceCount: u32,
+        firstVertex: u32,
+        firstInstance: u32,
+    ),
+>;
+pub type WGPUProcRenderPassEncoderDrawIndexed = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderPassEncoder: WGPURenderPassEncoder,
+        indexCount: u32,
+        instanceCount: u32,
+        firstIndex: u32,
+        baseVertex: i32,
+        firstInstance: u32,
+    ),
+>;
+pub type WGPUProcRenderPassEncoderDrawIndexedIndirect = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderPassEncoder: WGPURenderPassEncoder,
+        indirectBuffer: WGPUBuffer,
+        indirectOffset: u64,
+    ),
+>;
+pub type WGPUProcRenderPassEncoderDrawIndirect = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderPassEncoder: WGPURenderPassEncoder,
+        indirectBuffer: WGPUBuffer,
+        indirectOffset: u64,
+    ),
+>;
+pub type WGPUProcRenderPassEncoderEndPass =
+    ::core::option::Option<unsafe extern "C" fn(renderPassEncoder: WGPURenderPassEncoder)>;
+pub type WGPUProcRenderPassEncoderExecuteBundles = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderPassEncoder: WGPURenderPassEncoder,
+        bundlesCount: u32,
+        bundles: *const WGPURenderBundle,
+    ),
+>;
+pub type WGPUProcRenderPassEncoderInsertDebugMarker = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderPassEncoder: WGPURenderPassEncoder,
+        markerLabel: *const libc::c_char,
+    ),
+>;
+pub type WGPUProcRenderPassEncoderPopDebugGroup =
+    ::core::option::Option<unsafe extern "C" fn(renderPassEncoder: WGPURenderPassEncoder)>;
+pub type WGPUProcRenderPassEncoderPushDebugGroup = ::core::option::Option<
+    unsafe extern "C" fn(renderPassEncoder: WGPURenderPassEncoder, groupLabel: *const libc::c_char),
+>;
+pub type WGPUProcRenderPassEncoderSetBindGroup = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderPassEncoder: WGPURenderPassEncoder,
+        groupIndex: u32,
+        group: WGPUBindGroup,
+        dynamicOffsetCount: u32,
+        dynamicOffsets: *const u32,
+    ),
+>;
+pub type WGPUProcRenderPassEncoderSetBlendColor = ::core::option::Option<
+    unsafe extern "C" fn(renderPassEncoder: WGPURenderPassEncoder, color: *const WGPUColor),
+>;
+pub type WGPUProcRenderPassEncoderSetIndexBuffer = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderPassEncoder: WGPURenderPassEncoder,
+        buffer: WGPUBuffer,
+        offset: u64,
+        size: u64,
+    ),
+>;
+pub type WGPUProcRenderPassEncoderSetPipeline = ::core::option::Option<
+    unsafe extern "C" fn(renderPassEncoder: WGPURenderPassEncoder, pipeline: WGPURenderPipeline),
+>;
+pub type WGPUProcRenderPassEncoderSetScissorRect = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderPassEncoder: WGPURenderPassEncoder,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+    ),
+>;
+pub type WGPUProcRenderPassEncoderSetStencilReference = ::core::option::Option<
+    unsafe extern "C" fn(renderPassEncoder: WGPURenderPassEncoder, reference: u32),
+>;
+pub type WGPUProcRenderPassEncoderSetVertexBuffer = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderPassEncoder: WGPURenderPassEncoder,
+        slot: u32,
+        buffer: WGPUBuffer,
+        offset: u64,
+        size: u64,
+    ),
+>;
+pub type WGPUProcRenderPassEncoderSetViewport = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderPassEncoder: WGPURenderPassEncoder,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        minDepth: f32,
+        maxDepth: f32,
+    ),
+>;
+pub type WGPUProcRenderPipelineGetBindGroupLayout = ::core::option::Option<
+    unsafe extern "C" fn(
+        renderPipeline: WGPURenderPipeline,
+        groupIndex: u32,
+    ) -> WGPUBindGroupLayout,
+>;
+pub type WGPUProcSwapChainGetCurrentTextureView =
+    ::core::option::Option<unsafe extern "C" fn(swapChain: WGPUSwapChain) -> WGPUTextureView>;
+pub type WGPUProcSwapChainPresent =
+    ::core::option::Option<unsafe extern "C" fn(swapChain: WGPUSwapChain)>;
+pub type WGPUProcTextureCreateView = ::core::option::Option<
+    unsafe extern "C" fn(
+        texture: WGPUTexture,
+        descriptor: *const WGPUTextureViewDescriptor,
+    ) -> WGPUTextureView,
+>;
+pub type WGPUProcTextureDestroy =
+    ::core::option::Option<unsafe extern "C" fn(texture: WGPUTexture)>;
+pub type WGPUProcAdapterReference =
+    ::core::option::Option<unsafe extern "C" fn(adapter: WGPUAdapter)>;
+pub type WGPUProcAdapterRelease =
+    ::core::option::Option<unsafe extern "C" fn(adapter: WGPUAdapter)>;
+pub type WGPUProcBindGroupReference =
+    ::core::option::Option<unsafe extern "C" fn(bindGroup: WGPUBindGroup)>;
+pub type WGPUProcBindGroupRelease =
+    ::core::option::Option<unsafe extern "C" fn(bindGroup: WGPUBindGroup)>;
+pub type WGPUProcBindGroupLayoutReference =
+    ::core::option::Option<unsafe extern "C" fn(bindGroupLayout: WGPUBindGroupLayout)>;
+pub type WGPUProcBindGroupLayoutRelease =
+    ::core::option::Option<unsafe extern "C" fn(bindGroupLayout: WGPUBindGroupLayout)>;
+pub type WGPUProcBufferReference = ::core::option::Option<unsafe extern "C" fn(buffer: WGPUBuffer)>;
+pub type WGPUProcBufferRelease = ::core::option::Option<unsafe extern "C" fn(buffer: WGPUBuffer)>;
+pub type WGPUProcCommandBufferReference =
+    ::core::option::Option<unsafe extern "C" fn(commandBuffer: WGPUCommandBuffer)>;
+pub type WGPUProcCommandBufferRelease =
+    ::core::option::Option<unsafe extern "C" fn(commandBuffer: WGPUCommandBuffer)>;
+pub type WGPUProcCommandEncoderReference =
+    ::core::option::Option<unsafe extern "C" fn(commandEncoder: WGPUCommandEncoder)>;
+pub type WGPUProcCommandEncoderRelease =
+    ::core::option::Option<unsafe extern "C" fn(commandEncoder: WGPUCommandEncoder)>;
+pub type WGPUProcComputePassEncoderReference =
+    ::core::option::Option<unsafe extern "C" fn(computePassEncoder: WGPUComputePassEncoder)>;
+pub type WGPUProcComputePassEncoderRelease =
+    ::core::option::Option<unsafe extern "C" fn(computePassEncoder: WGPUComputePassEncoder)>;
+pub type WGPUProcComputePipelineReference =
+    ::core::option::Option<unsafe extern "C" fn(computePipeline: WGPUComputePipeline)>;
+pub type WGPUProcComputePipelineRelease =
+    ::core::option::Option<unsafe extern "C" fn(computePipeline: WGPUComputePipeline)>;
+pub type WGPUProcDeviceReference = ::core::option::Option<unsafe extern "C" fn(device: WGPUDevice)>;
+pub type WGPUProcDeviceRelease = ::core::option::Option<unsafe extern "C" fn(device: WGPUDevice)>;
+pub type WGPUProcFenceReference = ::core::option::Option<unsafe extern "C" fn(fence: WGPUFence)>;
+pub type WGPUProcFenceRelease = ::core::option::Option<unsafe extern "C" fn(fence: WGPUFence)>;
+pub type WGPUProcInstanceReference =
+    ::core::option::Option<unsafe extern "C" fn(instance: WGPUInstance)>;
+pub type WGPUProcInstanceRelease =
+    ::core::option::Option<unsafe extern "C" fn(instance: WGPUInstance)>;
+pub type WGPUProcPipelineLayoutReference =
+    ::core::option::Option<unsafe extern "C" fn(pipelineLayout: WGPUPipelineLayout)>;
+pub type WGPUProcPipelineLayoutRelease =
+    ::core::option::Option<unsafe extern "C" fn(pipelineLayout: WGPUPipelineLayout)>;
+pub type WGPUProcQuerySetReference =
+    ::core::option::Option<unsafe extern "C" fn(querySet: WGPUQuerySet)>;
+pub type WGPUProcQuerySetRelease =
+    ::core::option::Option<unsafe extern "C" fn(querySet: WGPUQuerySet)>;
+pub type WGPUProcQueueReference = ::core::option::Option<unsafe extern "C" fn(queue: WGPUQueue)>;
+pub type WGPUProcQueueRelease = ::core::option::Option<unsafe extern "C" fn(queue: WGPUQueue)>;
+pub type WGPUProcRenderBundleReference =
+    ::core::option::Option<unsafe extern "C" fn(renderBundle: WGPURenderBundle)>;
+pub type WGPUProcRenderBundleRelease =
+    ::core::option::Option<unsafe extern "C" fn(renderBundle: WGPURenderBundle)>;
+pub type WGPUProcRenderBundleEncoderReference =
+    ::core::option::Option<unsafe extern "C" fn(renderBundleEncoder: WGPURenderBundleEncoder)>;
+pub type WGPUProcRenderBundleEncoderRelease =
+    ::core::option::Option<unsafe extern "C" fn(renderBundleEncoder: WGPURenderBundleEncoder)>;
+pub type WGPUProcRenderPassEncoderReference =
+    ::core::option::Option<unsafe extern "C" fn(renderPassEncoder: WGPURenderPassEncoder)>;
+pub type WGPUProcRenderPassEncoderRelease =
+    ::core::option::Option<unsafe extern "C" fn(renderPassEncoder: WGPURenderPassEncoder)>;
+pub type WGPUProcRenderPipelineReference =
+    ::core::option::Option<unsafe extern "C" fn(renderPipeline: WGPURenderPipeline)>;
+pub type WGPUProcRenderPipelineRelease =
+    ::core::option::Option<unsafe extern "C" fn(renderPipeline: WGPURenderPipeline)>;
+pub type WGPUProcSamplerReference =
+    ::core::option::Option<unsafe extern "C" fn(sampler: WGPUSampler)>;
+pub type WGPUProcSamplerRelease =
+    ::core::option::Option<unsafe extern "C" fn(sampler: WGPUSampler)>;
+pub type WGPUProcShaderModuleReference =
+    ::core::option::Option<unsafe extern "C" fn(shaderModule: WGPUShaderModule)>;
+pub type WGPUProcShaderModuleRelease =
+    ::core::option::Option<unsafe extern "C" fn(shaderModule: WGPUShaderModule)>;
+pub type WGPUProcSurfaceReference =
+    ::core::option::Option<unsafe extern "C" fn(surface: WGPUSurface)>;
+pub type WGPUProcSurfaceRelease =
+    ::core::option::Option<unsafe extern "C" fn(surface: WGPUSurface)>;
+pub type WGPUProcSwapChainReference =
+    ::core::option::Option<unsafe extern "C" fn(swapChain: WGPUSwapChain)>;
+pub type WGPUProcSwapChainRelease =
+    ::core::option::Option<unsafe extern "C" fn(swapChain: WGPUSwapChain)>;
+pub type WGPUProcTextureReference =
+    ::core::option::Option<unsafe extern "C" fn(texture: WGPUTexture)>;
+pub type WGPUProcTextureRelease =
+    ::core::option::Option<unsafe extern "C" fn(texture: WGPUTexture)>;
+pub type WGPUProcTextureViewReference =
+    ::core::option::Option<unsafe extern "C" fn(textureView: WGPUTextureView)>;
+pub type WGPUProcTextureViewRelease =
+    ::core::option::Option<unsafe extern "C" fn(textureView: WGPUTextureView)>;
@@ -0,0 +1,200 @@
+use std::ffi::CStr;
+use std::mem;
+
+/// Bindings for the official `webgpu.h` from webgpu-headers. Its structs, enum values and entry
+/// points differ from Dawn's `webgpu.h` in `crate::webgpu`; `dawn::webgpu_native` translates
+/// between the two.
+///
+/// Only the types and the `WGPUProc*` function pointers are declared. Implementations are loaded
+/// at runtime, and declaring their `wgpu*` symbols would clash with Dawn's. Enable the `bindgen`
+/// feature and set `DAWN_SYS_WEBGPU_HEADER` to the path of a `webgpu.h` to generate them from it
+/// instead of using the baked copy.
+///
+/// <https://github.com/webgpu-native/webgpu-headers>
+#[allow(non_snake_case)]
+#[allow(non_upper_case_globals)]
+#[allow(non_camel_case_types)]
+pub mod webgpu {
+    #[cfg(not(feature = "bindgen"))]
+    include!("webgpu_headers.rs");
+
+    #[cfg(feature = "bindgen")]
+    include!(concat!(env!("OUT_DIR"), "/webgpu_native.rs"));
+}
+
+use self::webgpu::*;
+
+macro_rules! proc_table {
+    ($($field:ident: $ty:ident => $name:expr,)*) => {
+        /// The entry points of a `webgpu.h` implementation. Entry points that the implementation
+        /// doesn't export are `None`.
+        #[allow(non_snake_case)]
+        #[derive(Copy, Clone)]
+        pub struct WebGpuProcTable {
+            $(pub $field: $ty,)*
+        }
+
+        /// Resolve the entry points of a `webgpu.h` implementation, e.g. one loaded at runtime
+        /// with `dlopen`. `resolve` is called with each `wgpu*` symbol name and returns its
+        /// address or `null`.
+        ///
+        /// # Safety
+        ///
+        /// Every non-null address returned by `resolve` must point to a function with the
+        /// signature declared for that symbol in `webgpu`.
+        pub unsafe fn load_procs<F>(mut resolve: F) -> WebGpuProcTable
+        where
+            F: FnMut(&CStr) -> *const libc::c_void,
+        {
+            WebGpuProcTable {
+                $(
+                    // Each entry is an `Option<unsafe extern "C" fn(..)>`, which has the layout
+                    // of a nullable pointer.
+                    $field: mem::transmute::<*const libc::c_void, $ty>(resolve(
+                        CStr::from_bytes_with_nul_unchecked(concat!($name, "\0").as_bytes()),
+                    )),
+                )*
+            }
+        }
+    };
+}
+
+#[rustfmt::skip]
+proc_table! {
+    createInstance: WGPUProcCreateInstance => "wgpuCreateInstance",
+    getProcAddress: WGPUProcGetProcAddress => "wgpuGetProcAddress",
+    adapterGetProperties: WGPUProcAdapterGetProperties => "wgpuAdapterGetProperties",
+    adapterRequestDevice: WGPUProcAdapterRequestDevice => "wgpuAdapterRequestDevice",
+    bufferDestroy: WGPUProcBufferDestroy => "wgpuBufferDestroy",
+    bufferGetConstMappedRange: WGPUProcBufferGetConstMappedRange => "wgpuBufferGetConstMappedRange",
+    bufferGetMappedRange: WGPUProcBufferGetMappedRange => "wgpuBufferGetMappedRange",
+    bufferMapAsync: WGPUProcBufferMapAsync => "wgpuBufferMapAsync",
+    bufferUnmap: WGPUProcBufferUnmap => "wgpuBufferUnmap",
+    commandEncoderBeginComputePass: WGPUProcCommandEncoderBeginComputePass => "wgpuCommandEncoderBeginComputePass",
+    commandEncoderBeginRenderPass: WGPUProcCommandEncoderBeginRenderPass => "wgpuCommandEncoderBeginRenderPass",
+    commandEncoderCopyBufferToBuffer: WGPUProcCommandEncoderCopyBufferToBuffer => "wgpuCommandEncoderCopyBufferToBuffer",
+    commandEncoderCopyBufferToTexture: WGPUProcCommandEncoderCopyBufferToTexture => "wgpuCommandEncoderCopyBufferToTexture",
+    commandEncoderCopyTextureToBuffer: WGPUProcCommandEncoderCopyTextureToBuffer => "wgpuCommandEncoderCopyTextureToBuffer",
+    commandEncoderCopyTextureToTexture: WGPUProcCommandEncoderCopyTextureToTexture => "wgpuCommandEncoderCopyTextureToTexture",
+    commandEncoderFinish: WGPUProcCommandEncoderFinish => "wgpuCommandEncoderFinish",
+    commandEncoderInsertDebugMarker: WGPUProcCommandEncoderInsertDebugMarker => "wgpuCommandEncoderInsertDebugMarker",
+    commandEncoderPopDebugGroup: WGPUProcCommandEncoderPopDebugGroup => "wgpuCommandEncoderPopDebugGroup",
+    commandEncoderPushDebugGroup: WGPUProcCommandEncoderPushDebugGroup => "wgpuCommandEncoderPushDebugGroup",
+    computePassEncoderDispatch: WGPUProcComputePassEncoderDispatch => "wgpuComputePassEncoderDispatch",
+    computePassEncoderDispatchIndirect: WGPUProcComputePassEncoderDispatchIndirect => "wgpuComputePassEncoderDispatchIndirect",
+    computePassEncoderEndPass: WGPUProcComputePassEncoderEndPass => "wgpuComputePassEncoderEndPass",
+    computePassEncoderInsertDebugMarker: WGPUProcComputePassEncoderInsertDebugMarker => "wgpuComputePassEncoderInsertDebugMarker",
+    computePassEncoderPopDebugGroup: WGPUProcComputePassEncoderPopDebugGroup => "wgpuComputePassEncoderPopDebugGroup",
+    computePassEncoderPushDebugGroup: WGPUProcComputePassEncoderPushDebugGroup => "wgpuComputePassEncoderPushDebugGroup",
+    computePassEncoderSetBindGroup: WGPUProcComputePassEncoderSetBindGroup => "wgpuComputePassEncoderSetBindGroup",
+    computePassEncoderSetPipeline: WGPUProcComputePassEncoderSetPipeline => "wgpuComputePassEncoderSetPipeline",
+    computePipelineGetBindGroupLayout: WGPUProcComputePipelineGetBindGroupLayout => "wgpuComputePipelineGetBindGroupLayout",
+    deviceCreateBindGroup: WGPUProcDeviceCreateBindGroup => "wgpuDeviceCreateBindGroup",
+    deviceCreateBindGroupLayout: WGPUProcDeviceCreateBindGroupLayout => "wgpuDeviceCreateBindGroupLayout",
+    deviceCreateBuffer: WGPUProcDeviceCreateBuffer => "wgpuDeviceCreateBuffer",
+    deviceCreateCommandEncoder: WGPUProcDeviceCreateCommandEncoder => "wgpuDeviceCreateCommandEncoder",
+    deviceCreateComputePipeline: WGPUProcDeviceCreateComputePipeline => "wgpuDeviceCreateComputePipeline",
+    deviceCreatePipelineLayout: WGPUProcDeviceCreatePipelineLayout => "wgpuDeviceCreatePipelineLayout",
+    deviceCreateRenderBundleEncoder: WGPUProcDeviceCreateRenderBundleEncoder => "wgpuDeviceCreateRenderBundleEncoder",
+    deviceCreateRenderPipeline: WGPUProcDeviceCreateRenderPipeline => "wgpuDeviceCreateRenderPipeline",
+    deviceCreateSampler: WGPUProcDeviceCreateSampler => "wgpuDeviceCreateSampler",
+    deviceCreateShaderModule: WGPUProcDeviceCreateShaderModule => "wgpuDeviceCreateShaderModule",
+    deviceCreateSwapChain: WGPUProcDeviceCreateSwapChain => "wgpuDeviceCreateSwapChain",
+    deviceCreateTexture: WGPUProcDeviceCreateTexture => "wgpuDeviceCreateTexture",
+    deviceGetDefaultQueue: WGPUProcDeviceGetDefaultQueue => "wgpuDeviceGetDefaultQueue",
+    devicePopErrorScope: WGPUProcDevicePopErrorScope => "wgpuDevicePopErrorScope",
+    devicePushErrorScope: WGPUProcDevicePushErrorScope => "wgpuDevicePushErrorScope",
+    deviceSetDeviceLostCallback: WGPUProcDeviceSetDeviceLostCallback => "wgpuDeviceSetDeviceLostCallback",
+    deviceSetUncapturedErrorCallback: WGPUProcDeviceSetUncapturedErrorCallback => "wgpuDeviceSetUncapturedErrorCallback",
+    fenceGetCompletedValue: WGPUProcFenceGetCompletedValue => "wgpuFenceGetCompletedValue",
+    fenceOnCompletion: WGPUProcFenceOnCompletion => "wgpuFenceOnCompletion",
+    instanceCreateSurface: WGPUProcInstanceCreateSurface => "wgpuInstanceCreateSurface",
+    instanceRequestAdapter: WGPUProcInstanceRequestAdapter => "wgpuInstanceRequestAdapter",
+    queueCreateFence: WGPUProcQueueCreateFence => "wgpuQueueCreateFence",
+    queueSignal: WGPUProcQueueSignal => "wgpuQueueSignal",
+    queueSubmit: WGPUProcQueueSubmit => "wgpuQueueSubmit",
+    queueWriteBuffer: WGPUProcQueueWriteBuffer => "wgpuQueueWriteBuffer",
+    queueWriteTexture: WGPUProcQueueWriteTexture => "wgpuQueueWriteTexture",
+    renderBundleEncoderDraw: WGPUProcRenderBundleEncoderDraw => "wgpuRenderBundleEncoderDraw",
+    renderBundleEncoderDrawIndexed: WGPUProcRenderBundleEncoderDrawIndexed => "wgpuRenderBundleEncoderDrawIndexed",
+    renderBundleEncoderDrawIndexedIndirect: WGPUProcRenderBundleEncoderDrawIndexedIndirect => "wgpuRenderBundleEncoderDrawIndexedIndirect",
+    renderBundleEncoderDrawIndirect: WGPUProcRenderBundleEncoderDrawIndirect => "wgpuRenderBundleEncoderDrawIndirect",
+    renderBundleEncoderFinish: WGPUProcRenderBundleEncoderFinish => "wgpuRenderBundleEncoderFinish",
+    renderBundleEncoderInsertDebugMarker: WGPUProcRenderBundleEncoderInsertDebugMarker => "wgpuRenderBundleEncoderInsertDebugMarker",
+    renderBundleEncoderPopDebugGroup: WGPUProcRenderBundleEncoderPopDebugGroup => "wgpuRenderBundleEncoderPopDebugGroup",
+    renderBundleEncoderPushDebugGroup: WGPUProcRenderBundleEncoderPushDebugGroup => "wgpuRenderBundleEncoderPushDebugGroup",
+    renderBundleEncoderSetBindGroup: WGPUProcRenderBundleEncoderSetBindGroup => "wgpuRenderBundleEncoderSetBindGroup",
+    renderBundleEncoderSetIndexBuffer: WGPUProcRenderBundleEncoderSetIndexBuffer => "wgpuRenderBundleEncoderSetIndexBuffer",
+    renderBundleEncoderSetPipeline: WGPUProcRenderBundleEncoderSetPipeline => "wgpuRenderBundleEncoderSetPipeline",
+    renderBundleEncoderSetVertexBuffer: WGPUProcRenderBundleEncoderSetVertexBuffer => "wgpuRenderBundleEncoderSetVertexBuffer",
+    renderPassEncoderDraw: WGPUProcRenderPassEncoderDraw => "wgpuRenderPassEncoderDraw",
+    renderPassEncoderDrawIndexed: WGPUProcRenderPassEncoderDrawIndexed => "wgpuRenderPassEncoderDrawIndexed",
+    renderPassEncoderDrawIndexedIndirect: WGPUProcRenderPassEncoderDrawIndexedIndirect => "wgpuRenderPassEncoderDrawIndexedIndirect",
+    renderPassEncoderDrawIndirect: WGPUProcRenderPassEncoderDrawIndirect => "wgpuRenderPassEncoderDrawIndirect",
+    renderPassEncoderEndPass: WGPUProcRenderPassEncoderEndPass => "wgpuRenderPassEncoderEndPass",
+    renderPassEncoderExecuteBundles: WGPUProcRenderPassEncoderExecuteBundles => "wgpuRenderPassEncoderExecuteBundles",
+    renderPassEncoderInsertDebugMarker: WGPUProcRenderPassEncoderInsertDebugMarker => "wgpuRenderPassEncoderInsertDebugMarker",
+    renderPassEncoderPopDebugGroup: WGPUProcRenderPassEncoderPopDebugGroup => "wgpuRenderPassEncoderPopDebugGroup",
+    renderPassEncoderPushDebugGroup: WGPUProcRenderPassEncoderPushDebugGroup => "wgpuRenderPassEncoderPushDebugGroup",
+    renderPassEncoderSetBindGroup: WGPUProcRenderPassEncoderSetBindGroup => "wgpuRenderPassEncoderSetBindGroup",
+    renderPassEncoderSetBlendColor: WGPUProcRenderPassEncoderSetBlendColor => "wgpuRenderPassEncoderSetBlendColor",
+    renderPassEncoderSetIndexBuffer: WGPUProcRenderPassEncoderSetIndexBuffer => "wgpuRenderPassEncoderSetIndexBuffer",
+    renderPassEncoderSetPipeline: WGPUProcRenderPassEncoderSetPipeline => "wgpuRenderPassEncoderSetPipeline",
+    renderPassEncoderSetScissorRect: WGPUProcRenderPassEncoderSetScissorRect => "wgpuRenderPassEncoderSetScissorRect",
+    renderPassEncoderSetStencilReference: WGPUProcRenderPassEncoderSetStencilReference => "wgpuRenderPassEncoderSetStencilReference",
+    renderPassEncoderSetVertexBuffer: WGPUProcRenderPassEncoderSetVertexBuffer => "wgpuRenderPassEncoderSetVertexBuffer",
+    renderPassEncoderSetViewport: WGPUProcRenderPassEncoderSetViewport => "wgpuRenderPassEncoderSetViewport",
+    renderPipelineGetBindGroupLayout: WGPUProcRenderPipelineGetBindGroupLayout => "wgpuRenderPipelineGetBindGroupLayout",
+    swapChainGetCurrentTextureView: WGPUProcSwapChainGetCurrentTextureView => "wgpuSwapChainGetCurrentTextureView",
+    swapChainPresent: WGPUProcSwapChainPresent => "wgpuSwapChainPresent",
+    textureCreateView: WGPUProcTextureCreateView => "wgpuTextureCreateView",
+    textureDestroy: WGPUProcTextureDestroy => "wgpuTextureDestroy",
+    adapterReference: WGPUProcAdapterReference => "wgpuAdapterReference",
+    adapterRelease: WGPUProcAdapterRelease => "wgpuAdapterRelease",
+    bindGroupReference: WGPUProcBindGroupReference => "wgpuBindGroupReference",
+    bindGroupRelease: WGPUProcBindGroupRelease => "wgpuBindGroupRelease",
+    bindGroupLayoutReference: WGPUProcBindGroupLayoutReference => "wgpuBindGroupLayoutReference",
+    bindGroupLayoutRelease: WGPUProcBindGroupLayoutRelease => "wgpuBindGroupLayoutRelease",
+    bufferReference: WGPUProcBufferReference => "wgpuBufferReference",
+    bufferRelease: WGPUProcBufferRelease => "wgpuBufferRelease",
+    commandBufferReference: WGPUProcCommandBufferReference => "wgpuCommandBufferReference",
+    commandBufferRelease: WGPUProcCommandBufferRelease => "wgpuCommandBufferRelease",
+    commandEncoderReference: WGPUProcCommandEncoderReference => "wgpuCommandEncoderReference",
+    commandEncoderRelease: WGPUProcCommandEncoderRelease => "wgpuCommandEncoderRelease",
+    computePassEncoderReference: WGPUProcComputePassEncoderReference => "wgpuComputePassEncoderReference",
+    computePassEncoderRelease: WGPUProcComputePassEncoderRelease => "wgpuComputePassEncoderRelease",
+    computePipelineReference: WGPUProcComputePipelineReference => "wgpuComputePipelineReference",
+    computePipelineRelease: WGPUProcComputePipelineRelease => "wgpuComputePipelineRelease",
+    deviceReference: WGPUProcDeviceReference => "wgpuDeviceReference",
+    deviceRelease: WGPUProcDeviceRelease => "wgpuDeviceRelease",
+    fenceReference: WGPUProcFenceReference => "wgpuFenceReference",
+    fenceRelease: WGPUProcFenceRelease => "wgpuFenceRelease",
+    instanceReference: WGPUProcInstanceReference => "wgpuInstanceReference",
+    instanceRelease: WGPUProcInstanceRelease => "wgpuInstanceRelease",
+    pipelineLayoutReference: WGPUProcPipelineLayoutReference => "wgpuPipelineLayoutReference",
+    pipelineLayoutRelease: WGPUProcPipelineLayoutRelease => "wgpuPipelineLayoutRelease",
+    querySetReference: WGPUProcQuerySetReference => "wgpuQuerySetReference",
+    querySetRelease: WGPUProcQuerySetRelease => "wgpuQuerySetRelease",
+    queueReference: WGPUProcQueueReference => "wgpuQueueReference",
+    queueRelease: WGPUProcQueueRelease => "wgpuQueueRelease",
+    renderBundleReference: WGPUProcRenderBundleReference => "wgpuRenderBundleReference",
+    renderBundleRelease: WGPUProcRenderBundleRelease => "wgpuRenderBundleRelease",
+    renderBundleEncoderReference: WGPUProcRenderBundleEncoderReference => "wgpuRenderBundleEncoderReference",
+    renderBundleEncoderRelease: WGPUProcRenderBundleEncoderRelease => "wgpuRenderBundleEncoderRelease",
+    renderPassEncoderReference: WGPUProcRenderPassEncoderReference => "wgpuRenderPassEncoderReference",
+    renderPassEncoderRelease: WGPUProcRenderPassEncoderRelease => "wgpuRenderPassEncoderRelease",
+    renderPipelineReference: WGPUProcRenderPipelineReference => "wgpuRenderPipelineReference",
+    renderPipelineRelease: WGPUProcRenderPipelineRelease => "wgpuRenderPipelineRelease",
+    samplerReference: WGPUProcSamplerReference => "wgpuSamplerReference",
+    samplerRelease: WGPUProcSamplerRelease => "wgpuSamplerRelease",
+    shaderModuleReference: WGPUProcShaderModuleReference => "wgpuShaderModuleReference",
+    shaderModuleRelease: WGPUProcShaderModuleRelease => "wgpuShaderModuleRelease",
+    surfaceReference: WGPUProcSurfaceReference => "wgpuSurfaceReference",
+    surfaceRelease: WGPUProcSurfaceRelease => "wgpuSurfaceRelease",
+    swapChainReference: WGPUProcSwapChainReference => "wgpuSwapChainReference",
+    swapChainRelease: WGPUProcSwapChainRelease => "wgpuSwapChainRelease",
+    textureReference: WGPUProcTextureReference => "wgpuTextureReference",
+    textureRelease: WGPUProcTextureRelease => "wgpuTextureRelease",
+    textureViewReference: WGPUProcTextureViewReference => "wgpuTextureViewReference",
+    textureViewRelease: WGPUProcTextureViewRelease => "wgpuTextureViewRelease",
+}